@@ -5,12 +5,16 @@
 //! [chrono]: https://docs.rs/chrono/
 
 use crate::{
-    formats::{Flexible, Format, Strict, Strictness},
+    formats::{self, Flexible, Format, Saturating, Strict, Strictness},
     prelude::*,
+    utils::{
+        calendar::{calendar_impls, MonthNumber, WeekdayNumber},
+        offset::UtcOffsetSeconds,
+    },
 };
 #[cfg(feature = "std")]
 use ::chrono_0_4::Local;
-use ::chrono_0_4::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
+use ::chrono_0_4::{DateTime, Duration, FixedOffset, Month, NaiveDateTime, TimeZone, Utc, Weekday};
 
 /// Create a [`DateTime`] for the Unix Epoch using the [`Utc`] timezone
 fn unix_epoch_utc() -> DateTime<Utc> {
@@ -32,6 +36,8 @@ fn unix_epoch_naive() -> NaiveDateTime {
 ///
 /// The `DateTime<Utc>` can be serialized from an integer, a float, or a string representing a number.
 ///
+/// [`TimestampAny`](crate::TimestampAny) offers the same for the `serde_as` annotation, and additionally accepts RFC 3339 strings.
+///
 /// # Examples
 ///
 /// ```
@@ -226,6 +232,26 @@ where
     Ok(chrono_dur)
 }
 
+/// Convert a [`DurationSigned`] into a [`chrono_0_4::Duration`], if it is in the representable range
+fn checked_duration_from_duration_signed(dur: DurationSigned) -> Option<Duration> {
+    let chrono_dur = Duration::from_std(dur.duration).ok()?;
+    Some(if dur.sign.is_negative() {
+        -chrono_dur
+    } else {
+        chrono_dur
+    })
+}
+
+/// Convert a [`DurationSigned`] into a [`chrono_0_4::Duration`], clamping it to the representable range
+fn duration_from_duration_signed_saturating<'de, D>(
+    dur: DurationSigned,
+) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(dur.saturating_convert(checked_duration_from_duration_signed))
+}
+
 macro_rules! use_duration_signed_ser {
     (
         $main_trait:ident $internal_trait:ident =>
@@ -460,6 +486,40 @@ where
     Ok(unix_epoch_naive() + duration_from_duration_signed::<D>(dur)?)
 }
 
+fn duration_to_datetime_utc_saturating<'de, D>(
+    dur: DurationSigned,
+) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(dur.saturating_convert(|dur| {
+        unix_epoch_utc().checked_add_signed(checked_duration_from_duration_signed(dur)?)
+    }))
+}
+
+#[cfg(feature = "std")]
+fn duration_to_datetime_local_saturating<'de, D>(
+    dur: DurationSigned,
+) -> Result<DateTime<Local>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(dur.saturating_convert(|dur| {
+        unix_epoch_local().checked_add_signed(checked_duration_from_duration_signed(dur)?)
+    }))
+}
+
+fn duration_to_naive_datetime_saturating<'de, D>(
+    dur: DurationSigned,
+) -> Result<NaiveDateTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(dur.saturating_convert(|dur| {
+        unix_epoch_naive().checked_add_signed(checked_duration_from_duration_signed(dur)?)
+    }))
+}
+
 // No subsecond precision
 use_duration_signed_de!(
     DurationSeconds DurationSeconds,
@@ -655,3 +715,267 @@ use_duration_signed_de!(
         {String, Strict =>}
     }
 );
+
+// Saturating conversions clamp out-of-range values
+use_duration_signed_de!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Duration; duration_from_duration_signed_saturating =>
+        {FORMAT, Saturating => FORMAT: Format}
+    }
+);
+use_duration_signed_de!(
+    TimestampSeconds DurationSeconds,
+    TimestampMilliSeconds DurationMilliSeconds,
+    TimestampMicroSeconds DurationMicroSeconds,
+    TimestampNanoSeconds DurationNanoSeconds,
+    TimestampSecondsWithFrac DurationSecondsWithFrac,
+    TimestampMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    TimestampMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    TimestampNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        DateTime<Utc>; duration_to_datetime_utc_saturating =>
+        {FORMAT, Saturating => FORMAT: Format}
+    }
+);
+#[cfg(feature = "std")]
+use_duration_signed_de!(
+    TimestampSeconds DurationSeconds,
+    TimestampMilliSeconds DurationMilliSeconds,
+    TimestampMicroSeconds DurationMicroSeconds,
+    TimestampNanoSeconds DurationNanoSeconds,
+    TimestampSecondsWithFrac DurationSecondsWithFrac,
+    TimestampMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    TimestampMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    TimestampNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        DateTime<Local>; duration_to_datetime_local_saturating =>
+        {FORMAT, Saturating => FORMAT: Format}
+    }
+);
+use_duration_signed_de!(
+    TimestampSeconds DurationSeconds,
+    TimestampMilliSeconds DurationMilliSeconds,
+    TimestampMicroSeconds DurationMicroSeconds,
+    TimestampNanoSeconds DurationNanoSeconds,
+    TimestampSecondsWithFrac DurationSecondsWithFrac,
+    TimestampMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    TimestampMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    TimestampNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        NaiveDateTime; duration_to_naive_datetime_saturating =>
+        {FORMAT, Saturating => FORMAT: Format}
+    }
+);
+
+macro_rules! use_timestamp_any_ser {
+    (
+        $ty:ty; $converter:ident =>
+        $({
+            $format:ty =>
+            $($tbound:ident: $bound:ident)*
+        })*
+    ) => {
+        $(
+            impl<$($tbound,)*> SerializeAs<$ty> for TimestampAny<$format>
+            where
+                $($tbound: $bound,)*
+            {
+                fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let dur: DurationSigned = $converter(source);
+                    TimestampAny::<$format>::serialize_as(&dur, serializer)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! use_timestamp_any_de {
+    ($ty:ty; $converter:ident) => {
+        impl<'de, FORMAT> DeserializeAs<'de, $ty> for TimestampAny<FORMAT>
+        where
+            FORMAT: Format,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let dur: DurationSigned = TimestampAny::<FORMAT>::deserialize_as(deserializer)?;
+                $converter::<D>(dur)
+            }
+        }
+    };
+}
+
+use_timestamp_any_ser!(
+    DateTime<TZ>; datetime_to_duration =>
+    {i64 => TZ: TimeZone}
+    {f64 => TZ: TimeZone}
+    {formats::Rfc3339 => TZ: TimeZone}
+);
+#[cfg(feature = "alloc")]
+use_timestamp_any_ser!(
+    DateTime<TZ>; datetime_to_duration =>
+    {String => TZ: TimeZone}
+);
+use_timestamp_any_ser!(
+    NaiveDateTime; naive_datetime_to_duration =>
+    {i64 =>}
+    {f64 =>}
+    {formats::Rfc3339 =>}
+);
+#[cfg(feature = "alloc")]
+use_timestamp_any_ser!(
+    NaiveDateTime; naive_datetime_to_duration =>
+    {String =>}
+);
+use_timestamp_any_de!(DateTime<Utc>; duration_to_datetime_utc);
+#[cfg(feature = "std")]
+use_timestamp_any_de!(DateTime<Local>; duration_to_datetime_local);
+use_timestamp_any_de!(NaiveDateTime; duration_to_naive_datetime);
+
+macro_rules! use_utc_offset_seconds_ser {
+    (
+        $ty:ty; $converter:ident =>
+        $({
+            $main:ty =>
+            $($tbound:ident: $bound:ident)*
+        })*
+    ) => {
+        $(
+            impl<$($tbound,)*> SerializeAs<$ty> for $main
+            where
+                $($tbound: $bound,)*
+            {
+                fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let offset: UtcOffsetSeconds = $converter(source);
+                    <$main as SerializeAs<UtcOffsetSeconds>>::serialize_as(&offset, serializer)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! use_utc_offset_seconds_de {
+    (
+        $ty:ty; $converter:ident =>
+        $({
+            $main:ty =>
+            $($tbound:ident: $bound:ident)*
+        })*
+    ) => {
+        $(
+            impl<'de, $($tbound,)*> DeserializeAs<'de, $ty> for $main
+            where
+                $($tbound: $bound,)*
+            {
+                fn deserialize_as<D>(deserializer: D) -> Result<$ty, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let offset: UtcOffsetSeconds =
+                        <$main as DeserializeAs<'de, UtcOffsetSeconds>>::deserialize_as(deserializer)?;
+                    $converter::<D>(offset)
+                }
+            }
+        )*
+    };
+}
+
+fn fixed_offset_to_seconds(source: &FixedOffset) -> UtcOffsetSeconds {
+    UtcOffsetSeconds(source.local_minus_utc())
+}
+
+fn seconds_to_fixed_offset<'de, D>(offset: UtcOffsetSeconds) -> Result<FixedOffset, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    FixedOffset::east_opt(offset.0).ok_or_else(|| {
+        DeError::custom(format_args!(
+            "UTC offset is outside of the representable range: {} seconds",
+            offset.0
+        ))
+    })
+}
+
+use_utc_offset_seconds_ser!(
+    FixedOffset; fixed_offset_to_seconds =>
+    {OffsetAsString<STRICTNESS> => STRICTNESS: Strictness}
+    {OffsetSeconds<i64, STRICTNESS> => STRICTNESS: Strictness}
+);
+#[cfg(feature = "alloc")]
+use_utc_offset_seconds_ser!(
+    FixedOffset; fixed_offset_to_seconds =>
+    {OffsetSeconds<String, STRICTNESS> => STRICTNESS: Strictness}
+);
+use_utc_offset_seconds_de!(
+    FixedOffset; seconds_to_fixed_offset =>
+    {OffsetAsString<Strict> =>}
+    {OffsetAsString<Flexible> =>}
+    {OffsetSeconds<i64, Strict> =>}
+    {OffsetSeconds<FORMAT, Flexible> => FORMAT: Format}
+);
+#[cfg(feature = "alloc")]
+use_utc_offset_seconds_de!(
+    FixedOffset; seconds_to_fixed_offset =>
+    {OffsetSeconds<String, Strict> =>}
+);
+
+const MONTHS: [Month; 12] = [
+    Month::January,
+    Month::February,
+    Month::March,
+    Month::April,
+    Month::May,
+    Month::June,
+    Month::July,
+    Month::August,
+    Month::September,
+    Month::October,
+    Month::November,
+    Month::December,
+];
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+fn month_to_number(source: &Month) -> MonthNumber {
+    MonthNumber(source.number_from_month() as u8)
+}
+
+fn number_to_month(month: MonthNumber) -> Month {
+    MONTHS[usize::from(month.0 - 1)]
+}
+
+fn weekday_to_number(source: &Weekday) -> WeekdayNumber {
+    WeekdayNumber(source.num_days_from_monday() as u8)
+}
+
+fn number_to_weekday(weekday: WeekdayNumber) -> Weekday {
+    WEEKDAYS[usize::from(weekday.0)]
+}
+
+calendar_impls!(
+    Month: month_to_number, number_to_month;
+    Weekday: weekday_to_number, number_to_weekday;
+);
@@ -0,0 +1,255 @@
+use crate::{
+    content::{
+        de::{Content as DeContent, ContentDeserializer},
+        ser::{Content, ContentSerializer},
+    },
+    duplicate_key_impls::Scalar,
+    prelude::*,
+};
+
+/// Serialize a `Vec` of structs as a struct of arrays
+///
+/// Instead of a list of objects, one array per field is produced.
+/// This avoids repeating the field names for every element, which makes payloads like time series much smaller.
+/// The field names become the keys of a map and the arrays contain the field values in the order of the elements.
+/// All arrays have the same length.
+///
+/// The elements must serialize as a struct or a map.
+/// No derive or other support from `T` is needed, since the fields are captured during serialization.
+/// If a field is missing from some elements, e.g., due to `#[serde(skip_serializing_if = "...")]`, `null` is used in its place.
+/// A field repeated within one element is an error.
+///
+/// During deserialization, the columns are zipped back into elements.
+/// Columns of different lengths are an error.
+/// `null` values are treated like missing fields, so `Option` fields become `None` and fields with `#[serde(default)]` use their default.
+///
+/// `T` is used to de/serialize the elements and defaults to [`Same`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_with::{serde_as, Columnar};
+///
+/// # #[derive(Debug, Clone, PartialEq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Sample {
+///     ts: u64,
+///     value: f64,
+///     #[serde(skip_serializing_if = "Option::is_none")]
+///     note: Option<String>,
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, Clone, PartialEq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Series {
+///     #[serde_as(as = "Columnar")]
+///     samples: Vec<Sample>,
+/// }
+///
+/// let series = Series {
+///     samples: vec![
+///         Sample { ts: 1, value: 0.5, note: None },
+///         Sample { ts: 2, value: 1.5, note: Some("peak".into()) },
+///         Sample { ts: 3, value: 1.0, note: None },
+///     ],
+/// };
+/// let json = json!({
+///     "samples": {
+///         "ts": [1, 2, 3],
+///         "value": [0.5, 1.5, 1.0],
+///         "note": [null, "peak", null],
+///     },
+/// });
+///
+/// assert_eq!(json, serde_json::to_value(&series).unwrap());
+/// assert_eq!(series, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+pub struct Columnar<T = Same>(PhantomData<T>);
+
+impl<T, TAs> SerializeAs<Vec<T>> for Columnar<TAs>
+where
+    TAs: SerializeAs<T>,
+{
+    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let columns = collect_columns::<T, TAs, S::Error>(source, serializer.is_human_readable())?;
+        let mut map = serializer.serialize_map(Some(columns.len()))?;
+        for (field, column) in &columns {
+            map.serialize_entry(field, column)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, T, TAs> DeserializeAs<'de, Vec<T>> for Columnar<TAs>
+where
+    TAs: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ColumnarVisitor<T, TAs> {
+            is_human_readable: bool,
+            phantom: PhantomData<(T, TAs)>,
+        }
+
+        impl<'de, T, TAs> Visitor<'de> for ColumnarVisitor<T, TAs>
+        where
+            TAs: DeserializeAs<'de, T>,
+        {
+            type Value = Vec<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a map of columns")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut columns = Vec::new();
+                let mut len = None;
+                while let Some((field, column)) =
+                    map.next_entry::<DeContent<'de>, Vec<DeContent<'de>>>()?
+                {
+                    match len {
+                        None => len = Some(column.len()),
+                        Some(len) if len != column.len() => {
+                            let found = column.len();
+                            return Err(match Scalar::from_content(&field) {
+                                Some(field) => DeError::custom(format_args!(
+                                    "invalid length {found} of column {field}, expected {len} values like the previous columns"
+                                )),
+                                None => DeError::custom(format_args!(
+                                    "invalid length {found} of column, expected {len} values like the previous columns"
+                                )),
+                            });
+                        }
+                        Some(_) => {}
+                    }
+                    columns.push((field, column.into_iter()));
+                }
+
+                let len = len.unwrap_or(0);
+                let mut values = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let fields = columns
+                        .iter_mut()
+                        .filter_map(|(field, column)| match column.next()? {
+                            // `null` marks a field missing from the element
+                            DeContent::None | DeContent::Unit => None,
+                            value => Some((field.clone(), value)),
+                        })
+                        .collect();
+                    values.push(TAs::deserialize_as(ContentDeserializer::new(
+                        DeContent::Map(fields),
+                        self.is_human_readable,
+                    ))?);
+                }
+                Ok(values)
+            }
+        }
+
+        let is_human_readable = deserializer.is_human_readable();
+        deserializer.deserialize_map(ColumnarVisitor::<T, TAs> {
+            is_human_readable,
+            phantom: PhantomData,
+        })
+    }
+}
+
+/// Serialize the elements and collect the values of each field into a column
+///
+/// The columns are in the order of their first appearance.
+/// Fields missing from an element are filled with `None`.
+/// Fields repeated within an element are an error, since they would misalign the following rows.
+pub(crate) fn collect_columns<T, TAs, E>(
+    source: &[T],
+    is_human_readable: bool,
+) -> Result<Vec<(Content, Vec<Content>)>, E>
+where
+    TAs: SerializeAs<T>,
+    E: SerError,
+{
+    let mut columns: Vec<(Content, Vec<Content>)> = Vec::new();
+    for (row, value) in source.iter().enumerate() {
+        let element = SerializeAsWrap::<T, TAs>::new(value)
+            .serialize(ContentSerializer::<E>::new(is_human_readable))?;
+        match element {
+            Content::Struct(_, fields) => {
+                for (field, value) in fields {
+                    let position = columns
+                        .iter()
+                        .position(|(existing, _)| existing.as_str() == Some(field));
+                    push_value(&mut columns, source.len(), row, position, value, || {
+                        Content::String(field.into())
+                    })?;
+                }
+            }
+            Content::Map(entries) => {
+                for (field, value) in entries {
+                    let position = columns
+                        .iter()
+                        .position(|(existing, _)| existing.total_cmp(&field).is_eq());
+                    push_value(&mut columns, source.len(), row, position, value, || field)?;
+                }
+            }
+            _ => {
+                return Err(E::custom(
+                    "the elements must serialize as a struct or a map",
+                ))
+            }
+        }
+        // Fill the fields missing in this element
+        for (_, column) in &mut columns {
+            if column.len() <= row {
+                column.push(Content::None);
+            }
+        }
+    }
+
+    Ok(columns)
+}
+
+/// Push the `value` of the element at `row` into the column at `position` or into a new column
+fn push_value<E>(
+    columns: &mut Vec<(Content, Vec<Content>)>,
+    len: usize,
+    row: usize,
+    position: Option<usize>,
+    value: Content,
+    field: impl FnOnce() -> Content,
+) -> Result<(), E>
+where
+    E: SerError,
+{
+    match position {
+        Some(position) => {
+            let (field, column) = &mut columns[position];
+            if column.len() > row {
+                return Err(match field.as_str() {
+                    Some(field) => E::custom(format_args!(
+                        "the field `{field}` is repeated in the element at index {row}"
+                    )),
+                    None => E::custom(format_args!(
+                        "a field is repeated in the element at index {row}"
+                    )),
+                });
+            }
+            column.push(value);
+        }
+        None => {
+            // The field was missing in all previous elements
+            let mut column = Vec::with_capacity(len);
+            column.resize_with(row, || Content::None);
+            column.push(value);
+            columns.push((field(), column));
+        }
+    }
+    Ok(())
+}
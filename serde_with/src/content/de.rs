@@ -58,8 +58,98 @@ pub(crate) enum Content<'de> {
 }
 
 impl<'de> Content<'de> {
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match *self {
+            Content::String(ref x) => Some(x),
+            Content::Str(x) => Some(x),
+            Content::ByteBuf(ref x) => core::str::from_utf8(x).ok(),
+            Content::Bytes(x) => core::str::from_utf8(x).ok(),
+            _ => None,
+        }
+    }
+
+    /// Total order over all values, which allows finding repeated keys without formatting them
+    ///
+    /// Borrowed and owned strings and bytes compare equal if their contents are equal.
+    /// Floats are ordered by [`f64::total_cmp`], such that `NaN` is equal to itself.
+    pub(crate) fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        fn cmp_seq(a: &[Content<'_>], b: &[Content<'_>]) -> core::cmp::Ordering {
+            a.iter()
+                .zip(b)
+                .map(|(a, b)| a.total_cmp(b))
+                .find(|ord| ord.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len()))
+        }
+
+        match (self, other) {
+            (Content::Bool(a), Content::Bool(b)) => a.cmp(b),
+            (Content::U8(a), Content::U8(b)) => a.cmp(b),
+            (Content::U16(a), Content::U16(b)) => a.cmp(b),
+            (Content::U32(a), Content::U32(b)) => a.cmp(b),
+            (Content::U64(a), Content::U64(b)) => a.cmp(b),
+            (Content::U128(a), Content::U128(b)) => a.cmp(b),
+            (Content::I8(a), Content::I8(b)) => a.cmp(b),
+            (Content::I16(a), Content::I16(b)) => a.cmp(b),
+            (Content::I32(a), Content::I32(b)) => a.cmp(b),
+            (Content::I64(a), Content::I64(b)) => a.cmp(b),
+            (Content::I128(a), Content::I128(b)) => a.cmp(b),
+            (Content::F32(a), Content::F32(b)) => a.total_cmp(b),
+            (Content::F64(a), Content::F64(b)) => a.total_cmp(b),
+            (Content::Char(a), Content::Char(b)) => a.cmp(b),
+            (Content::String(_) | Content::Str(_), Content::String(_) | Content::Str(_)) => {
+                self.as_str().cmp(&other.as_str())
+            }
+            (Content::ByteBuf(a), Content::ByteBuf(b)) => a.cmp(b),
+            (Content::ByteBuf(a), Content::Bytes(b)) => (**a).cmp(*b),
+            (Content::Bytes(a), Content::ByteBuf(b)) => (*a).cmp(&**b),
+            (Content::Bytes(a), Content::Bytes(b)) => a.cmp(b),
+            (Content::None, Content::None) | (Content::Unit, Content::Unit) => {
+                core::cmp::Ordering::Equal
+            }
+            (Content::Some(a), Content::Some(b)) | (Content::Newtype(a), Content::Newtype(b)) => {
+                a.total_cmp(b)
+            }
+            (Content::Seq(a), Content::Seq(b)) => cmp_seq(a, b),
+            (Content::Map(a), Content::Map(b)) => a
+                .iter()
+                .zip(b)
+                .map(|((ka, va), (kb, vb))| ka.total_cmp(kb).then_with(|| va.total_cmp(vb)))
+                .find(|ord| ord.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len())),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+
+    /// Position of the variant, which orders values of different variants
+    fn rank(&self) -> u8 {
+        match self {
+            Content::Bool(_) => 0,
+            Content::U8(_) => 1,
+            Content::U16(_) => 2,
+            Content::U32(_) => 3,
+            Content::U64(_) => 4,
+            Content::U128(_) => 5,
+            Content::I8(_) => 6,
+            Content::I16(_) => 7,
+            Content::I32(_) => 8,
+            Content::I64(_) => 9,
+            Content::I128(_) => 10,
+            Content::F32(_) => 11,
+            Content::F64(_) => 12,
+            Content::Char(_) => 13,
+            Content::String(_) | Content::Str(_) => 14,
+            Content::ByteBuf(_) | Content::Bytes(_) => 15,
+            Content::None => 16,
+            Content::Some(_) => 17,
+            Content::Unit => 18,
+            Content::Newtype(_) => 19,
+            Content::Seq(_) => 20,
+            Content::Map(_) => 21,
+        }
+    }
+
     #[cold]
-    fn unexpected(&self) -> Unexpected<'_> {
+    pub(crate) fn unexpected(&self) -> Unexpected<'_> {
         match *self {
             Content::Bool(b) => Unexpected::Bool(b),
             Content::U8(n) => Unexpected::Unsigned(n as u64),
@@ -66,6 +66,122 @@ impl Content {
             _ => None,
         }
     }
+
+    /// Total order over all values, which allows using them as keys of ordered maps
+    ///
+    /// Floats are ordered by [`f64::total_cmp`], such that `NaN` is equal to itself.
+    pub(crate) fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        fn cmp_seq(a: &[Content], b: &[Content]) -> core::cmp::Ordering {
+            a.iter()
+                .zip(b)
+                .map(|(a, b)| a.total_cmp(b))
+                .find(|ord| ord.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len()))
+        }
+        fn cmp_fields(
+            a: &[(&'static str, Content)],
+            b: &[(&'static str, Content)],
+        ) -> core::cmp::Ordering {
+            a.iter()
+                .zip(b)
+                .map(|((ka, va), (kb, vb))| ka.cmp(kb).then_with(|| va.total_cmp(vb)))
+                .find(|ord| ord.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len()))
+        }
+
+        match (self, other) {
+            (Content::Bool(a), Content::Bool(b)) => a.cmp(b),
+            (Content::U8(a), Content::U8(b)) => a.cmp(b),
+            (Content::U16(a), Content::U16(b)) => a.cmp(b),
+            (Content::U32(a), Content::U32(b)) => a.cmp(b),
+            (Content::U64(a), Content::U64(b)) => a.cmp(b),
+            (Content::U128(a), Content::U128(b)) => a.cmp(b),
+            (Content::I8(a), Content::I8(b)) => a.cmp(b),
+            (Content::I16(a), Content::I16(b)) => a.cmp(b),
+            (Content::I32(a), Content::I32(b)) => a.cmp(b),
+            (Content::I64(a), Content::I64(b)) => a.cmp(b),
+            (Content::I128(a), Content::I128(b)) => a.cmp(b),
+            (Content::F32(a), Content::F32(b)) => a.total_cmp(b),
+            (Content::F64(a), Content::F64(b)) => a.total_cmp(b),
+            (Content::Char(a), Content::Char(b)) => a.cmp(b),
+            (Content::String(a), Content::String(b)) => a.cmp(b),
+            (Content::Bytes(a), Content::Bytes(b)) => a.cmp(b),
+            (Content::None, Content::None) | (Content::Unit, Content::Unit) => {
+                core::cmp::Ordering::Equal
+            }
+            (Content::Some(a), Content::Some(b)) => a.total_cmp(b),
+            (Content::UnitStruct(a), Content::UnitStruct(b)) => a.cmp(b),
+            (Content::UnitVariant(na, ia, va), Content::UnitVariant(nb, ib, vb)) => {
+                (na, ia, va).cmp(&(nb, ib, vb))
+            }
+            (Content::NewtypeStruct(na, a), Content::NewtypeStruct(nb, b)) => {
+                na.cmp(nb).then_with(|| a.total_cmp(b))
+            }
+            (Content::NewtypeVariant(na, ia, va, a), Content::NewtypeVariant(nb, ib, vb, b)) => {
+                (na, ia, va).cmp(&(nb, ib, vb)).then_with(|| a.total_cmp(b))
+            }
+            (Content::Seq(a), Content::Seq(b)) | (Content::Tuple(a), Content::Tuple(b)) => {
+                cmp_seq(a, b)
+            }
+            (Content::TupleStruct(na, a), Content::TupleStruct(nb, b)) => {
+                na.cmp(nb).then_with(|| cmp_seq(a, b))
+            }
+            (Content::TupleVariant(na, ia, va, a), Content::TupleVariant(nb, ib, vb, b)) => {
+                (na, ia, va).cmp(&(nb, ib, vb)).then_with(|| cmp_seq(a, b))
+            }
+            (Content::Map(a), Content::Map(b)) => a
+                .iter()
+                .zip(b)
+                .map(|((ka, va), (kb, vb))| ka.total_cmp(kb).then_with(|| va.total_cmp(vb)))
+                .find(|ord| ord.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len())),
+            (Content::Struct(na, a), Content::Struct(nb, b)) => {
+                na.cmp(nb).then_with(|| cmp_fields(a, b))
+            }
+            (Content::StructVariant(na, ia, va, a), Content::StructVariant(nb, ib, vb, b)) => {
+                (na, ia, va)
+                    .cmp(&(nb, ib, vb))
+                    .then_with(|| cmp_fields(a, b))
+            }
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+
+    /// Position of the variant, which orders values of different variants
+    fn rank(&self) -> u8 {
+        match self {
+            Content::Bool(_) => 0,
+            Content::U8(_) => 1,
+            Content::U16(_) => 2,
+            Content::U32(_) => 3,
+            Content::U64(_) => 4,
+            Content::U128(_) => 5,
+            Content::I8(_) => 6,
+            Content::I16(_) => 7,
+            Content::I32(_) => 8,
+            Content::I64(_) => 9,
+            Content::I128(_) => 10,
+            Content::F32(_) => 11,
+            Content::F64(_) => 12,
+            Content::Char(_) => 13,
+            Content::String(_) => 14,
+            Content::Bytes(_) => 15,
+            Content::None => 16,
+            Content::Some(_) => 17,
+            Content::Unit => 18,
+            Content::UnitStruct(_) => 19,
+            Content::UnitVariant(..) => 20,
+            Content::NewtypeStruct(..) => 21,
+            Content::NewtypeVariant(..) => 22,
+            Content::Seq(_) => 23,
+            Content::Tuple(_) => 24,
+            Content::TupleStruct(..) => 25,
+            Content::TupleVariant(..) => 26,
+            Content::Map(_) => 27,
+            Content::Struct(..) => 28,
+            Content::StructVariant(..) => 29,
+        }
+    }
 }
 
 impl Serialize for Content {
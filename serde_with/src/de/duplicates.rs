@@ -1,11 +1,14 @@
 use super::impls::{foreach_map, foreach_set};
 use crate::{
     duplicate_key_impls::{
-        DuplicateInsertsFirstWinsMap, DuplicateInsertsLastWinsSet, PreventDuplicateInsertsMap,
-        PreventDuplicateInsertsSet,
+        duplicate_error, DescribeSeed, DuplicateInsertsFirstWinsMap, DuplicateInsertsLastWinsSet,
+        DuplicateInsertsMergeMap, DuplicateKeyPolicy, PreventDuplicateInsertsMap,
+        PreventDuplicateInsertsSet, Scalar,
     },
+    formats::{KeyNormalizer, MergeStrategy},
     prelude::*,
-    MapFirstKeyWins, MapPreventDuplicates, SetLastValueWins, SetPreventDuplicates,
+    MapFirstKeyWins, MapMergeDuplicates, MapNormalizeKeys, MapPreventDuplicates, SetLastValueWins,
+    SetPreventDuplicates,
 };
 #[cfg(feature = "hashbrown_0_14")]
 use hashbrown_0_14::{HashMap as HashbrownMap014, HashSet as HashbrownSet014};
@@ -33,11 +36,16 @@ where
         A: SeqAccess<'de>,
     {
         let mut values = Self::Value::new(access.size_hint());
+        let mut description = None;
+        let mut index = 0;
 
-        while let Some(value) = access.next_element::<DeserializeAsWrap<T, TAs>>()? {
-            if !values.insert(value.into_inner()) {
-                return Err(DeError::custom("invalid entry: found duplicate value"));
+        while let Some(value) =
+            access.next_element_seed(DescribeSeed::<T, TAs>::new(&mut description))?
+        {
+            if !values.insert(value) {
+                return Err(duplicate_error("value", description.as_ref(), index));
             };
+            index += 1;
         }
 
         Ok(values)
@@ -133,13 +141,15 @@ where
         A: MapAccess<'de>,
     {
         let mut values = Self::Value::new(access.size_hint());
+        let mut description = None;
+        let mut index = 0;
 
-        while let Some((key, value)) =
-            access.next_entry::<DeserializeAsWrap<K, KAs>, DeserializeAsWrap<V, VAs>>()?
-        {
-            if !values.insert(key.into_inner(), value.into_inner()) {
-                return Err(DeError::custom("invalid entry: found duplicate key"));
+        while let Some(key) = access.next_key_seed(DescribeSeed::<K, KAs>::new(&mut description))? {
+            let value = access.next_value::<DeserializeAsWrap<V, VAs>>()?;
+            if !values.insert(key, value.into_inner()) {
+                return Err(duplicate_error("key", description.as_ref(), index));
             };
+            index += 1;
         }
 
         Ok(values)
@@ -177,6 +187,87 @@ where
     }
 }
 
+struct MapMergeDuplicatesVisitor<MAP, K, KAs, V, VAs, STRATEGY>(
+    PhantomData<(MAP, K, KAs, V, VAs, STRATEGY)>,
+);
+
+impl<'de, MAP, K, KAs, V, VAs, STRATEGY> Visitor<'de>
+    for MapMergeDuplicatesVisitor<MAP, K, KAs, V, VAs, STRATEGY>
+where
+    MAP: DuplicateInsertsMergeMap<K, V>,
+    KAs: DeserializeAs<'de, K>,
+    VAs: DeserializeAs<'de, V>,
+    STRATEGY: MergeStrategy<V>,
+{
+    type Value = MAP;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    #[inline]
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut values = Self::Value::new(access.size_hint());
+
+        while let Some((key, value)) =
+            access.next_entry::<DeserializeAsWrap<K, KAs>, DeserializeAsWrap<V, VAs>>()?
+        {
+            values.insert(key.into_inner(), value.into_inner(), STRATEGY::merge);
+        }
+
+        Ok(values)
+    }
+}
+
+struct MapNormalizeKeysVisitor<MAP, NORMALIZER, K, KAs, V, VAs, POLICY>(
+    PhantomData<(MAP, NORMALIZER, K, KAs, V, VAs, POLICY)>,
+);
+
+impl<'de, MAP, NORMALIZER, K, KAs, V, VAs, POLICY> Visitor<'de>
+    for MapNormalizeKeysVisitor<MAP, NORMALIZER, K, KAs, V, VAs, POLICY>
+where
+    MAP: DuplicateInsertsMergeMap<K, V>,
+    NORMALIZER: KeyNormalizer,
+    KAs: DeserializeAs<'de, K>,
+    VAs: DeserializeAs<'de, V>,
+    POLICY: DuplicateKeyPolicy,
+{
+    type Value = MAP;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    #[inline]
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut values = Self::Value::new(access.size_hint());
+        let mut index = 0;
+
+        while let Some(key) = access.next_key::<String>()? {
+            let key = NORMALIZER::normalize(key);
+            let normalized: K =
+                KAs::deserialize_as(IntoDeserializer::<A::Error>::into_deserializer(&*key))?;
+            let value = access.next_value::<DeserializeAsWrap<V, VAs>>()?;
+            POLICY::insert(
+                &mut values,
+                normalized,
+                value.into_inner(),
+                index,
+                Some(Scalar::Borrowed(&key)),
+            )?;
+            index += 1;
+        }
+
+        Ok(values)
+    }
+}
+
 #[cfg(feature = "alloc")]
 macro_rules! map_impl {
     (
@@ -222,6 +313,56 @@ macro_rules! map_impl {
                 ))
             }
         }
+
+        impl<'de, K, V, KAs, VAs, STRATEGY $(, $typaram)*> DeserializeAs<'de, $ty<K, V $(, $typaram)*>>
+            for MapMergeDuplicates<KAs, VAs, STRATEGY>
+        where
+            KAs: DeserializeAs<'de, K>,
+            VAs: DeserializeAs<'de, V>,
+            STRATEGY: MergeStrategy<V>,
+            $(K: $kbound1 $(+ $kbound2)*,)?
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<K, V $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_map(MapMergeDuplicatesVisitor::<
+                    $ty<K, V $(, $typaram)*>,
+                    K,
+                    KAs,
+                    V,
+                    VAs,
+                    STRATEGY,
+                >(PhantomData))
+            }
+        }
+
+        impl<'de, NORMALIZER, K, V, KAs, VAs, POLICY $(, $typaram)*> DeserializeAs<'de, $ty<K, V $(, $typaram)*>>
+            for MapNormalizeKeys<NORMALIZER, KAs, VAs, POLICY>
+        where
+            NORMALIZER: KeyNormalizer,
+            KAs: DeserializeAs<'de, K>,
+            VAs: DeserializeAs<'de, V>,
+            POLICY: DuplicateKeyPolicy,
+            $(K: $kbound1 $(+ $kbound2)*,)?
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<K, V $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_map(MapNormalizeKeysVisitor::<
+                    $ty<K, V $(, $typaram)*>,
+                    NORMALIZER,
+                    K,
+                    KAs,
+                    V,
+                    VAs,
+                    POLICY,
+                >(PhantomData))
+            }
+        }
     };
 }
 foreach_map!(map_impl);
@@ -1,3 +1,5 @@
+#[cfg(feature = "alloc")]
+use crate::duplicate_key_impls::DuplicateInsertsMergeMap;
 use crate::{formats::*, prelude::*};
 #[cfg(feature = "hashbrown_0_14")]
 use hashbrown_0_14::{HashMap as HashbrownMap014, HashSet as HashbrownSet014};
@@ -816,6 +818,213 @@ tuple_seq_as_map_arr!(BTreeMap<KAs, VAs>);
 #[cfg(feature = "std")]
 tuple_seq_as_map_arr!(HashMap<KAs, VAs>);
 
+/// Deserialize a map index, which may be stored as a string or as an integer
+#[cfg(feature = "alloc")]
+struct MapIndex<const MAX_INDEX: usize>(usize);
+
+#[cfg(feature = "alloc")]
+impl<'de, const MAX_INDEX: usize> Deserialize<'de> for MapIndex<MAX_INDEX> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Helper<const MAX_INDEX: usize>;
+
+        impl<const MAX_INDEX: usize> Helper<MAX_INDEX> {
+            fn check<E>(index: u64) -> Result<MapIndex<MAX_INDEX>, E>
+            where
+                E: DeError,
+            {
+                match usize::try_from(index) {
+                    Ok(index) if index <= MAX_INDEX => Ok(MapIndex(index)),
+                    _ => Err(DeError::invalid_value(Unexpected::Unsigned(index), &Self)),
+                }
+            }
+        }
+
+        impl<'de, const MAX_INDEX: usize> Visitor<'de> for Helper<MAX_INDEX> {
+            type Value = MapIndex<MAX_INDEX>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "an index between 0 and {MAX_INDEX}")
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                Self::check(value)
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                let index = u64::try_from(value)
+                    .map_err(|_| DeError::invalid_value(Unexpected::Signed(value), &self))?;
+                Self::check(index)
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                // Only accept the canonical form, such that `"01"` and `"1"` cannot both occur
+                let index = match value.parse::<u64>() {
+                    Ok(index) if value == "0" || !value.starts_with('0') => index,
+                    _ => return Err(DeError::invalid_value(Unexpected::Str(value), &self)),
+                };
+                Self::check(index)
+            }
+        }
+
+        deserializer.deserialize_str(Helper::<MAX_INDEX>)
+    }
+}
+
+/// Collect the entries of an index-keyed map into a vector, leaving holes as `None`
+#[cfg(feature = "alloc")]
+fn deserialize_index_map<'de, D, T, U, const MAX_INDEX: usize>(
+    deserializer: D,
+) -> Result<Vec<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    U: DeserializeAs<'de, T>,
+{
+    struct MapVisitor<T, U, const MAX_INDEX: usize>(PhantomData<(T, U)>);
+
+    impl<'de, T, U, const MAX_INDEX: usize> Visitor<'de> for MapVisitor<T, U, MAX_INDEX>
+    where
+        U: DeserializeAs<'de, T>,
+    {
+        type Value = Vec<Option<T>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map with indices as keys")
+        }
+
+        fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut values: Vec<Option<T>> =
+                Vec::with_capacity(utils::size_hint_cautious::<T>(access.size_hint()));
+            while let Some(MapIndex(index)) = access.next_key::<MapIndex<MAX_INDEX>>()? {
+                let value: DeserializeAsWrap<T, U> = access.next_value()?;
+                if index >= values.len() {
+                    values.resize_with(index + 1, || None);
+                }
+                let slot = &mut values[index];
+                if slot.is_some() {
+                    return Err(DeError::custom(format_args!(
+                        "invalid entry: found duplicate index {index}"
+                    )));
+                }
+                *slot = Some(value.into_inner());
+            }
+            Ok(values)
+        }
+    }
+
+    deserializer.deserialize_map(MapVisitor::<T, U, MAX_INDEX>(PhantomData))
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T, U, const MAX_INDEX: usize> DeserializeAs<'de, Vec<T>>
+    for VecAsIndexMap<U, Strict, MAX_INDEX>
+where
+    U: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_index_map::<D, T, U, MAX_INDEX>(deserializer)?
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| {
+                value.ok_or_else(|| {
+                    DeError::custom(format_args!("invalid entry: missing index {index}"))
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T, U, const MAX_INDEX: usize> DeserializeAs<'de, Vec<Option<T>>>
+    for VecAsIndexMap<U, Flexible, MAX_INDEX>
+where
+    U: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<Option<T>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_index_map::<D, T, U, MAX_INDEX>(deserializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T> DeserializeAs<'de, T> for StructAsSeq
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Helper<T>(PhantomData<T>);
+
+        impl<'de, T> Visitor<'de> for Helper<T>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a sequence of struct fields")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+                T::deserialize(serde::de::value::SeqAccessDeserializer::new(seq))
+            }
+        }
+
+        deserializer.deserialize_seq(Helper(PhantomData))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T> DeserializeAs<'de, T> for StructAsFieldIndex
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Helper<T>(PhantomData<T>);
+
+        impl<'de, T> Visitor<'de> for Helper<T>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a map from field indices to struct fields")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+                T::deserialize(serde::de::value::MapAccessDeserializer::new(map))
+            }
+        }
+
+        deserializer.deserialize_map(Helper(PhantomData))
+    }
+}
+
 // endregion
 ///////////////////////////////////////////////////////////////////////////////
 // region: Conversion types which cause different serialization behavior
@@ -879,43 +1088,94 @@ where
     }
 }
 
+/// Deserialize a value with `TAs`, but remember a failure instead of returning the error
+///
+/// The value is buffered first, such that the deserializer is in a consistent state afterwards.
+/// The error is captured as `ERR`, which is `String` for adapters which report the message and `()` for adapters which skip the value.
 #[cfg(feature = "alloc")]
-impl<'de, T, U> DeserializeAs<'de, Vec<T>> for VecSkipError<U>
+enum GoodOrError<T, TAs, ERR = ()> {
+    Good(T),
+    // The PhantomData is only here to consume the TAs generic
+    Error(ERR, PhantomData<TAs>),
+}
+
+/// Capture the error of a value which failed to deserialize
+#[cfg(feature = "alloc")]
+trait CaptureError {
+    fn capture(err: impl Display) -> Self;
+}
+
+#[cfg(feature = "alloc")]
+impl CaptureError for () {
+    #[inline]
+    fn capture(_err: impl Display) -> Self {}
+}
+
+#[cfg(feature = "alloc")]
+impl CaptureError for String {
+    #[inline]
+    fn capture(err: impl Display) -> Self {
+        err.to_string()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, TAs> GoodOrError<T, TAs, String> {
+    fn into_result(self) -> Result<T, String> {
+        match self {
+            GoodOrError::Good(value) => Ok(value),
+            GoodOrError::Error(message, _) => Err(message),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T, TAs, ERR> Deserialize<'de> for GoodOrError<T, TAs, ERR>
 where
-    U: DeserializeAs<'de, T>,
+    TAs: DeserializeAs<'de, T>,
+    ERR: CaptureError,
 {
-    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        enum GoodOrError<T, TAs> {
-            Good(T),
-            // Only here to consume the TAs generic
-            Error(PhantomData<TAs>),
-        }
+        let is_hr = deserializer.is_human_readable();
+        let content: content::de::Content<'de> = Deserialize::deserialize(deserializer)?;
 
-        impl<'de, T, TAs> Deserialize<'de> for GoodOrError<T, TAs>
-        where
-            TAs: DeserializeAs<'de, T>,
-        {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-            where
-                D: Deserializer<'de>,
+        Ok(
+            match <DeserializeAsWrap<T, TAs>>::deserialize(content::de::ContentDeserializer::<
+                D::Error,
+            >::new(content, is_hr))
             {
-                let is_hr = deserializer.is_human_readable();
-                let content: content::de::Content<'de> = Deserialize::deserialize(deserializer)?;
+                Ok(elem) => GoodOrError::Good(elem.into_inner()),
+                Err(err) => GoodOrError::Error(ERR::capture(err), PhantomData),
+            },
+        )
+    }
+}
 
-                Ok(
-                    match <DeserializeAsWrap<T, TAs>>::deserialize(
-                        content::de::ContentDeserializer::<D::Error>::new(content, is_hr),
-                    ) {
-                        Ok(elem) => GoodOrError::Good(elem.into_inner()),
-                        Err(_) => GoodOrError::Error(PhantomData),
-                    },
-                )
-            }
-        }
+#[cfg(feature = "alloc")]
+impl<'de, T, U> DeserializeAs<'de, Vec<T>> for VecSkipError<U>
+where
+    U: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        SeqSkipError::<U>::deserialize_as(deserializer)
+    }
+}
 
+#[cfg(feature = "alloc")]
+impl<'de, T, U> DeserializeAs<'de, Vec<Result<T, String>>> for VecCollectErrors<U>
+where
+    U: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<Result<T, String>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
         struct SeqVisitor<T, U> {
             marker: PhantomData<T>,
             marker2: PhantomData<U>,
@@ -925,7 +1185,7 @@ where
         where
             TAs: DeserializeAs<'de, T>,
         {
-            type Value = Vec<T>;
+            type Value = Vec<Result<T, String>>;
 
             fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("a sequence")
@@ -936,11 +1196,7 @@ where
                 A: SeqAccess<'de>,
             {
                 utils::SeqIter::new(seq)
-                    .filter_map(|res: Result<GoodOrError<T, TAs>, A::Error>| match res {
-                        Ok(GoodOrError::Good(value)) => Some(Ok(value)),
-                        Ok(GoodOrError::Error(_)) => None,
-                        Err(err) => Some(Err(err)),
-                    })
+                    .map(|res| res.map(GoodOrError::<T, TAs, String>::into_result))
                     .collect()
             }
         }
@@ -953,6 +1209,176 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+macro_rules! seq_skip_error_impl {
+    (
+        $ty:ident < T $(: $tbound1:ident $(+ $tbound2:ident)*)? $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)* )* >,
+        $with_capacity:expr,
+        $append:ident
+    ) => {
+        impl<'de, T, U $(, $typaram)*> DeserializeAs<'de, $ty<T $(, $typaram)*>> for SeqSkipError<U>
+        where
+            U: DeserializeAs<'de, T>,
+            $(T: $tbound1 $(+ $tbound2)*,)?
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<T $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct SeqVisitor<T, U $(, $typaram)*> {
+                    marker: PhantomData<(T, U $(, $typaram)*)>,
+                }
+
+                impl<'de, T, U $(, $typaram)*> Visitor<'de> for SeqVisitor<T, U $(, $typaram)*>
+                where
+                    U: DeserializeAs<'de, T>,
+                    $(T: $tbound1 $(+ $tbound2)*,)?
+                    $($typaram: $bound1 $(+ $bound2)*),*
+                {
+                    type Value = $ty<T $(, $typaram)*>;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str("a sequence")
+                    }
+
+                    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: SeqAccess<'de>,
+                    {
+                        #[allow(clippy::redundant_closure_call)]
+                        let mut values = ($with_capacity)(utils::size_hint_cautious::<T>(seq.size_hint()));
+
+                        while let Some(value) = seq.next_element::<GoodOrError<T, U>>()? {
+                            if let GoodOrError::Good(value) = value {
+                                values.$append(value);
+                            }
+                        }
+
+                        Ok(values.into())
+                    }
+                }
+
+                let visitor = SeqVisitor::<T, U $(, $typaram)*> {
+                    marker: PhantomData,
+                };
+                deserializer.deserialize_seq(visitor)
+            }
+        }
+    };
+}
+#[cfg(feature = "alloc")]
+foreach_seq!(seq_skip_error_impl);
+
+#[cfg(feature = "alloc")]
+macro_rules! map_skip_error_impl {
+    (
+        $ty:ident < K $(: $kbound1:ident $(+ $kbound2:ident)*)*, V $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)*)* >,
+        $with_capacity:expr
+    ) => {
+        impl<'de, K, V, KU, VU $(, $typaram)*> DeserializeAs<'de, $ty<K, V $(, $typaram)*>> for MapSkipError<KU, VU>
+        where
+            KU: DeserializeAs<'de, K>,
+            VU: DeserializeAs<'de, V>,
+            $(K: $kbound1 $(+ $kbound2)*,)*
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<K, V $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct MapVisitor<K, V, KU, VU $(, $typaram)*>(PhantomData<(K, V, KU, VU $(, $typaram)*)>);
+
+                impl<'de, K, V, KU, VU $(, $typaram)*> Visitor<'de> for MapVisitor<K, V, KU, VU $(, $typaram)*>
+                where
+                    KU: DeserializeAs<'de, K>,
+                    VU: DeserializeAs<'de, V>,
+                    $(K: $kbound1 $(+ $kbound2)*,)*
+                    $($typaram: $bound1 $(+ $bound2)*),*
+                {
+                    type Value = $ty<K, V $(, $typaram)*>;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str("a map")
+                    }
+
+                    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: MapAccess<'de>,
+                    {
+                        #[allow(clippy::redundant_closure_call)]
+                        let mut values = ($with_capacity)(utils::size_hint_cautious::<(K, V)>(map.size_hint()));
+
+                        while let Some(entry) = map.next_entry::<GoodOrError<K, KU>, GoodOrError<V, VU>>()? {
+                            // Skip the entry if either the key or the value is invalid
+                            if let (GoodOrError::Good(key), GoodOrError::Good(value)) = entry {
+                                values.insert(key, value);
+                            }
+                        }
+
+                        Ok(values)
+                    }
+                }
+
+                let visitor = MapVisitor::<K, V, KU, VU $(, $typaram)*>(PhantomData);
+                deserializer.deserialize_map(visitor)
+            }
+        }
+    };
+}
+#[cfg(feature = "alloc")]
+foreach_map!(map_skip_error_impl);
+
+#[cfg(feature = "alloc")]
+macro_rules! sorted_set_impl {
+    (
+        $ty:ident < T $(: $tbound1:ident $(+ $tbound2:ident)*)? $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)* )* >,
+        $with_capacity:expr,
+        $append:ident
+    ) => {
+        impl<'de, T, U $(, $typaram)*> DeserializeAs<'de, $ty<T $(, $typaram)*>> for SortedSet<U>
+        where
+            U: DeserializeAs<'de, T>,
+            $(T: $tbound1 $(+ $tbound2)*,)?
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<T $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                <$ty<U $(, $typaram)*>>::deserialize_as(deserializer)
+            }
+        }
+    };
+}
+#[cfg(feature = "alloc")]
+foreach_set!(sorted_set_impl);
+
+#[cfg(feature = "alloc")]
+macro_rules! sorted_map_impl {
+    (
+        $ty:ident < K $(: $kbound1:ident $(+ $kbound2:ident)*)*, V $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)*)* >,
+        $with_capacity:expr
+    ) => {
+        impl<'de, K, V, KU, VU $(, $typaram)*> DeserializeAs<'de, $ty<K, V $(, $typaram)*>> for SortedMap<KU, VU>
+        where
+            KU: DeserializeAs<'de, K>,
+            VU: DeserializeAs<'de, V>,
+            $(K: $kbound1 $(+ $kbound2)*,)*
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<K, V $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                <$ty<KU, VU $(, $typaram)*>>::deserialize_as(deserializer)
+            }
+        }
+    };
+}
+#[cfg(feature = "alloc")]
+foreach_map!(sorted_map_impl);
+
 impl<'de, Str> DeserializeAs<'de, Option<Str>> for NoneAsEmptyString
 where
     Str: FromStr,
@@ -1204,6 +1630,51 @@ use_signed_duration!(
     }
 );
 
+#[cfg(feature = "std")]
+use_signed_duration!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Duration; to_std_duration_saturating =>
+        {FORMAT, Saturating => FORMAT: Format}
+    }
+);
+#[cfg(feature = "std")]
+use_signed_duration!(
+    TimestampSeconds DurationSeconds,
+    TimestampMilliSeconds DurationMilliSeconds,
+    TimestampMicroSeconds DurationMicroSeconds,
+    TimestampNanoSeconds DurationNanoSeconds,
+    TimestampSecondsWithFrac DurationSecondsWithFrac,
+    TimestampMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    TimestampMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    TimestampNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        SystemTime; to_system_time_saturating =>
+        {FORMAT, Saturating => FORMAT: Format}
+    }
+);
+
+#[cfg(feature = "std")]
+impl<'de, FORMAT> DeserializeAs<'de, SystemTime> for TimestampAny<FORMAT>
+where
+    FORMAT: Format,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<SystemTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let dur: DurationSigned = TimestampAny::<FORMAT>::deserialize_as(deserializer)?;
+        dur.to_system_time::<D>()
+    }
+}
+
 impl<'de, T, U> DeserializeAs<'de, T> for DefaultOnNull<U>
 where
     U: DeserializeAs<'de, T>,
@@ -1217,6 +1688,19 @@ where
     }
 }
 
+impl<'de, T, U, V> DeserializeAs<'de, T> for Validated<U, V>
+where
+    U: DeserializeAs<'de, T>,
+    V: validate::Validator<T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        validate::validate::<T, V, D::Error>(U::deserialize_as(deserializer)?)
+    }
+}
+
 impl<'de> DeserializeAs<'de, &'de [u8]> for Bytes {
     fn deserialize_as<D>(deserializer: D) -> Result<&'de [u8], D::Error>
     where
@@ -1570,37 +2054,172 @@ impl<'de, const N: usize> DeserializeAs<'de, Box<[u8; N]>> for Bytes {
 }
 
 #[cfg(feature = "alloc")]
-impl<'de, T, TAs, FORMAT> DeserializeAs<'de, Vec<T>> for OneOrMany<TAs, FORMAT>
+macro_rules! one_or_many_impl {
+    (
+        $ty:ident < T $(: $tbound1:ident $(+ $tbound2:ident)*)? $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)* )* >,
+        $with_capacity:expr,
+        $append:ident
+    ) => {
+        impl<'de, T, TAs, FORMAT $(, $typaram)*> DeserializeAs<'de, $ty<T $(, $typaram)*>> for OneOrMany<TAs, FORMAT>
+        where
+            TAs: DeserializeAs<'de, T>,
+            FORMAT: Format,
+            $(T: $tbound1 $(+ $tbound2)*,)?
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<T $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let is_hr = deserializer.is_human_readable();
+                let content: content::de::Content<'de> = Deserialize::deserialize(deserializer)?;
+
+                let one_err: D::Error = match <DeserializeAsWrap<T, TAs>>::deserialize(
+                    content::de::ContentRefDeserializer::new(&content, is_hr),
+                ) {
+                    Ok(one) => {
+                        #[allow(clippy::redundant_closure_call)]
+                        let mut values = ($with_capacity)(1);
+                        values.$append(one.into_inner());
+                        return Ok(values.into());
+                    }
+                    Err(err) => err,
+                };
+                let many_err: D::Error = match <DeserializeAsWrap<$ty<T $(, $typaram)*>, $ty<TAs $(, $typaram)*>>>::deserialize(
+                    content::de::ContentDeserializer::new(content, is_hr),
+                ) {
+                    Ok(many) => return Ok(many.into_inner()),
+                    Err(err) => err,
+                };
+                Err(DeError::custom(format_args!(
+                    "OneOrMany could not deserialize any variant:\n  One: {}\n  Many: {}",
+                    one_err, many_err
+                )))
+            }
+        }
+    };
+}
+#[cfg(feature = "alloc")]
+foreach_seq!(one_or_many_impl);
+
+#[cfg(feature = "alloc")]
+struct OneOrManyMapVisitor<MAP, K, KAs, V, VAs>(PhantomData<(MAP, K, KAs, V, VAs)>);
+
+#[cfg(feature = "alloc")]
+impl<'de, MAP, K, KAs, V, VAs> Visitor<'de> for OneOrManyMapVisitor<MAP, K, KAs, V, VAs>
 where
-    TAs: DeserializeAs<'de, T>,
-    FORMAT: Format,
+    MAP: DuplicateInsertsMergeMap<K, V>,
+    KAs: DeserializeAs<'de, K>,
+    VAs: DeserializeAs<'de, V>,
 {
-    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    type Value = MAP;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map or a sequence of maps")
+    }
+
+    fn visit_map<A>(self, access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut values = MAP::new(access.size_hint());
+        ExtendMapVisitor::<MAP, K, KAs, V, VAs>(&mut values, PhantomData).visit_map(access)?;
+        Ok(values)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = MAP::new(None);
+        while seq
+            .next_element_seed(ExtendMapVisitor::<MAP, K, KAs, V, VAs>(
+                &mut values,
+                PhantomData,
+            ))?
+            .is_some()
+        {}
+        Ok(values)
+    }
+}
+
+/// Insert the entries of a map into an existing map
+#[cfg(feature = "alloc")]
+struct ExtendMapVisitor<'a, MAP, K, KAs, V, VAs>(&'a mut MAP, PhantomData<(K, KAs, V, VAs)>);
+
+#[cfg(feature = "alloc")]
+impl<'a, 'de, MAP, K, KAs, V, VAs> DeserializeSeed<'de>
+    for ExtendMapVisitor<'a, MAP, K, KAs, V, VAs>
+where
+    MAP: DuplicateInsertsMergeMap<K, V>,
+    KAs: DeserializeAs<'de, K>,
+    VAs: DeserializeAs<'de, V>,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let is_hr = deserializer.is_human_readable();
-        let content: content::de::Content<'de> = Deserialize::deserialize(deserializer)?;
+        deserializer.deserialize_map(self)
+    }
+}
 
-        let one_err: D::Error = match <DeserializeAsWrap<T, TAs>>::deserialize(
-            content::de::ContentRefDeserializer::new(&content, is_hr),
-        ) {
-            Ok(one) => return Ok(alloc::vec![one.into_inner()]),
-            Err(err) => err,
-        };
-        let many_err: D::Error = match <DeserializeAsWrap<Vec<T>, Vec<TAs>>>::deserialize(
-            content::de::ContentDeserializer::new(content, is_hr),
-        ) {
-            Ok(many) => return Ok(many.into_inner()),
-            Err(err) => err,
-        };
-        Err(DeError::custom(format_args!(
-            "OneOrMany could not deserialize any variant:\n  One: {}\n  Many: {}",
-            one_err, many_err
-        )))
+#[cfg(feature = "alloc")]
+impl<'a, 'de, MAP, K, KAs, V, VAs> Visitor<'de> for ExtendMapVisitor<'a, MAP, K, KAs, V, VAs>
+where
+    MAP: DuplicateInsertsMergeMap<K, V>,
+    KAs: DeserializeAs<'de, K>,
+    VAs: DeserializeAs<'de, V>,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        while let Some((key, value)) =
+            access.next_entry::<DeserializeAsWrap<K, KAs>, DeserializeAsWrap<V, VAs>>()?
+        {
+            self.0
+                .insert(key.into_inner(), value.into_inner(), |existing, value| {
+                    *existing = value;
+                });
+        }
+        Ok(())
     }
 }
 
+#[cfg(feature = "alloc")]
+macro_rules! one_or_many_map_impl {
+    (
+        $ty:ident < K $(: $kbound1:ident $(+ $kbound2:ident)*)*, V $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)*)* >,
+        $with_capacity:expr
+    ) => {
+        impl<'de, K, V, KAs, VAs, FORMAT $(, $typaram)*> DeserializeAs<'de, $ty<K, V $(, $typaram)*>> for OneOrManyMap<KAs, VAs, FORMAT>
+        where
+            KAs: DeserializeAs<'de, K>,
+            VAs: DeserializeAs<'de, V>,
+            FORMAT: Format,
+            $(K: $kbound1 $(+ $kbound2)*,)*
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<K, V $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_any(OneOrManyMapVisitor::<$ty<K, V $(, $typaram)*>, K, KAs, V, VAs>(PhantomData))
+            }
+        }
+    };
+}
+#[cfg(feature = "alloc")]
+foreach_map!(one_or_many_map_impl);
+
 #[cfg(feature = "alloc")]
 impl<'de, T, TAs1> DeserializeAs<'de, T> for PickFirst<(TAs1,)>
 where
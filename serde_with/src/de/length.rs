@@ -0,0 +1,440 @@
+//! Length limits for [`MaxLen`] and [`LenBetween`]
+//!
+//! The limits are enforced by wrapping the [`Deserializer`] and the [`Visitor`].
+//! The wrappers count the elements while the inner visitor consumes them.
+//! This allows failing as soon as the limit is exceeded, instead of deserializing the whole value first.
+
+use super::impls::{foreach_map, foreach_seq, foreach_set};
+use crate::prelude::*;
+#[cfg(feature = "hashbrown_0_14")]
+use hashbrown_0_14::{HashMap as HashbrownMap014, HashSet as HashbrownSet014};
+#[cfg(feature = "indexmap_1")]
+use indexmap_1::{IndexMap, IndexSet};
+#[cfg(feature = "indexmap_2")]
+use indexmap_2::{IndexMap as IndexMap2, IndexSet as IndexSet2};
+
+type BoxedSlice<T> = Box<[T]>;
+
+/// The inclusive length bounds and the unit the length is counted in
+#[derive(Copy, Clone)]
+struct LenBounds {
+    min: usize,
+    max: usize,
+    /// Singular and plural name of the unit
+    unit: (&'static str, &'static str),
+}
+
+impl LenBounds {
+    fn check<E>(self, len: usize) -> Result<(), E>
+    where
+        E: DeError,
+    {
+        if len < self.min || len > self.max {
+            Err(E::invalid_length(len, &self))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Expected for LenBounds {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { min, max, unit } = *self;
+        let unit = if max == 1 { unit.0 } else { unit.1 };
+        if min == 0 {
+            write!(formatter, "at most {max} {unit}")
+        } else if min == max {
+            write!(formatter, "exactly {max} {unit}")
+        } else {
+            write!(formatter, "between {min} and {max} {unit}")
+        }
+    }
+}
+
+struct LenDeserializer<D> {
+    inner: D,
+    min: usize,
+    max: usize,
+}
+
+impl<D> LenDeserializer<D> {
+    fn visitor<V>(&self, visitor: V) -> LenVisitor<V> {
+        LenVisitor {
+            inner: visitor,
+            min: self.min,
+            max: self.max,
+        }
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident $(($($arg:ident: $ty:ty),*))?;)*) => {
+        $(
+            fn $method<V>(self, $($($arg: $ty,)*)? visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                let visitor = self.visitor(visitor);
+                self.inner.$method($($($arg,)*)? visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, D> Deserializer<'de> for LenDeserializer<D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any;
+        deserialize_bool;
+        deserialize_i8;
+        deserialize_i16;
+        deserialize_i32;
+        deserialize_i64;
+        deserialize_i128;
+        deserialize_u8;
+        deserialize_u16;
+        deserialize_u32;
+        deserialize_u64;
+        deserialize_u128;
+        deserialize_f32;
+        deserialize_f64;
+        deserialize_char;
+        deserialize_str;
+        deserialize_string;
+        deserialize_bytes;
+        deserialize_byte_buf;
+        deserialize_option;
+        deserialize_unit;
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_seq;
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map;
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier;
+        deserialize_ignored_any;
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+struct LenVisitor<V> {
+    inner: V,
+    min: usize,
+    max: usize,
+}
+
+impl<V> LenVisitor<V> {
+    fn bounds(&self, unit: (&'static str, &'static str)) -> LenBounds {
+        LenBounds {
+            min: self.min,
+            max: self.max,
+            unit,
+        }
+    }
+}
+
+macro_rules! check_visit {
+    ($($method:ident($ty:ty) => $unit:expr;)*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                self.bounds($unit).check(v.len())?;
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V> Visitor<'de> for LenVisitor<V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    check_visit! {
+        visit_str(&str) => ("byte", "bytes");
+        visit_borrowed_str(&'de str) => ("byte", "bytes");
+        visit_string(String) => ("byte", "bytes");
+        visit_bytes(&[u8]) => ("byte", "bytes");
+        visit_borrowed_bytes(&'de [u8]) => ("byte", "bytes");
+        visit_byte_buf(Vec<u8>) => ("byte", "bytes");
+    }
+
+    forward_visit! {
+        visit_bool(bool);
+        visit_i8(i8);
+        visit_i16(i16);
+        visit_i32(i32);
+        visit_i64(i64);
+        visit_i128(i128);
+        visit_u8(u8);
+        visit_u16(u16);
+        visit_u32(u32);
+        visit_u64(u64);
+        visit_u128(u128);
+        visit_f32(f32);
+        visit_f64(f64);
+        visit_char(char);
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        self.inner.visit_none()
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        self.inner.visit_unit()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.inner.visit_some(LenDeserializer {
+            inner: deserializer,
+            min: self.min,
+            max: self.max,
+        })
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.inner.visit_newtype_struct(LenDeserializer {
+            inner: deserializer,
+            min: self.min,
+            max: self.max,
+        })
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let bounds = self.bounds(("element", "elements"));
+        // Reject known oversized inputs before the inner visitor allocates anything
+        if let Some(len) = seq.size_hint() {
+            if len > bounds.max {
+                return Err(DeError::invalid_length(len, &bounds));
+            }
+        }
+        let mut count = 0;
+        let value = self.inner.visit_seq(LenSeqAccess {
+            inner: seq,
+            count: &mut count,
+            bounds,
+        })?;
+        bounds.check(count)?;
+        Ok(value)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let bounds = self.bounds(("entry", "entries"));
+        if let Some(len) = map.size_hint() {
+            if len > bounds.max {
+                return Err(DeError::invalid_length(len, &bounds));
+            }
+        }
+        let mut count = 0;
+        let value = self.inner.visit_map(LenMapAccess {
+            inner: map,
+            count: &mut count,
+            bounds,
+        })?;
+        bounds.check(count)?;
+        Ok(value)
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        self.inner.visit_enum(data)
+    }
+}
+
+/// Count the elements and fail on the first element exceeding the maximum
+struct LenSeqAccess<'a, A> {
+    inner: A,
+    count: &'a mut usize,
+    bounds: LenBounds,
+}
+
+impl<'a, 'de, A> SeqAccess<'de> for LenSeqAccess<'a, A>
+where
+    A: SeqAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if *self.count == self.bounds.max {
+            // Only check if there is another element, without keeping it
+            return match self.inner.next_element::<IgnoredAny>()? {
+                Some(IgnoredAny) => Err(DeError::invalid_length(self.bounds.max + 1, &self.bounds)),
+                None => Ok(None),
+            };
+        }
+        let value = self.inner.next_element_seed(seed)?;
+        if value.is_some() {
+            *self.count += 1;
+        }
+        Ok(value)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner
+            .size_hint()
+            .map(|len| len.min(self.bounds.max - *self.count))
+    }
+}
+
+/// Count the entries and fail on the first entry exceeding the maximum
+struct LenMapAccess<'a, A> {
+    inner: A,
+    count: &'a mut usize,
+    bounds: LenBounds,
+}
+
+impl<'a, 'de, A> MapAccess<'de> for LenMapAccess<'a, A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        if *self.count == self.bounds.max {
+            return match self.inner.next_key::<IgnoredAny>()? {
+                Some(IgnoredAny) => Err(DeError::invalid_length(self.bounds.max + 1, &self.bounds)),
+                None => Ok(None),
+            };
+        }
+        let key = self.inner.next_key_seed(seed)?;
+        if key.is_some() {
+            *self.count += 1;
+        }
+        Ok(key)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        self.inner.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner
+            .size_hint()
+            .map(|len| len.min(self.bounds.max - *self.count))
+    }
+}
+
+fn deserialize_len<'de, D, T, TAs>(deserializer: D, min: usize, max: usize) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    TAs: DeserializeAs<'de, T>,
+{
+    TAs::deserialize_as(LenDeserializer {
+        inner: deserializer,
+        min,
+        max,
+    })
+}
+
+macro_rules! len_impl {
+    ($ty:ty $(, $typaram:ident)* $(; $lt:lifetime)?) => {
+        impl<'de, $($lt,)? TAs, const N: usize $(, $typaram)*> DeserializeAs<'de, $ty> for MaxLen<N, TAs>
+        where
+            TAs: DeserializeAs<'de, $ty>,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserialize_len::<D, $ty, TAs>(deserializer, 0, N)
+            }
+        }
+
+        impl<'de, $($lt,)? TAs, const MIN: usize, const MAX: usize $(, $typaram)*> DeserializeAs<'de, $ty>
+            for LenBetween<MIN, MAX, TAs>
+        where
+            TAs: DeserializeAs<'de, $ty>,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserialize_len::<D, $ty, TAs>(deserializer, MIN, MAX)
+            }
+        }
+    };
+}
+
+macro_rules! seq_len_impl {
+    (
+        $ty:ident < T $(: $tbound1:ident $(+ $tbound2:ident)*)? $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)* )* >,
+        $with_capacity:expr,
+        $append:ident
+    ) => {
+        len_impl!($ty<T $(, $typaram)*>, T $(, $typaram)*);
+    };
+}
+foreach_seq!(seq_len_impl);
+
+macro_rules! map_len_impl {
+    (
+        $ty:ident < K $(: $kbound1:ident $(+ $kbound2:ident)*)?, V $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)*)* >,
+        $with_capacity:expr
+    ) => {
+        len_impl!($ty<K, V $(, $typaram)*>, K, V $(, $typaram)*);
+    };
+}
+foreach_map!(map_len_impl);
+
+len_impl!(String);
+len_impl!(Box<str>);
+len_impl!(Cow<'a, str>; 'a);
+len_impl!(Cow<'a, [u8]>; 'a);
@@ -0,0 +1,356 @@
+//! Describe duplicate keys and values in error messages
+//!
+//! The key types are arbitrary, so they do not necessarily implement `Debug` or `Display`.
+//! Instead of formatting the deserialized value, the [`DescribeSeed`] wraps the [`Deserializer`] and records the scalar value it passes to the visitor.
+//! The recording is fully transparent, the visitor still sees the same calls.
+//! This keeps working for all key types and does not require `std`.
+//!
+//! Buffered content, e.g., the keys of [`KeyValueMap`](crate::KeyValueMap), is described with [`Scalar::from_content`], such that all keys are formatted the same way.
+//!
+//! The recorded [`Scalar`] is only formatted once a duplicate is found, so the common case without duplicates does not allocate.
+
+use crate::{content::de::Content as DeContent, prelude::*};
+
+/// Maximum number of bytes kept from strings which are not borrowed from the input
+const INLINE_LEN: usize = 64;
+
+/// A scalar value passed to the visitor, which describes the value in error messages
+pub(crate) enum Scalar<'de> {
+    Bool(bool),
+    I64(i64),
+    I128(i128),
+    U64(u64),
+    U128(u128),
+    F32(f32),
+    F64(f64),
+    Char(char),
+    Borrowed(&'de str),
+    /// A transient string, copied into an inline buffer and truncated if necessary
+    Inline {
+        buf: [u8; INLINE_LEN],
+        len: usize,
+        truncated: bool,
+    },
+}
+
+impl<'de> Scalar<'de> {
+    /// Describe buffered content in the same way as the values recorded by [`DescribeSeed`]
+    ///
+    /// Only scalar values are described, `None` is returned for all other content.
+    pub(crate) fn from_content(content: &'de DeContent<'_>) -> Option<Self> {
+        Some(match *content {
+            DeContent::Bool(v) => Scalar::Bool(v),
+            DeContent::U8(v) => Scalar::U64(u64::from(v)),
+            DeContent::U16(v) => Scalar::U64(u64::from(v)),
+            DeContent::U32(v) => Scalar::U64(u64::from(v)),
+            DeContent::U64(v) => Scalar::U64(v),
+            DeContent::U128(v) => Scalar::U128(v),
+            DeContent::I8(v) => Scalar::I64(i64::from(v)),
+            DeContent::I16(v) => Scalar::I64(i64::from(v)),
+            DeContent::I32(v) => Scalar::I64(i64::from(v)),
+            DeContent::I64(v) => Scalar::I64(v),
+            DeContent::I128(v) => Scalar::I128(v),
+            DeContent::F32(v) => Scalar::F32(v),
+            DeContent::F64(v) => Scalar::F64(v),
+            DeContent::Char(v) => Scalar::Char(v),
+            DeContent::String(ref v) => Scalar::Borrowed(v),
+            DeContent::Str(v) => Scalar::Borrowed(v),
+            DeContent::Some(ref v) | DeContent::Newtype(ref v) => return Self::from_content(v),
+            _ => return None,
+        })
+    }
+
+    fn inline(value: &str) -> Self {
+        let mut len = value.len().min(INLINE_LEN);
+        while !value.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut buf = [0; INLINE_LEN];
+        buf[..len].copy_from_slice(&value.as_bytes()[..len]);
+        Scalar::Inline {
+            buf,
+            len,
+            truncated: len < value.len(),
+        }
+    }
+}
+
+impl Display for Scalar<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Scalar::Bool(v) => Display::fmt(&v, f),
+            Scalar::I64(v) => Display::fmt(&v, f),
+            Scalar::I128(v) => Display::fmt(&v, f),
+            Scalar::U64(v) => Display::fmt(&v, f),
+            Scalar::U128(v) => Display::fmt(&v, f),
+            Scalar::F32(v) => Display::fmt(&v, f),
+            Scalar::F64(v) => Display::fmt(&v, f),
+            Scalar::Char(v) => fmt::Debug::fmt(&v, f),
+            Scalar::Borrowed(v) => fmt::Debug::fmt(v, f),
+            Scalar::Inline {
+                ref buf,
+                len,
+                truncated,
+            } => {
+                // The buffer is always cut at a char boundary
+                let value = core::str::from_utf8(&buf[..len]).unwrap_or_default();
+                fmt::Debug::fmt(value, f)?;
+                if truncated {
+                    f.write_str("...")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Deserialize a `T` using `TAs` and record a description of the serialized value
+pub(crate) struct DescribeSeed<'a, 'de, T, TAs> {
+    description: &'a mut Option<Scalar<'de>>,
+    marker: PhantomData<(T, TAs)>,
+}
+
+impl<'a, 'de, T, TAs> DescribeSeed<'a, 'de, T, TAs> {
+    pub(crate) fn new(description: &'a mut Option<Scalar<'de>>) -> Self {
+        *description = None;
+        Self {
+            description,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, 'de, T, TAs> DeserializeSeed<'de> for DescribeSeed<'a, 'de, T, TAs>
+where
+    TAs: DeserializeAs<'de, T>,
+{
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        TAs::deserialize_as(DescribeDeserializer {
+            inner: deserializer,
+            description: self.description,
+        })
+    }
+}
+
+/// Create the error for a duplicate key or value
+///
+/// `kind` is either `"key"` or `"value"` and `index` is the position of the entry in the input.
+pub(crate) fn duplicate_error<E, D>(kind: &str, description: Option<D>, index: usize) -> E
+where
+    E: DeError,
+    D: Display,
+{
+    match description {
+        Some(description) => E::custom(format_args!(
+            "invalid entry: found duplicate {kind} {description} at index {index}"
+        )),
+        None => E::custom(format_args!(
+            "invalid entry: found duplicate {kind} at index {index}"
+        )),
+    }
+}
+
+struct DescribeDeserializer<'a, 'de, D> {
+    inner: D,
+    description: &'a mut Option<Scalar<'de>>,
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident $(($($arg:ident: $ty:ty),*))?;)*) => {
+        $(
+            fn $method<V>(self $($(, $arg: $ty)*)?, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.inner.$method($($($arg,)*)? DescribeVisitor {
+                    inner: visitor,
+                    description: self.description,
+                })
+            }
+        )*
+    };
+}
+
+impl<'a, 'de, D> Deserializer<'de> for DescribeDeserializer<'a, 'de, D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any;
+        deserialize_bool;
+        deserialize_i8;
+        deserialize_i16;
+        deserialize_i32;
+        deserialize_i64;
+        deserialize_i128;
+        deserialize_u8;
+        deserialize_u16;
+        deserialize_u32;
+        deserialize_u64;
+        deserialize_u128;
+        deserialize_f32;
+        deserialize_f64;
+        deserialize_char;
+        deserialize_str;
+        deserialize_string;
+        deserialize_bytes;
+        deserialize_byte_buf;
+        deserialize_option;
+        deserialize_unit;
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_seq;
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map;
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier;
+        deserialize_ignored_any;
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+struct DescribeVisitor<'a, 'de, V> {
+    inner: V,
+    description: &'a mut Option<Scalar<'de>>,
+}
+
+macro_rules! record_visit {
+    ($($method:ident($ty:ty) => $record:expr;)*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                #[allow(clippy::redundant_closure_call)]
+                {
+                    *self.description = Some(($record)(v));
+                }
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'a, 'de, V> Visitor<'de> for DescribeVisitor<'a, 'de, V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    record_visit! {
+        visit_bool(bool) => Scalar::Bool;
+        visit_i8(i8) => |v| Scalar::I64(i64::from(v));
+        visit_i16(i16) => |v| Scalar::I64(i64::from(v));
+        visit_i32(i32) => |v| Scalar::I64(i64::from(v));
+        visit_i64(i64) => Scalar::I64;
+        visit_i128(i128) => Scalar::I128;
+        visit_u8(u8) => |v| Scalar::U64(u64::from(v));
+        visit_u16(u16) => |v| Scalar::U64(u64::from(v));
+        visit_u32(u32) => |v| Scalar::U64(u64::from(v));
+        visit_u64(u64) => Scalar::U64;
+        visit_u128(u128) => Scalar::U128;
+        visit_f32(f32) => Scalar::F32;
+        visit_f64(f64) => Scalar::F64;
+        visit_char(char) => Scalar::Char;
+        visit_str(&str) => Scalar::inline;
+        visit_borrowed_str(&'de str) => Scalar::Borrowed;
+    }
+
+    forward_visit! {
+        visit_bytes(&[u8]);
+        visit_borrowed_bytes(&'de [u8]);
+        visit_byte_buf(Vec<u8>);
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        *self.description = Some(Scalar::inline(&v));
+        self.inner.visit_string(v)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        self.inner.visit_none()
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        self.inner.visit_unit()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.inner.visit_some(DescribeDeserializer {
+            inner: deserializer,
+            description: self.description,
+        })
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.inner.visit_newtype_struct(DescribeDeserializer {
+            inner: deserializer,
+            description: self.description,
+        })
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.inner.visit_seq(seq)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.inner.visit_map(map)
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        self.inner.visit_enum(data)
+    }
+}
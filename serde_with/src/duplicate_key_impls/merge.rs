@@ -0,0 +1,186 @@
+use crate::{
+    formats::{DeepMerge, MergeStrategy},
+    prelude::*,
+};
+
+/// Map which merges the values of duplicate keys on insertion
+///
+/// This is the common interface of all supported map types for helpers which need to handle repeated keys, like [`MapMergeDuplicates`](crate::MapMergeDuplicates) or [`KeyValueMapBy`](crate::KeyValueMapBy).
+/// The caller decides in `merge` what happens with the value of a duplicate key.
+/// It is implemented for `BTreeMap`, `HashMap`, and the other supported maps, and can be named as a bound to be generic over the map type.
+pub trait DuplicateInsertsMergeMap<K, V> {
+    /// Create an empty map, which has capacity for `size_hint` entries if known
+    fn new(size_hint: Option<usize>) -> Self;
+
+    /// Insert the value into the map, or merge it into the existing value using `merge`
+    fn insert<F>(&mut self, key: K, value: V, merge: F)
+    where
+        F: FnOnce(&mut V, V);
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> DuplicateInsertsMergeMap<K, V> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn new(size_hint: Option<usize>) -> Self {
+        match size_hint {
+            Some(size) => Self::with_capacity_and_hasher(size, S::default()),
+            None => Self::with_hasher(S::default()),
+        }
+    }
+
+    #[inline]
+    fn insert<F>(&mut self, key: K, value: V, merge: F)
+    where
+        F: FnOnce(&mut V, V),
+    {
+        use std::collections::hash_map::Entry;
+
+        match self.entry(key) {
+            Entry::Occupied(mut occupied) => merge(occupied.get_mut(), value),
+            Entry::Vacant(vacant) => {
+                vacant.insert(value);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "hashbrown_0_14")]
+impl<K, V, S> DuplicateInsertsMergeMap<K, V> for hashbrown_0_14::HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn new(size_hint: Option<usize>) -> Self {
+        match size_hint {
+            Some(size) => Self::with_capacity_and_hasher(size, S::default()),
+            None => Self::with_hasher(S::default()),
+        }
+    }
+
+    #[inline]
+    fn insert<F>(&mut self, key: K, value: V, merge: F)
+    where
+        F: FnOnce(&mut V, V),
+    {
+        use hashbrown_0_14::hash_map::Entry;
+
+        match self.entry(key) {
+            Entry::Occupied(mut occupied) => merge(occupied.get_mut(), value),
+            Entry::Vacant(vacant) => {
+                vacant.insert(value);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "indexmap_1")]
+impl<K, V, S> DuplicateInsertsMergeMap<K, V> for indexmap_1::IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn new(size_hint: Option<usize>) -> Self {
+        match size_hint {
+            Some(size) => Self::with_capacity_and_hasher(size, S::default()),
+            None => Self::with_hasher(S::default()),
+        }
+    }
+
+    #[inline]
+    fn insert<F>(&mut self, key: K, value: V, merge: F)
+    where
+        F: FnOnce(&mut V, V),
+    {
+        use indexmap_1::map::Entry;
+
+        match self.entry(key) {
+            Entry::Occupied(mut occupied) => merge(occupied.get_mut(), value),
+            Entry::Vacant(vacant) => {
+                vacant.insert(value);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "indexmap_2")]
+impl<K, V, S> DuplicateInsertsMergeMap<K, V> for indexmap_2::IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn new(size_hint: Option<usize>) -> Self {
+        match size_hint {
+            Some(size) => Self::with_capacity_and_hasher(size, S::default()),
+            None => Self::with_hasher(S::default()),
+        }
+    }
+
+    #[inline]
+    fn insert<F>(&mut self, key: K, value: V, merge: F)
+    where
+        F: FnOnce(&mut V, V),
+    {
+        use indexmap_2::map::Entry;
+
+        match self.entry(key) {
+            Entry::Occupied(mut occupied) => merge(occupied.get_mut(), value),
+            Entry::Vacant(vacant) => {
+                vacant.insert(value);
+            }
+        }
+    }
+}
+
+impl<K, V> DuplicateInsertsMergeMap<K, V> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    #[inline]
+    fn new(_size_hint: Option<usize>) -> Self {
+        Self::new()
+    }
+
+    #[inline]
+    fn insert<F>(&mut self, key: K, value: V, merge: F)
+    where
+        F: FnOnce(&mut V, V),
+    {
+        use alloc::collections::btree_map::Entry;
+
+        match self.entry(key) {
+            Entry::Occupied(mut occupied) => merge(occupied.get_mut(), value),
+            Entry::Vacant(vacant) => {
+                vacant.insert(value);
+            }
+        }
+    }
+}
+
+macro_rules! deep_merge_impl {
+    ($(#[$attr:meta])* $ty:ty, $($typaram:ident),*) => {
+        $(#[$attr])*
+        impl<K, V, STRATEGY $(, $typaram)*> MergeStrategy<$ty> for DeepMerge<STRATEGY>
+        where
+            $ty: DuplicateInsertsMergeMap<K, V> + IntoIterator<Item = (K, V)>,
+            STRATEGY: MergeStrategy<V>,
+        {
+            fn merge(existing: &mut $ty, value: $ty) {
+                for (k, v) in value {
+                    DuplicateInsertsMergeMap::insert(existing, k, v, STRATEGY::merge);
+                }
+            }
+        }
+    };
+}
+deep_merge_impl!(BTreeMap<K, V>,);
+deep_merge_impl!(#[cfg(feature = "std")] HashMap<K, V, S>, S);
+deep_merge_impl!(#[cfg(feature = "hashbrown_0_14")] hashbrown_0_14::HashMap<K, V, S>, S);
+deep_merge_impl!(#[cfg(feature = "indexmap_1")] indexmap_1::IndexMap<K, V, S>, S);
+deep_merge_impl!(#[cfg(feature = "indexmap_2")] indexmap_2::IndexMap<K, V, S>, S);
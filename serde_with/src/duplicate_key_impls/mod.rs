@@ -1,9 +1,15 @@
+mod describe;
 mod error_on_duplicate;
 mod first_value_wins;
 mod last_value_wins;
+mod merge;
+mod policy;
 
+pub(crate) use self::describe::{duplicate_error, DescribeSeed, Scalar};
 pub use self::{
     error_on_duplicate::{PreventDuplicateInsertsMap, PreventDuplicateInsertsSet},
     first_value_wins::DuplicateInsertsFirstWinsMap,
     last_value_wins::DuplicateInsertsLastWinsSet,
+    merge::DuplicateInsertsMergeMap,
+    policy::DuplicateKeyPolicy,
 };
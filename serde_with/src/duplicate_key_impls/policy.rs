@@ -0,0 +1,90 @@
+use super::{duplicate_error, DuplicateInsertsMergeMap};
+use crate::{
+    formats::{FirstValueWins, LastValueWins, PreventDuplicates},
+    prelude::*,
+};
+
+/// Insert entries into a map according to a duplicate key policy
+///
+/// The policy is selected with one of the marker types [`PreventDuplicates`], [`FirstValueWins`], or [`LastValueWins`].
+/// It is used by [`MapNormalizeKeys`](crate::MapNormalizeKeys), [`KeyValueMap`](crate::KeyValueMap), and [`KeyValueMapBy`](crate::KeyValueMapBy).
+/// Name this trait as a bound to be generic over the policy.
+pub trait DuplicateKeyPolicy {
+    /// Insert the entry into the map
+    ///
+    /// `description` names the key in the error message for a duplicate key.
+    /// It is only formatted if the key is a duplicate.
+    fn insert<MAP, K, V, D, E>(
+        map: &mut MAP,
+        key: K,
+        value: V,
+        index: usize,
+        description: Option<D>,
+    ) -> Result<(), E>
+    where
+        MAP: DuplicateInsertsMergeMap<K, V>,
+        D: Display,
+        E: DeError;
+}
+
+impl DuplicateKeyPolicy for PreventDuplicates {
+    #[inline]
+    fn insert<MAP, K, V, D, E>(
+        map: &mut MAP,
+        key: K,
+        value: V,
+        index: usize,
+        description: Option<D>,
+    ) -> Result<(), E>
+    where
+        MAP: DuplicateInsertsMergeMap<K, V>,
+        D: Display,
+        E: DeError,
+    {
+        let mut is_duplicate = false;
+        map.insert(key, value, |_, _| is_duplicate = true);
+        if is_duplicate {
+            return Err(duplicate_error("key", description, index));
+        }
+        Ok(())
+    }
+}
+
+impl DuplicateKeyPolicy for FirstValueWins {
+    #[inline]
+    fn insert<MAP, K, V, D, E>(
+        map: &mut MAP,
+        key: K,
+        value: V,
+        _index: usize,
+        _description: Option<D>,
+    ) -> Result<(), E>
+    where
+        MAP: DuplicateInsertsMergeMap<K, V>,
+        D: Display,
+        E: DeError,
+    {
+        // we want to keep the first value, so do nothing
+        map.insert(key, value, |_, _| {});
+        Ok(())
+    }
+}
+
+impl DuplicateKeyPolicy for LastValueWins {
+    #[inline]
+    fn insert<MAP, K, V, D, E>(
+        map: &mut MAP,
+        key: K,
+        value: V,
+        _index: usize,
+        _description: Option<D>,
+    ) -> Result<(), E>
+    where
+        MAP: DuplicateInsertsMergeMap<K, V>,
+        D: Display,
+        E: DeError,
+    {
+        map.insert(key, value, |existing, value| *existing = value);
+        Ok(())
+    }
+}
@@ -1,17 +1,34 @@
 use crate::{
-    content::ser::{Content, ContentSerializer},
+    content::{
+        de::{Content as DeContent, ContentDeserializer},
+        ser::{Content, ContentSerializer},
+    },
+    duplicate_key_impls::{duplicate_error, Scalar},
+    formats::{AdjacentlyTagged, FieldName, InternallyTagged},
     prelude::*,
 };
+#[cfg(feature = "hashbrown_0_14")]
+use hashbrown_0_14::{HashMap as HashbrownMap014, HashSet as HashbrownSet014};
+#[cfg(feature = "indexmap_1")]
+use indexmap_1::{IndexMap, IndexSet};
+#[cfg(feature = "indexmap_2")]
+use indexmap_2::{IndexMap as IndexMap2, IndexSet as IndexSet2};
 
 /// Represent a list of enum values as a map.
 ///
-/// This **only works** if the enum uses the default *externally tagged* representation.
-/// Other enum representations are not supported.
-///
 /// serde data formats often represent *externally tagged* enums as maps with a single key.
 /// The key is the enum variant name, and the value is the variant value.
 /// Sometimes a map with multiple keys should be treated like a list of enum values.
 ///
+/// This **only works** if the enum uses the default *externally tagged* representation.
+/// Use [`TaggedEnumMap`] for *internally* and *adjacently tagged* enums.
+///
+/// Besides `Vec`, the enum values can be collected into sets, like `BTreeSet` or `HashSet`, and into maps, like `BTreeMap` or `HashMap`.
+/// The map key is the variant name, which allows looking up the value of a variant directly.
+/// Repeated variants are kept in a `Vec`, while maps keep the last value.
+/// Use [`EnumMapUnique`] to reject repeated variants.
+/// Sets and maps are only supported for *externally tagged* enums.
+///
 /// # Examples
 ///
 /// ## JSON Map with multiple keys
@@ -174,39 +191,619 @@ where
     where
         D: Deserializer<'de>,
     {
-        struct EnumMapVisitor<T> {
-            is_human_readable: bool,
-            phantom: PhantomData<T>,
+        deserialize_enum_seq(deserializer)
+    }
+}
+
+/// Represent a list of enum values as a map, but reject repeated enum variants.
+///
+/// This works like [`EnumMap`] for *externally tagged* enums, but deserialization fails if the same variant occurs more than once.
+/// This is useful for settings, where each variant configures one option, which may only be set once.
+///
+/// The enum values can be collected into a `Vec`, into sets, like `BTreeSet` or `HashSet`, and into maps, like `BTreeMap` or `HashMap`.
+/// For maps, the key is the variant name.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use std::collections::BTreeMap;
+/// use serde_with::{serde_as, EnumMapUnique};
+///
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// enum Setting {
+///     Verbose(bool),
+///     Threads(u32),
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Settings {
+///     #[serde_as(as = "EnumMapUnique")]
+///     settings: BTreeMap<String, Setting>,
+/// }
+///
+/// let settings = Settings {
+///     settings: BTreeMap::from([
+///         ("Threads".to_string(), Setting::Threads(4)),
+///         ("Verbose".to_string(), Setting::Verbose(true)),
+///     ]),
+/// };
+/// let json = json!({
+///     "settings": {
+///         "Threads": 4,
+///         "Verbose": true,
+///     },
+/// });
+///
+/// assert_eq!(json, serde_json::to_value(&settings).unwrap());
+/// assert_eq!(settings, serde_json::from_value(json).unwrap());
+///
+/// // Repeated variants are rejected
+/// let res: Result<Settings, _> =
+///     serde_json::from_str(r#"{"settings": {"Threads": 4, "Threads": 8}}"#);
+/// assert_eq!(
+///     res.unwrap_err().to_string(),
+///     r#"invalid entry: found duplicate key "Threads" at index 1 at line 1 column 41"#,
+/// );
+/// # }
+/// ```
+pub struct EnumMapUnique;
+
+impl<T> SerializeAs<Vec<T>> for EnumMapUnique
+where
+    T: Serialize,
+{
+    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        source.serialize(SeqAsMapSerializer(serializer))
+    }
+}
+
+impl<'de, T> DeserializeAs<'de, Vec<T>> for EnumMapUnique
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let entries = deserialize_enum_entries::<T, D>(deserializer, true)?;
+        Ok(entries.into_iter().map(|(_, value)| value).collect())
+    }
+}
+
+macro_rules! enum_map_set {
+    (
+        $(#[$attr:meta])*
+        $ty:ident < T $(, $typaram:ident)* >
+    ) => {
+        $(#[$attr])*
+        impl<T $(, $typaram)*> SerializeAs<$ty<T $(, $typaram)*>> for EnumMap
+        where
+            T: Serialize,
+        {
+            fn serialize_as<S>(source: &$ty<T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                SeqAsMapSerializer(serializer).collect_seq(source)
+            }
+        }
+
+        $(#[$attr])*
+        impl<'de, T $(, $typaram)*> DeserializeAs<'de, $ty<T $(, $typaram)*>> for EnumMap
+        where
+            $ty<T $(, $typaram)*>: Deserialize<'de>,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<T $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserialize_enum_seq(deserializer)
+            }
         }
 
-        impl<'de, T> Visitor<'de> for EnumMapVisitor<T>
+        $(#[$attr])*
+        impl<T $(, $typaram)*> SerializeAs<$ty<T $(, $typaram)*>> for EnumMapUnique
+        where
+            T: Serialize,
+        {
+            fn serialize_as<S>(source: &$ty<T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                SeqAsMapSerializer(serializer).collect_seq(source)
+            }
+        }
+
+        $(#[$attr])*
+        impl<'de, T $(, $typaram)*> DeserializeAs<'de, $ty<T $(, $typaram)*>> for EnumMapUnique
         where
             T: Deserialize<'de>,
+            $ty<T $(, $typaram)*>: FromIterator<T>,
         {
-            type Value = Vec<T>;
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<T $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let entries = deserialize_enum_entries::<T, D>(deserializer, true)?;
+                Ok(entries.into_iter().map(|(_, value)| value).collect())
+            }
+        }
+    };
+}
+enum_map_set!(BTreeSet<T>);
+enum_map_set!(
+    #[cfg(feature = "std")]
+    HashSet<T, H>
+);
+enum_map_set!(
+    #[cfg(feature = "hashbrown_0_14")]
+    HashbrownSet014<T, H>
+);
+enum_map_set!(
+    #[cfg(feature = "indexmap_1")]
+    IndexSet<T, H>
+);
+enum_map_set!(
+    #[cfg(feature = "indexmap_2")]
+    IndexSet2<T, H>
+);
+
+macro_rules! enum_map_map {
+    (
+        $(#[$attr:meta])*
+        $ty:ident < K, V $(, $typaram:ident)* >
+    ) => {
+        enum_map_map!(@impl $(#[$attr])* EnumMap, false, $ty<$($typaram),*>);
+        enum_map_map!(@impl $(#[$attr])* EnumMapUnique, true, $ty<$($typaram),*>);
+    };
+    (
+        @impl
+        $(#[$attr:meta])*
+        $adapter:ident, $unique:literal, $ty:ident < $($typaram:ident),* >
+    ) => {
+        $(#[$attr])*
+        impl<K, T $(, $typaram)*> SerializeAs<$ty<K, T $(, $typaram)*>> for $adapter
+        where
+            T: Serialize,
+        {
+            fn serialize_as<S>(source: &$ty<K, T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                SeqAsMapSerializer(serializer).collect_seq(source.values())
+            }
+        }
 
-            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("a map of enum values")
+        $(#[$attr])*
+        impl<'de, K, T $(, $typaram)*> DeserializeAs<'de, $ty<K, T $(, $typaram)*>> for $adapter
+        where
+            K: Deserialize<'de>,
+            T: Deserialize<'de>,
+            $ty<K, T $(, $typaram)*>: FromIterator<(K, T)>,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<K, T $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let is_human_readable = deserializer.is_human_readable();
+                let entries = deserialize_enum_entries::<T, D>(deserializer, $unique)?;
+                entries
+                    .into_iter()
+                    .map(|(variant, value)| {
+                        let key = K::deserialize(ContentDeserializer::new(variant, is_human_readable))?;
+                        Ok((key, value))
+                    })
+                    .collect()
             }
+        }
+    };
+}
+enum_map_map!(BTreeMap<K, V>);
+enum_map_map!(
+    #[cfg(feature = "std")]
+    HashMap<K, V, H>
+);
+enum_map_map!(
+    #[cfg(feature = "hashbrown_0_14")]
+    HashbrownMap014<K, V, H>
+);
+enum_map_map!(
+    #[cfg(feature = "indexmap_1")]
+    IndexMap<K, V, H>
+);
+enum_map_map!(
+    #[cfg(feature = "indexmap_2")]
+    IndexMap2<K, V, H>
+);
+
+/// Deserialize the map of *externally tagged* enum values into the sequence type `C`
+fn deserialize_enum_seq<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+where
+    C: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    struct EnumMapVisitor<C> {
+        is_human_readable: bool,
+        phantom: PhantomData<C>,
+    }
+
+    impl<'de, C> Visitor<'de> for EnumMapVisitor<C>
+    where
+        C: Deserialize<'de>,
+    {
+        type Value = C;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map of enum values")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+            C::deserialize(SeqDeserializer {
+                delegate: map,
+                is_human_readable: self.is_human_readable,
+            })
+        }
+    }
 
-            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
-                Vec::deserialize(SeqDeserializer {
-                    delegate: map,
+    let is_human_readable = deserializer.is_human_readable();
+    deserializer.deserialize_map(EnumMapVisitor {
+        is_human_readable,
+        phantom: PhantomData,
+    })
+}
+
+/// Deserialize the map of *externally tagged* enum values together with the buffered variant names
+///
+/// If `unique` is set, a repeated variant is an error.
+fn deserialize_enum_entries<'de, T, D>(
+    deserializer: D,
+    unique: bool,
+) -> Result<Vec<(DeContent<'de>, T)>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    struct EnumEntriesVisitor<T> {
+        is_human_readable: bool,
+        unique: bool,
+        phantom: PhantomData<T>,
+    }
+
+    impl<'de, T> Visitor<'de> for EnumEntriesVisitor<T>
+    where
+        T: Deserialize<'de>,
+    {
+        type Value = Vec<(DeContent<'de>, T)>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map of enum values")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut entries: Vec<(DeContent<'de>, T)> = Vec::with_capacity(
+                utils::size_hint_cautious::<(DeContent<'de>, T)>(map.size_hint()),
+            );
+            // Indices into `entries`, sorted by the variant
+            let mut sorted: Vec<usize> = Vec::new();
+            while let Some(variant) = map.next_key::<DeContent<'de>>()? {
+                let value = T::deserialize(EnumDeserializer {
+                    delegate: &mut map,
                     is_human_readable: self.is_human_readable,
-                })
+                    variant: Some(variant.clone()),
+                })?;
+                if self.unique {
+                    match sorted.binary_search_by(|&idx| entries[idx].0.total_cmp(&variant)) {
+                        Ok(_) => {
+                            return Err(duplicate_error(
+                                "key",
+                                Scalar::from_content(&variant),
+                                entries.len(),
+                            ));
+                        }
+                        Err(pos) => sorted.insert(pos, entries.len()),
+                    }
+                }
+                entries.push((variant, value));
             }
+            Ok(entries)
         }
-
-        let is_human_readable = deserializer.is_human_readable();
-        deserializer.deserialize_map(EnumMapVisitor {
-            is_human_readable,
-            phantom: PhantomData,
-        })
     }
+
+    let is_human_readable = deserializer.is_human_readable();
+    deserializer.deserialize_map(EnumEntriesVisitor {
+        is_human_readable,
+        unique,
+        phantom: PhantomData,
+    })
 }
 
 static END_OF_MAP_IDENTIFIER: &str = "__PRIVATE_END_OF_MAP_MARKER__";
 
+/// Represent a list of *internally* or *adjacently tagged* enum values as a map
+///
+/// This works like [`EnumMap`], but for enums using a tag field.
+/// The enum representation is selected with `REPR`:
+///
+/// * [`InternallyTagged<TAG>`] for enums with `#[serde(tag = "...")]`.
+///   The value of the tag field becomes the map key and the remaining fields the map value.
+///   The variants must serialize as a map or struct, as required by serde for internally tagged enums.
+/// * [`AdjacentlyTagged<TAG, CONTENT>`] for enums with `#[serde(tag = "...", content = "...")]`.
+///   The value of the tag field becomes the map key and the value of the content field the map value.
+///
+/// The names of the tag and content fields are provided by types implementing [`FieldName`].
+/// Only `Vec` is supported.
+///
+/// [`InternallyTagged<TAG>`]: crate::formats::InternallyTagged
+/// [`AdjacentlyTagged<TAG, CONTENT>`]: crate::formats::AdjacentlyTagged
+/// [`FieldName`]: crate::formats::FieldName
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_with::{
+///     formats::{AdjacentlyTagged, FieldName, InternallyTagged},
+///     serde_as, TaggedEnumMap,
+/// };
+///
+/// struct Type;
+/// impl FieldName for Type {
+///     fn name() -> &'static str {
+///         "type"
+///     }
+/// }
+///
+/// struct Value;
+/// impl FieldName for Value {
+///     fn name() -> &'static str {
+///         "value"
+///     }
+/// }
+///
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// #[serde(tag = "type")]
+/// enum Internal {
+///     Point { x: i32, y: i32 },
+///     Empty,
+/// }
+///
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// #[serde(tag = "type", content = "value")]
+/// enum Adjacent {
+///     Int(i32),
+///     Pair(i32, i32),
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Values {
+///     #[serde_as(as = "TaggedEnumMap<InternallyTagged<Type>>")]
+///     internal: Vec<Internal>,
+///     #[serde_as(as = "TaggedEnumMap<AdjacentlyTagged<Type, Value>>")]
+///     adjacent: Vec<Adjacent>,
+/// }
+///
+/// let values = Values {
+///     internal: vec![Internal::Point { x: 1, y: 2 }, Internal::Empty],
+///     adjacent: vec![Adjacent::Int(1), Adjacent::Pair(2, 3)],
+/// };
+/// let json = json!({
+///     "internal": {
+///         "Point": {"x": 1, "y": 2},
+///         "Empty": {},
+///     },
+///     "adjacent": {
+///         "Int": 1,
+///         "Pair": [2, 3],
+///     },
+/// });
+///
+/// assert_eq!(json, serde_json::to_value(&values).unwrap());
+/// assert_eq!(values, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+pub struct TaggedEnumMap<REPR>(PhantomData<REPR>);
+
+// Internally and adjacently tagged enums are converted through the `content` types.
+// Each element is buffered and the tag field is split off as the map key, or added back during deserialization.
+
+impl<TAG, T> SerializeAs<Vec<T>> for TaggedEnumMap<InternallyTagged<TAG>>
+where
+    TAG: FieldName,
+    T: Serialize,
+{
+    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_tagged(source, serializer, TAG::name(), None)
+    }
+}
+
+impl<'de, TAG, T> DeserializeAs<'de, Vec<T>> for TaggedEnumMap<InternallyTagged<TAG>>
+where
+    TAG: FieldName,
+    T: Deserialize<'de>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_tagged(deserializer, TAG::name(), None)
+    }
+}
+
+impl<TAG, CONTENT, T> SerializeAs<Vec<T>> for TaggedEnumMap<AdjacentlyTagged<TAG, CONTENT>>
+where
+    TAG: FieldName,
+    CONTENT: FieldName,
+    T: Serialize,
+{
+    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_tagged(source, serializer, TAG::name(), Some(CONTENT::name()))
+    }
+}
+
+impl<'de, TAG, CONTENT, T> DeserializeAs<'de, Vec<T>>
+    for TaggedEnumMap<AdjacentlyTagged<TAG, CONTENT>>
+where
+    TAG: FieldName,
+    CONTENT: FieldName,
+    T: Deserialize<'de>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_tagged(deserializer, TAG::name(), Some(CONTENT::name()))
+    }
+}
+
+/// Serialize the elements as map entries, using the value of the `tag` field as the map key
+///
+/// Without a `content` field, the other fields of the element form the map value (internally tagged).
+/// Otherwise, the value of the `content` field is used (adjacently tagged).
+fn serialize_tagged<T, S>(
+    source: &[T],
+    serializer: S,
+    tag: &'static str,
+    content: Option<&'static str>,
+) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    let is_human_readable = serializer.is_human_readable();
+    let mut map = serializer.serialize_map(Some(source.len()))?;
+    for element in source {
+        let element = element.serialize(ContentSerializer::<S::Error>::new(is_human_readable))?;
+        let (key, value) = split_tag(element, tag, content)
+            .ok_or_else(|| S::Error::custom(format_args!("missing tag field `{tag}`")))?;
+        map.serialize_entry(&key, &value)?;
+    }
+    map.end()
+}
+
+/// Split the tagged `element` into the tag value and the remaining value
+fn split_tag(
+    element: Content,
+    tag: &'static str,
+    content: Option<&'static str>,
+) -> Option<(Content, Content)> {
+    let mut key = None;
+    let value = match element {
+        Content::Struct(name, mut fields) => {
+            let pos = fields.iter().position(|(field, _)| *field == tag)?;
+            key = Some(fields.remove(pos).1);
+            match content {
+                None => Content::Struct(name, fields),
+                Some(content) => fields
+                    .into_iter()
+                    .find(|(field, _)| *field == content)
+                    .map_or(Content::Unit, |(_, value)| value),
+            }
+        }
+        Content::Map(mut entries) => {
+            let pos = entries
+                .iter()
+                .position(|(field, _)| field.as_str() == Some(tag))?;
+            key = Some(entries.remove(pos).1);
+            match content {
+                None => Content::Map(entries),
+                Some(content) => entries
+                    .into_iter()
+                    .find(|(field, _)| field.as_str() == Some(content))
+                    .map_or(Content::Unit, |(_, value)| value),
+            }
+        }
+        _ => Content::Unit,
+    };
+    key.map(|key| (key, value))
+}
+
+fn deserialize_tagged<'de, T, D>(
+    deserializer: D,
+    tag: &'static str,
+    content: Option<&'static str>,
+) -> Result<Vec<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    struct TaggedEnumMapVisitor<T> {
+        is_human_readable: bool,
+        tag: &'static str,
+        content: Option<&'static str>,
+        phantom: PhantomData<T>,
+    }
+
+    impl<'de, T> Visitor<'de> for TaggedEnumMapVisitor<T>
+    where
+        T: Deserialize<'de>,
+    {
+        type Value = Vec<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map of enum values")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut values = Vec::with_capacity(utils::size_hint_cautious::<T>(map.size_hint()));
+            while let Some((key, value)) = map.next_entry::<DeContent<'de>, DeContent<'de>>()? {
+                let tag = (DeContent::Str(self.tag), key);
+                let element = match self.content {
+                    Some(content) => {
+                        DeContent::Map(alloc::vec![tag, (DeContent::Str(content), value)])
+                    }
+                    None => match value {
+                        DeContent::Map(mut entries) => {
+                            entries.insert(0, tag);
+                            DeContent::Map(entries)
+                        }
+                        DeContent::Unit | DeContent::None => DeContent::Map(alloc::vec![tag]),
+                        value => {
+                            return Err(DeError::invalid_type(
+                                value.unexpected(),
+                                &"a map of the variant fields",
+                            ))
+                        }
+                    },
+                };
+                values.push(T::deserialize(ContentDeserializer::new(
+                    element,
+                    self.is_human_readable,
+                ))?);
+            }
+            Ok(values)
+        }
+    }
+
+    let is_human_readable = deserializer.is_human_readable();
+    deserializer.deserialize_map(TaggedEnumMapVisitor {
+        is_human_readable,
+        tag,
+        content,
+        phantom: PhantomData,
+    })
+}
+
 // Serialization code below here
 
 /// Convert a sequence to a map during serialization.
@@ -762,6 +1359,7 @@ where
         match seed.deserialize(EnumDeserializer {
             delegate: &mut self.delegate,
             is_human_readable: self.is_human_readable,
+            variant: None,
         }) {
             Ok(value) => Ok(Some(value)),
             Err(err) => {
@@ -785,12 +1383,15 @@ where
 /// The [`Deserializer`] implementation is the starting point, which first calls the [`EnumAccess`] methods.
 /// The [`EnumAccess`] is used to deserialize the enum variant type of the enum.
 /// The [`VariantAccess`] is used to deserialize the value part of the enum.
-struct EnumDeserializer<M> {
+///
+/// If `variant` is set, the map key was already read and is used as the enum variant.
+struct EnumDeserializer<'de, M> {
     delegate: M,
     is_human_readable: bool,
+    variant: Option<DeContent<'de>>,
 }
 
-impl<'de, M> Deserializer<'de> for EnumDeserializer<M>
+impl<'de, M> Deserializer<'de> for EnumDeserializer<'de, M>
 where
     M: MapAccess<'de>,
 {
@@ -826,7 +1427,7 @@ where
     }
 }
 
-impl<'de, M> EnumAccess<'de> for EnumDeserializer<M>
+impl<'de, M> EnumAccess<'de> for EnumDeserializer<'de, M>
 where
     M: MapAccess<'de>,
 {
@@ -837,6 +1438,11 @@ where
     where
         T: DeserializeSeed<'de>,
     {
+        if let Some(variant) = self.variant.take() {
+            let key =
+                seed.deserialize(ContentDeserializer::new(variant, self.is_human_readable))?;
+            return Ok((key, self));
+        }
         match self.delegate.next_key_seed(seed)? {
             Some(key) => Ok((key, self)),
 
@@ -846,7 +1452,7 @@ where
     }
 }
 
-impl<'de, M> VariantAccess<'de> for EnumDeserializer<M>
+impl<'de, M> VariantAccess<'de> for EnumDeserializer<'de, M>
 where
     M: MapAccess<'de>,
 {
@@ -0,0 +1,346 @@
+use crate::{
+    content::{
+        de::{Content as DeContent, ContentDeserializer},
+        ser::{Content, ContentSerializer},
+    },
+    formats::{ArrayFlattening, Separator},
+    prelude::*,
+};
+
+/// Flatten nested structs and maps into a single map with joined keys
+///
+/// Environment variables, metric labels, and many configuration systems use flat keys like `db.pool.max_size`.
+/// During serialization, nested structs and maps are flattened, such that the keys of all levels are joined by the `SEP` separator.
+/// During deserialization, the keys are split at the separator and the nesting is rebuilt.
+///
+/// The separator defaults to [`DotSeparator`], but any [`Separator`] can be used.
+/// Keys which contain the separator themselves cannot be split unambiguously and are rejected during serialization.
+/// The value must serialize as a struct or a map, and the keys of nested maps must be strings.
+/// `Option` and newtype wrappers are transparent, and enum variants are flattened like a map with a single entry.
+///
+/// The `ARRAYS` option determines how sequences are handled:
+///
+/// * [`KeepArrays`], the default, keeps sequences as values, like `{"items": [1, 2]}`.
+/// * [`IndexArrays`] flattens the elements with their index as key, like `{"items.0.name": "a", "items.1.name": "b"}`.
+///
+/// With [`IndexArrays`], any nested map whose keys are exactly the indices `0` to `n - 1` is read back as a sequence.
+/// Empty structs, maps, and sequences are kept as values, since they have no keys to flatten.
+///
+/// [`DotSeparator`]: crate::formats::DotSeparator
+/// [`Separator`]: crate::formats::Separator
+/// [`KeepArrays`]: crate::formats::KeepArrays
+/// [`IndexArrays`]: crate::formats::IndexArrays
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_with::{formats::{DotSeparator, IndexArrays}, serde_as, FlattenKeys};
+///
+/// # #[derive(Debug, Clone, PartialEq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Pool {
+///     max_size: u32,
+///     timeout: Option<u32>,
+/// }
+///
+/// # #[derive(Debug, Clone, PartialEq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Database {
+///     url: String,
+///     pool: Pool,
+///     replicas: Vec<String>,
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, Clone, PartialEq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde_as(as = "FlattenKeys")]
+///     db: Database,
+///     #[serde_as(as = "FlattenKeys<DotSeparator, IndexArrays>")]
+///     cache: Database,
+/// }
+///
+/// let database = |url: &str| Database {
+///     url: url.into(),
+///     pool: Pool {
+///         max_size: 10,
+///         timeout: None,
+///     },
+///     replicas: vec!["a".into(), "b".into()],
+/// };
+/// let config = Config {
+///     db: database("postgres://"),
+///     cache: database("redis://"),
+/// };
+/// let json = json!({
+///     "db": {
+///         "url": "postgres://",
+///         "pool.max_size": 10,
+///         "pool.timeout": null,
+///         "replicas": ["a", "b"],
+///     },
+///     "cache": {
+///         "url": "redis://",
+///         "pool.max_size": 10,
+///         "pool.timeout": null,
+///         "replicas.0": "a",
+///         "replicas.1": "b",
+///     },
+/// });
+/// assert_eq!(json, serde_json::to_value(&config).unwrap());
+/// assert_eq!(config, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+pub struct FlattenKeys<SEP = formats::DotSeparator, ARRAYS = formats::KeepArrays>(
+    PhantomData<(SEP, ARRAYS)>,
+);
+
+impl<T, SEP, ARRAYS> SerializeAs<T> for FlattenKeys<SEP, ARRAYS>
+where
+    T: Serialize,
+    SEP: Separator,
+    ARRAYS: ArrayFlattening,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let content = source.serialize(ContentSerializer::<S::Error>::new(
+            serializer.is_human_readable(),
+        ))?;
+        let mut entries = Vec::new();
+        flatten::<S::Error>(
+            &mut entries,
+            None,
+            content,
+            SEP::separator(),
+            ARRAYS::index_arrays(),
+        )?;
+        serializer.collect_map(entries)
+    }
+}
+
+impl<'de, T, SEP, ARRAYS> DeserializeAs<'de, T> for FlattenKeys<SEP, ARRAYS>
+where
+    T: Deserialize<'de>,
+    SEP: Separator,
+    ARRAYS: ArrayFlattening,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct FlattenKeysVisitor {
+            separator: &'static str,
+        }
+
+        impl<'de> Visitor<'de> for FlattenKeysVisitor {
+            type Value = Level<'de>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a map with flattened keys")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut nodes = Level::default();
+                while let Some((key, value)) = map.next_entry::<DeContent<'de>, DeContent<'de>>()? {
+                    let key = key
+                        .as_str()
+                        .ok_or_else(|| DeError::invalid_type(key.unexpected(), &"a string key"))?;
+                    let path: Vec<&str> = key.split(self.separator).collect();
+                    insert(&mut nodes, key, &path, value)?;
+                }
+                Ok(nodes)
+            }
+        }
+
+        let is_human_readable = deserializer.is_human_readable();
+        let nodes = deserializer.deserialize_map(FlattenKeysVisitor {
+            separator: SEP::separator(),
+        })?;
+        T::deserialize(ContentDeserializer::new(
+            nodes_into_map(nodes.entries, ARRAYS::index_arrays()),
+            is_human_readable,
+        ))
+    }
+}
+
+/// Flatten the value into `entries`, prefixing all keys with `key`
+fn flatten<E>(
+    entries: &mut Vec<(String, Content)>,
+    key: Option<String>,
+    value: Content,
+    separator: &str,
+    index_arrays: bool,
+) -> Result<(), E>
+where
+    E: SerError,
+{
+    let children: Vec<(String, Content)> = match value {
+        Content::Some(value) | Content::NewtypeStruct(_, value) => {
+            return flatten(entries, key, *value, separator, index_arrays)
+        }
+        Content::Struct(_, fields) => fields
+            .into_iter()
+            .map(|(field, value)| (field.into(), value))
+            .collect(),
+        Content::Map(map) => map
+            .into_iter()
+            .map(|(key, value)| Ok((map_key(key)?, value)))
+            .collect::<Result<_, E>>()?,
+        Content::NewtypeVariant(_, _, variant, value) => alloc::vec![(variant.into(), *value)],
+        Content::TupleVariant(_, _, variant, elements) => {
+            alloc::vec![(variant.into(), Content::Seq(elements))]
+        }
+        Content::StructVariant(_, _, variant, fields) => {
+            alloc::vec![(variant.into(), Content::Struct(variant, fields))]
+        }
+        Content::Seq(elements) | Content::Tuple(elements) | Content::TupleStruct(_, elements)
+            if index_arrays && !elements.is_empty() =>
+        {
+            elements
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| (index.to_string(), value))
+                .collect()
+        }
+        value => {
+            return match key {
+                Some(key) => {
+                    entries.push((key, value));
+                    Ok(())
+                }
+                None => Err(E::custom("the value must serialize as a struct or a map")),
+            }
+        }
+    };
+
+    if children.is_empty() {
+        if let Some(key) = key {
+            entries.push((key, Content::Map(Vec::new())));
+        }
+        return Ok(());
+    }
+    for (child, value) in children {
+        if child.contains(separator) {
+            return Err(E::custom(format_args!(
+                "the key {child:?} contains the separator {separator:?}"
+            )));
+        }
+        let child = match &key {
+            Some(key) => alloc::format!("{key}{separator}{child}"),
+            None => child,
+        };
+        flatten(entries, Some(child), value, separator, index_arrays)?;
+    }
+    Ok(())
+}
+
+fn map_key<E>(key: Content) -> Result<String, E>
+where
+    E: SerError,
+{
+    match key {
+        Content::String(key) => Ok(key),
+        Content::Char(key) => Ok(key.to_string()),
+        Content::UnitVariant(_, _, variant) => Ok(variant.into()),
+        Content::NewtypeStruct(_, key) => map_key(*key),
+        _ => Err(E::custom("the map keys must be strings")),
+    }
+}
+
+/// A nested map rebuilt from the flattened keys
+enum Node<'de> {
+    Value(DeContent<'de>),
+    Map(Level<'de>),
+}
+
+/// The entries of one nesting level, in the order of their first appearance
+#[derive(Default)]
+struct Level<'de> {
+    entries: Vec<(String, Node<'de>)>,
+    /// Position of each key in `entries`
+    index: BTreeMap<String, usize>,
+}
+
+/// Insert the value at the `path` of the flattened `key`
+fn insert<'de, E>(
+    level: &mut Level<'de>,
+    key: &str,
+    path: &[&str],
+    value: DeContent<'de>,
+) -> Result<(), E>
+where
+    E: DeError,
+{
+    let (first, rest) = path
+        .split_first()
+        .expect("Splitting a string yields at least one part");
+    match level.index.get(*first) {
+        None => {
+            let node = if rest.is_empty() {
+                Node::Value(value)
+            } else {
+                let mut children = Level::default();
+                insert(&mut children, key, rest, value)?;
+                Node::Map(children)
+            };
+            level.index.insert((*first).into(), level.entries.len());
+            level.entries.push(((*first).into(), node));
+        }
+        Some(&pos) => match (&mut level.entries[pos].1, rest.is_empty()) {
+            (Node::Map(children), false) => insert(children, key, rest, value)?,
+            _ => {
+                return Err(E::custom(format_args!(
+                    "the key {key:?} conflicts with a previous key"
+                )))
+            }
+        },
+    }
+    Ok(())
+}
+
+fn nodes_into_map(nodes: Vec<(String, Node<'_>)>, index_arrays: bool) -> DeContent<'_> {
+    DeContent::Map(
+        nodes
+            .into_iter()
+            .map(|(key, node)| (DeContent::String(key), node.into_content(index_arrays)))
+            .collect(),
+    )
+}
+
+impl<'de> Node<'de> {
+    fn into_content(self, index_arrays: bool) -> DeContent<'de> {
+        let nodes = match self {
+            Node::Value(value) => return value,
+            Node::Map(level) => level.entries,
+        };
+        if index_arrays {
+            let len = nodes.len();
+            let indices: Option<Vec<usize>> = nodes
+                .iter()
+                .map(|(key, _)| {
+                    key.parse::<usize>()
+                        .ok()
+                        .filter(|&index| index < len && index.to_string() == *key)
+                })
+                .collect();
+            // The indices are distinct and smaller than `len`, so they are exactly `0..len`
+            if let Some(indices) = indices {
+                let mut elements: Vec<_> = indices.into_iter().zip(nodes).collect();
+                elements.sort_by_key(|(index, _)| *index);
+                return DeContent::Seq(
+                    elements
+                        .into_iter()
+                        .map(|(_, (_, node))| node.into_content(index_arrays))
+                        .collect(),
+                );
+            }
+        }
+        nodes_into_map(nodes, index_arrays)
+    }
+}
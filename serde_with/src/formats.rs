@@ -68,6 +68,8 @@ create_format!(
     Uppercase
     /// Use lowercase characters
     Lowercase
+    /// Use titlecase, i.e., an uppercase first character followed by lowercase characters
+    Titlecase
 
     /// Use in combination with [`OneOrMany`](crate::OneOrMany). Emit single element for lists of size 1.
     PreferOne
@@ -78,6 +80,14 @@ create_format!(
     Padded
     /// Do not emit padding during serialization.
     Unpadded
+
+    /// Use in combination with [`TimestampAny`](crate::TimestampAny). Serialize as an RFC 3339 string in UTC.
+    Rfc3339
+
+    /// Use in combination with [`AsName`](crate::AsName). Use the full name, e.g., `"January"`.
+    FullName
+    /// Use in combination with [`AsName`](crate::AsName). Use the three letter abbreviation, e.g., `"Jan"`.
+    Abbreviated
 );
 
 /// Specify how lenient the deserialization process should be
@@ -93,6 +103,12 @@ impl Strictness for Strict {}
 pub struct Flexible;
 impl Strictness for Flexible {}
 
+/// Use a flexible deserialization behavior, which clamps out-of-range values instead of failing, see [`Strictness`].
+///
+/// It is supported by [`DurationSeconds`](crate::DurationSeconds), [`TimestampSeconds`](crate::TimestampSeconds), and their variants.
+pub struct Saturating;
+impl Strictness for Saturating {}
+
 /// Separator for string-based collection de/serialization
 pub trait Separator {
     /// Return the string delimiting two elements in the string-based collection
@@ -138,3 +154,375 @@ impl Separator for ColonSeparator {
         ":"
     }
 }
+
+/// Predefined separator using a single dot
+pub struct DotSeparator;
+
+impl Separator for DotSeparator {
+    #[inline]
+    fn separator() -> &'static str {
+        "."
+    }
+}
+
+/// Numbering scheme for the days of the week, see [`WeekdayAsNumber`](crate::WeekdayAsNumber)
+///
+/// The days are numbered consecutively, starting with [`first_number`](WeekdayNumbering::first_number) for the day given by [`first_day`](WeekdayNumbering::first_day).
+pub trait WeekdayNumbering {
+    /// Return the day which is numbered first, as number of days since Monday, i.e., `0` for Monday and `6` for Sunday
+    fn first_day() -> u8;
+    /// Return the number of the first day
+    fn first_number() -> u8;
+}
+
+/// Predefined numbering from Monday as `0` to Sunday as `6`
+pub struct MondayZero;
+
+impl WeekdayNumbering for MondayZero {
+    #[inline]
+    fn first_day() -> u8 {
+        0
+    }
+
+    #[inline]
+    fn first_number() -> u8 {
+        0
+    }
+}
+
+/// Predefined numbering from Sunday as `0` to Saturday as `6`
+pub struct SundayZero;
+
+impl WeekdayNumbering for SundayZero {
+    #[inline]
+    fn first_day() -> u8 {
+        6
+    }
+
+    #[inline]
+    fn first_number() -> u8 {
+        0
+    }
+}
+
+/// Predefined ISO 8601 numbering from Monday as `1` to Sunday as `7`
+pub struct MondayOne;
+
+impl WeekdayNumbering for MondayOne {
+    #[inline]
+    fn first_day() -> u8 {
+        0
+    }
+
+    #[inline]
+    fn first_number() -> u8 {
+        1
+    }
+}
+
+/// Strategy to combine the values of duplicate map keys, see [`MapMergeDuplicates`](crate::MapMergeDuplicates)
+///
+/// The predefined strategies are [`Concat`], [`ExtendValues`], [`DeepMerge`], and [`Replace`].
+/// Implement this trait for a custom strategy, e.g., to sum up counters.
+pub trait MergeStrategy<V> {
+    /// Merge the `value` of a duplicate key into the `existing` value
+    fn merge(existing: &mut V, value: V);
+}
+
+/// Predefined strategy keeping only the last value, see [`MergeStrategy`]
+///
+/// This matches the default behavior of maps and is useful as the inner strategy of [`DeepMerge`].
+pub struct Replace;
+
+impl<V> MergeStrategy<V> for Replace {
+    #[inline]
+    fn merge(existing: &mut V, value: V) {
+        *existing = value;
+    }
+}
+
+/// Predefined strategy appending the later value to the existing one, see [`MergeStrategy`]
+///
+/// It supports `Vec`, `VecDeque`, `LinkedList`, and `String`.
+pub struct Concat;
+
+#[cfg(feature = "alloc")]
+impl<T> MergeStrategy<Vec<T>> for Concat {
+    #[inline]
+    fn merge(existing: &mut Vec<T>, mut value: Vec<T>) {
+        existing.append(&mut value);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> MergeStrategy<VecDeque<T>> for Concat {
+    #[inline]
+    fn merge(existing: &mut VecDeque<T>, mut value: VecDeque<T>) {
+        existing.append(&mut value);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> MergeStrategy<LinkedList<T>> for Concat {
+    #[inline]
+    fn merge(existing: &mut LinkedList<T>, mut value: LinkedList<T>) {
+        existing.append(&mut value);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl MergeStrategy<String> for Concat {
+    #[inline]
+    fn merge(existing: &mut String, value: String) {
+        existing.push_str(&value);
+    }
+}
+
+/// Predefined strategy extending the existing value with the elements of the later value, see [`MergeStrategy`]
+///
+/// It supports all types implementing [`Extend`](core::iter::Extend) with their own items, like sets and maps.
+/// For maps, the values of the later map overwrite the existing ones.
+/// Use [`DeepMerge`] to merge them instead.
+pub struct ExtendValues;
+
+impl<V> MergeStrategy<V> for ExtendValues
+where
+    V: IntoIterator + Extend<<V as IntoIterator>::Item>,
+{
+    #[inline]
+    fn merge(existing: &mut V, value: V) {
+        existing.extend(value);
+    }
+}
+
+/// Predefined strategy merging maps recursively, see [`MergeStrategy`]
+///
+/// The entries of the later map are inserted into the existing map.
+/// If both maps contain the same key, the two values are merged using `STRATEGY`.
+/// Nest the strategy like `DeepMerge<DeepMerge<Concat>>` to merge multiple levels of maps.
+///
+/// It supports all map types supported by `serde_with`.
+pub struct DeepMerge<STRATEGY = Replace>(PhantomData<STRATEGY>);
+
+/// Normalize the string keys of a map, see [`MapNormalizeKeys`](crate::MapNormalizeKeys)
+///
+/// The predefined normalizers are [`AsciiLowercase`], [`Lowercase`], [`Uppercase`], and [`Trim`].
+/// Tuples of normalizers apply each normalizer in order, e.g., `(Trim, AsciiLowercase)`.
+#[cfg(feature = "alloc")]
+pub trait KeyNormalizer {
+    /// Return the normalized form of the `key`
+    fn normalize(key: String) -> String;
+}
+
+/// Convert ASCII characters to lowercase, see [`KeyNormalizer`]
+///
+/// Non-ASCII characters are unchanged.
+pub struct AsciiLowercase;
+
+/// Remove leading and trailing whitespace, see [`KeyNormalizer`]
+pub struct Trim;
+
+#[cfg(feature = "alloc")]
+impl KeyNormalizer for AsciiLowercase {
+    #[inline]
+    fn normalize(mut key: String) -> String {
+        key.make_ascii_lowercase();
+        key
+    }
+}
+
+/// Convert all characters to lowercase, using the Unicode case mapping
+#[cfg(feature = "alloc")]
+impl KeyNormalizer for Lowercase {
+    #[inline]
+    fn normalize(key: String) -> String {
+        key.to_lowercase()
+    }
+}
+
+/// Convert all characters to uppercase, using the Unicode case mapping
+#[cfg(feature = "alloc")]
+impl KeyNormalizer for Uppercase {
+    #[inline]
+    fn normalize(key: String) -> String {
+        key.to_uppercase()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl KeyNormalizer for Trim {
+    #[inline]
+    fn normalize(key: String) -> String {
+        let trimmed = key.trim();
+        if trimmed.len() == key.len() {
+            key
+        } else {
+            trimmed.into()
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A, B> KeyNormalizer for (A, B)
+where
+    A: KeyNormalizer,
+    B: KeyNormalizer,
+{
+    #[inline]
+    fn normalize(key: String) -> String {
+        B::normalize(A::normalize(key))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A, B, C> KeyNormalizer for (A, B, C)
+where
+    A: KeyNormalizer,
+    B: KeyNormalizer,
+    C: KeyNormalizer,
+{
+    #[inline]
+    fn normalize(key: String) -> String {
+        C::normalize(B::normalize(A::normalize(key)))
+    }
+}
+
+/// Return an error for duplicate map keys, see [`MapNormalizeKeys`](crate::MapNormalizeKeys)
+///
+/// This matches the behavior of [`MapPreventDuplicates`](crate::MapPreventDuplicates).
+pub struct PreventDuplicates;
+
+/// Keep the first value for duplicate map keys, see [`MapNormalizeKeys`](crate::MapNormalizeKeys)
+///
+/// This matches the behavior of [`MapFirstKeyWins`](crate::MapFirstKeyWins).
+pub struct FirstValueWins;
+
+/// Keep the last value for duplicate map keys, see [`MapNormalizeKeys`](crate::MapNormalizeKeys)
+///
+/// This matches the default behavior of maps.
+pub struct LastValueWins;
+
+#[cfg(feature = "alloc")]
+pub use crate::duplicate_key_impls::{DuplicateInsertsMergeMap, DuplicateKeyPolicy};
+
+/// Name of the field which becomes the map key, see [`KeyValueMapBy`](crate::KeyValueMapBy)
+///
+/// Implement this trait on a marker type for each field name.
+pub trait FieldName {
+    /// Return the name of the struct field or map key
+    fn name() -> &'static str;
+}
+
+/// Specify whether the map key stays in the value, see [`KeyValueMapBy`](crate::KeyValueMapBy)
+pub trait KeyRetention {
+    /// Return `true` if the key is serialized as part of the value too
+    fn keep_key() -> bool;
+}
+
+/// Remove the key from the value, see [`KeyRetention`]
+pub struct RemoveKey;
+
+impl KeyRetention for RemoveKey {
+    #[inline]
+    fn keep_key() -> bool {
+        false
+    }
+}
+
+/// Keep the key in the value, see [`KeyRetention`]
+pub struct KeepKey;
+
+impl KeyRetention for KeepKey {
+    #[inline]
+    fn keep_key() -> bool {
+        true
+    }
+}
+
+/// Use the *internally tagged* enum representation `#[serde(tag = "...")]`, see [`TaggedEnumMap`](crate::TaggedEnumMap)
+///
+/// `TAG` provides the name of the tag field.
+pub struct InternallyTagged<TAG>(PhantomData<TAG>);
+
+/// Use the *adjacently tagged* enum representation `#[serde(tag = "...", content = "...")]`, see [`TaggedEnumMap`](crate::TaggedEnumMap)
+///
+/// `TAG` and `CONTENT` provide the names of the tag and content fields.
+pub struct AdjacentlyTagged<TAG, CONTENT>(PhantomData<(TAG, CONTENT)>);
+
+/// Specify the layout of a [`Table`](crate::Table)
+pub trait TableLayout {
+    /// Return `true` if the column names are the first row, instead of a separate `columns` field
+    fn header_row() -> bool;
+}
+
+/// Use an object with separate `columns` and `rows` fields, like `{"columns": ["a", "b"], "rows": [[1, 2]]}`, see [`TableLayout`]
+pub struct ColumnsAndRows;
+
+impl TableLayout for ColumnsAndRows {
+    #[inline]
+    fn header_row() -> bool {
+        false
+    }
+}
+
+/// Use a list of rows, where the first row contains the column names, like `[["a", "b"], [1, 2]]`, see [`TableLayout`]
+pub struct HeaderRow;
+
+impl TableLayout for HeaderRow {
+    #[inline]
+    fn header_row() -> bool {
+        true
+    }
+}
+
+/// Specify how sequences are flattened, see [`FlattenKeys`](crate::FlattenKeys)
+pub trait ArrayFlattening {
+    /// Return `true` if the sequence elements are flattened into keys with their index
+    fn index_arrays() -> bool;
+}
+
+/// Keep sequences as values, like `{"items": [1, 2]}`, see [`ArrayFlattening`]
+pub struct KeepArrays;
+
+impl ArrayFlattening for KeepArrays {
+    #[inline]
+    fn index_arrays() -> bool {
+        false
+    }
+}
+
+/// Flatten the sequence elements into keys with their index, like `{"items.0": 1, "items.1": 2}`, see [`ArrayFlattening`]
+pub struct IndexArrays;
+
+impl ArrayFlattening for IndexArrays {
+    #[inline]
+    fn index_arrays() -> bool {
+        true
+    }
+}
+
+/// Specify whether map keys serializing as strings keep their JSON quotes, see [`JsonKey`](crate::json::JsonKey)
+pub trait StringQuoting {
+    /// Return `true` if the quotes are removed from strings, which are not valid JSON themselves
+    fn unquoted() -> bool;
+}
+
+/// Keep the JSON quotes of strings, like `"\"a\""`, see [`StringQuoting`]
+pub struct QuotedStrings;
+
+impl StringQuoting for QuotedStrings {
+    #[inline]
+    fn unquoted() -> bool {
+        false
+    }
+}
+
+/// Remove the JSON quotes of strings, like `"a"`, unless the string is valid JSON itself, see [`StringQuoting`]
+pub struct UnquotedStrings;
+
+impl StringQuoting for UnquotedStrings {
+    #[inline]
+    fn unquoted() -> bool {
+        true
+    }
+}
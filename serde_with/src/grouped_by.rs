@@ -0,0 +1,382 @@
+use crate::{
+    content::{
+        de::{Content as DeContent, ContentDeserializer},
+        ser::{Content, ContentSerializer},
+    },
+    duplicate_key_impls::DuplicateInsertsMergeMap,
+    prelude::*,
+};
+use alloc::collections::btree_map;
+#[cfg(feature = "hashbrown_0_14")]
+use hashbrown_0_14::HashMap as HashbrownMap014;
+#[cfg(feature = "indexmap_1")]
+use indexmap_1::IndexMap;
+#[cfg(feature = "indexmap_2")]
+use indexmap_2::IndexMap as IndexMap2;
+
+/// Group the `Vec` elements by the value of a field
+///
+/// The elements are grouped by the value of the field named by `KEY`, which must implement [`FieldName`].
+/// The result is a map from the field value to the list of elements with that value.
+/// The field is removed from each element during serialization and reinserted during deserialization.
+/// The groups are serialized in the order of their first appearance.
+/// Deserialization yields the elements group by group.
+///
+/// The elements must serialize as a struct or a map, which contains the `KEY` field.
+/// `T` is used to de/serialize the elements, similar to [`KeyValueMapBy`].
+///
+/// Besides `Vec`, multimaps like `BTreeMap<K, Vec<T>>` or `HashMap<K, Vec<T>>` are supported.
+/// The map key is the value of the `KEY` field, which is still part of the elements.
+/// Repeated map keys are merged, such that the map contains the elements of all groups with the same key.
+///
+/// [`FieldName`]: crate::formats::FieldName
+/// [`KeyValueMapBy`]: crate::KeyValueMapBy
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use std::collections::BTreeMap;
+/// use serde_with::{formats::FieldName, serde_as, GroupedBy};
+///
+/// struct Region;
+/// impl FieldName for Region {
+///     fn name() -> &'static str {
+///         "region"
+///     }
+/// }
+///
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Server {
+///     region: String,
+///     name: String,
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Report {
+///     #[serde_as(as = "GroupedBy<Region, _>")]
+///     servers: Vec<Server>,
+///     #[serde_as(as = "GroupedBy<Region, _>")]
+///     by_region: BTreeMap<String, Vec<Server>>,
+/// }
+///
+/// let server = |region: &str, name: &str| Server {
+///     region: region.into(),
+///     name: name.into(),
+/// };
+/// let report = Report {
+///     servers: vec![server("eu-west", "a"), server("eu-west", "b"), server("us-east", "c")],
+///     by_region: BTreeMap::from([("us-east".into(), vec![server("us-east", "d")])]),
+/// };
+/// let json = json!({
+///     "servers": {
+///         "eu-west": [{"name": "a"}, {"name": "b"}],
+///         "us-east": [{"name": "c"}],
+///     },
+///     "by_region": {
+///         "us-east": [{"name": "d"}],
+///     },
+/// });
+///
+/// assert_eq!(json, serde_json::to_value(&report).unwrap());
+/// assert_eq!(report, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+pub struct GroupedBy<KEY, T>(PhantomData<(KEY, T)>);
+
+impl<KEY, T, TAs> SerializeAs<Vec<T>> for GroupedBy<KEY, TAs>
+where
+    KEY: formats::FieldName,
+    TAs: SerializeAs<T>,
+{
+    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_grouped::<T, TAs, S>(source.iter(), serializer, KEY::name())
+    }
+}
+
+impl<'de, KEY, T, TAs> DeserializeAs<'de, Vec<T>> for GroupedBy<KEY, TAs>
+where
+    KEY: formats::FieldName,
+    TAs: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct GroupedByVisitor<T, TAs> {
+            is_human_readable: bool,
+            key: &'static str,
+            phantom: PhantomData<(T, TAs)>,
+        }
+
+        impl<'de, T, TAs> Visitor<'de> for GroupedByVisitor<T, TAs>
+        where
+            TAs: DeserializeAs<'de, T>,
+        {
+            type Value = Vec<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a map of groups")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut values = Vec::new();
+                while let Some((group, records)) =
+                    map.next_entry::<DeContent<'de>, Vec<DeContent<'de>>>()?
+                {
+                    values.reserve(records.len());
+                    for record in records {
+                        values.push(deserialize_record::<T, TAs, A::Error>(
+                            record,
+                            &group,
+                            self.key,
+                            self.is_human_readable,
+                        )?);
+                    }
+                }
+                Ok(values)
+            }
+        }
+
+        let is_human_readable = deserializer.is_human_readable();
+        deserializer.deserialize_map(GroupedByVisitor::<T, TAs> {
+            is_human_readable,
+            key: KEY::name(),
+            phantom: PhantomData,
+        })
+    }
+}
+
+macro_rules! grouped_by_into_map {
+    (
+        $(#[$attr:meta])*
+        $ty:ident < K $(: $kbound1:ident $(+ $kbound2:ident)*)?, V $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)*)* >
+    ) => {
+        $(#[$attr])*
+        impl<KEY, K, T, TAs $(, $typaram)*> SerializeAs<$ty<K, Vec<T> $(, $typaram)*>> for GroupedBy<KEY, TAs>
+        where
+            KEY: formats::FieldName,
+            TAs: SerializeAs<T>,
+        {
+            fn serialize_as<S>(source: &$ty<K, Vec<T> $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_grouped::<T, TAs, S>(source.values().flatten(), serializer, KEY::name())
+            }
+        }
+
+        $(#[$attr])*
+        impl<'de, KEY, K, T, TAs $(, $typaram)*> DeserializeAs<'de, $ty<K, Vec<T> $(, $typaram)*>> for GroupedBy<KEY, TAs>
+        where
+            KEY: formats::FieldName,
+            K: Deserialize<'de> $(+ $kbound1 $(+ $kbound2)*)?,
+            TAs: DeserializeAs<'de, T>,
+            $($typaram: $bound1 $(+ $bound2)*,)*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<K, Vec<T> $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserialize_grouped_into::<_, K, T, TAs, D>(deserializer, KEY::name())
+            }
+        }
+    };
+}
+grouped_by_into_map!(BTreeMap<K: Ord, V>);
+grouped_by_into_map!(
+    #[cfg(feature = "std")]
+    HashMap<K: Eq + Hash, V, H: BuildHasher + Default>
+);
+grouped_by_into_map!(
+    #[cfg(feature = "hashbrown_0_14")]
+    HashbrownMap014<K: Eq + Hash, V, H: BuildHasher + Default>
+);
+grouped_by_into_map!(
+    #[cfg(feature = "indexmap_1")]
+    IndexMap<K: Eq + Hash, V, H: BuildHasher + Default>
+);
+grouped_by_into_map!(
+    #[cfg(feature = "indexmap_2")]
+    IndexMap2<K: Eq + Hash, V, H: BuildHasher + Default>
+);
+
+/// Serialize the elements as a map of groups, keyed by the value of the `key` field
+fn serialize_grouped<'a, T, TAs, S>(
+    values: impl Iterator<Item = &'a T>,
+    serializer: S,
+    key: &'static str,
+) -> Result<S::Ok, S::Error>
+where
+    T: 'a,
+    TAs: SerializeAs<T>,
+    S: Serializer,
+{
+    let is_human_readable = serializer.is_human_readable();
+    // The index of each group in `groups`, which keeps the order of their first appearance
+    let mut indices: BTreeMap<GroupKey, usize> = BTreeMap::new();
+    let mut groups: Vec<Vec<Content>> = Vec::new();
+    for value in values {
+        let record =
+            SerializeAsWrap::<T, TAs>::new(value)
+                .serialize(ContentSerializer::<S::Error>::new(is_human_readable))?;
+        let (group, record) = split_field(record, key)
+            .ok_or_else(|| S::Error::custom(format_args!("missing value for `{key}` field")))?;
+        match indices.entry(GroupKey(group)) {
+            btree_map::Entry::Occupied(entry) => groups[*entry.get()].push(record),
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(groups.len());
+                groups.push(alloc::vec![record]);
+            }
+        }
+    }
+
+    let mut keys: Vec<(GroupKey, usize)> = indices.into_iter().collect();
+    keys.sort_unstable_by_key(|&(_, index)| index);
+    let mut map = serializer.serialize_map(Some(groups.len()))?;
+    for ((group, _), records) in keys.iter().zip(&groups) {
+        map.serialize_entry(&group.0, records)?;
+    }
+    map.end()
+}
+
+/// The value of a group, ordered such that it can be looked up in a [`BTreeMap`]
+struct GroupKey(Content);
+
+impl PartialEq for GroupKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for GroupKey {}
+
+impl PartialOrd for GroupKey {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for GroupKey {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// Remove the `key` field from the record and return its value and the remaining record
+fn split_field(record: Content, key: &'static str) -> Option<(Content, Content)> {
+    match record {
+        Content::Struct(name, mut fields) => {
+            let pos = fields.iter().position(|(field, _)| *field == key)?;
+            let group = fields.remove(pos).1;
+            Some((group, Content::Struct(name, fields)))
+        }
+        Content::Map(mut entries) => {
+            let pos = entries
+                .iter()
+                .position(|(field, _)| field.as_str() == Some(key))?;
+            let group = entries.remove(pos).1;
+            Some((group, Content::Map(entries)))
+        }
+        _ => None,
+    }
+}
+
+/// Reinsert the `key` field with the value `group` into the record and deserialize it
+fn deserialize_record<'de, T, TAs, E>(
+    record: DeContent<'de>,
+    group: &DeContent<'de>,
+    key: &'static str,
+    is_human_readable: bool,
+) -> Result<T, E>
+where
+    TAs: DeserializeAs<'de, T>,
+    E: DeError,
+{
+    let record = match record {
+        DeContent::Map(mut entries) => {
+            entries.insert(0, (DeContent::Str(key), group.clone()));
+            DeContent::Map(entries)
+        }
+        record => {
+            return Err(DeError::invalid_type(
+                record.unexpected(),
+                &"a map of the record fields",
+            ))
+        }
+    };
+    TAs::deserialize_as(ContentDeserializer::new(record, is_human_readable))
+}
+
+fn deserialize_grouped_into<'de, MAP, K, T, TAs, D>(
+    deserializer: D,
+    key: &'static str,
+) -> Result<MAP, D::Error>
+where
+    MAP: DuplicateInsertsMergeMap<K, Vec<T>>,
+    K: Deserialize<'de>,
+    TAs: DeserializeAs<'de, T>,
+    D: Deserializer<'de>,
+{
+    struct GroupedByVisitor<MAP, K, T, TAs> {
+        is_human_readable: bool,
+        key: &'static str,
+        phantom: PhantomData<(MAP, K, T, TAs)>,
+    }
+
+    impl<'de, MAP, K, T, TAs> Visitor<'de> for GroupedByVisitor<MAP, K, T, TAs>
+    where
+        MAP: DuplicateInsertsMergeMap<K, Vec<T>>,
+        K: Deserialize<'de>,
+        TAs: DeserializeAs<'de, T>,
+    {
+        type Value = MAP;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map of groups")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut values = MAP::new(map.size_hint());
+            while let Some((group, records)) =
+                map.next_entry::<DeContent<'de>, Vec<DeContent<'de>>>()?
+            {
+                // The group is needed for the map key and as the key field of each record
+                let map_key = K::deserialize(ContentDeserializer::new(
+                    group.clone(),
+                    self.is_human_readable,
+                ))?;
+                let records = records
+                    .into_iter()
+                    .map(|record| {
+                        deserialize_record::<T, TAs, A::Error>(
+                            record,
+                            &group,
+                            self.key,
+                            self.is_human_readable,
+                        )
+                    })
+                    .collect::<Result<Vec<T>, _>>()?;
+                values.insert(map_key, records, |existing, records| {
+                    existing.extend(records);
+                });
+            }
+            Ok(values)
+        }
+    }
+
+    let is_human_readable = deserializer.is_human_readable();
+    deserializer.deserialize_map(GroupedByVisitor::<MAP, K, T, TAs> {
+        is_human_readable,
+        key,
+        phantom: PhantomData,
+    })
+}
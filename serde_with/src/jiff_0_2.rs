@@ -0,0 +1,871 @@
+//! De/Serialization of [jiff v0.2][jiff] types
+//!
+//! This modules is only available if using the `jiff_0_2` feature of the crate.
+//! It enables support for [`jiff_0_2::SignedDuration`] and [`jiff_0_2::Span`] together with [`DurationSeconds`] and its variants.
+//! A [`Span`] is only serializable if it does not contain any units of days or larger.
+//! The type [`jiff_0_2::Timestamp`] is supported by [`TimestampSeconds`], its variants, and [`TimestampAny`].
+//! The [`Rfc3339`] and [`Iso8601`] adapters support [`Timestamp`], [`Zoned`], and [`civil::DateTime`].
+//!
+//! [jiff]: https://docs.rs/jiff/0.2/
+
+use crate::{
+    formats::{self, Flexible, Format, Saturating, Strict, Strictness},
+    prelude::*,
+};
+use ::jiff_0_2::{
+    civil,
+    fmt::temporal::{Pieces, PiecesOffset},
+    tz::{Offset, TimeZone},
+    SignedDuration, Span, Timestamp, Zoned,
+};
+
+/// Serialize a [`Timestamp`], [`Zoned`], or [`civil::DateTime`] as RFC 3339 string
+///
+/// The string always contains a UTC offset, but never a time zone annotation.
+///
+/// * [`Timestamp`] is serialized in UTC, like `"2024-06-19T19:22:45.5Z"`.
+/// * [`Zoned`] is serialized with its offset, like `"2024-06-19T15:22:45.5-04:00"`.
+///   Deserializing it creates a [`Zoned`] with a fixed offset time zone, since RFC 3339 has no room for the time zone name.
+/// * [`civil::DateTime`] is interpreted as UTC and serialized like a [`Timestamp`].
+///   Deserializing it converts the timestamp to the civil datetime in UTC.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use jiff_0_2 as jiff;
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{jiff_0_2::Rfc3339, serde_as};
+/// use jiff::{civil::date, tz::TimeZone, Timestamp, Zoned};
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Event {
+///     #[serde_as(as = "Rfc3339")]
+///     timestamp: Timestamp,
+///     #[serde_as(as = "Rfc3339")]
+///     zoned: Zoned,
+/// }
+///
+/// let zoned = date(2024, 6, 19)
+///     .at(15, 22, 45, 0)
+///     .to_zoned(TimeZone::fixed(jiff::tz::offset(-4)))
+///     .unwrap();
+/// let event = Event {
+///     timestamp: zoned.timestamp(),
+///     zoned,
+/// };
+/// let json = json!({
+///     "timestamp": "2024-06-19T19:22:45Z",
+///     "zoned": "2024-06-19T15:22:45-04:00",
+/// });
+/// assert_eq!(json, serde_json::to_value(&event).unwrap());
+/// assert_eq!(event, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+pub struct Rfc3339;
+
+/// Serialize a [`Timestamp`], [`Zoned`], or [`civil::DateTime`] as ISO 8601 string
+///
+/// This uses the [`Display`] and [`FromStr`](core::str::FromStr) implementations of the jiff types.
+///
+/// * [`Timestamp`] is serialized in UTC, like `"2024-06-19T19:22:45Z"`.
+/// * [`Zoned`] is serialized with its offset and time zone annotation (RFC 9557), like `"2024-06-19T15:22:45-04:00[America/New_York]"`.
+///   Deserializing time zone names requires a time zone database, which is controlled by the features of the `jiff` crate.
+/// * [`civil::DateTime`] is serialized without any offset, like `"2024-06-19T15:22:45"`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use jiff_0_2 as jiff;
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{jiff_0_2::Iso8601, serde_as};
+/// use jiff::{civil::{date, DateTime}, tz::TimeZone, Zoned};
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Event {
+///     #[serde_as(as = "Iso8601")]
+///     civil: DateTime,
+///     #[serde_as(as = "Iso8601")]
+///     zoned: Zoned,
+/// }
+///
+/// let civil = date(2024, 6, 19).at(15, 22, 45, 0);
+/// let event = Event {
+///     civil,
+///     zoned: civil.to_zoned(TimeZone::fixed(jiff::tz::offset(-4))).unwrap(),
+/// };
+/// let json = json!({
+///     "civil": "2024-06-19T15:22:45",
+///     "zoned": "2024-06-19T15:22:45-04:00[-04:00]",
+/// });
+/// assert_eq!(json, serde_json::to_value(&event).unwrap());
+/// assert_eq!(event, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+pub struct Iso8601;
+
+/// Convert a [`SignedDuration`] into a [`DurationSigned`]
+fn signed_duration_into_duration_signed(dur: &SignedDuration) -> DurationSigned {
+    DurationSigned::with_duration(
+        // A duration of 0 is not positive, so check for negative value.
+        if dur.is_negative() {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        },
+        dur.unsigned_abs(),
+    )
+}
+
+/// Convert a [`DurationSigned`] into a [`SignedDuration`], if it is in the representable range
+fn checked_signed_duration_from_duration_signed(sdur: DurationSigned) -> Option<SignedDuration> {
+    let secs = i128::from(sdur.duration.as_secs());
+    let nanos = sdur.duration.subsec_nanos() as i32;
+    // Negate the parts separately, since the negative range is larger than the positive one
+    let (secs, nanos) = if sdur.sign.is_negative() {
+        (-secs, -nanos)
+    } else {
+        (secs, nanos)
+    };
+    Some(SignedDuration::new(i64::try_from(secs).ok()?, nanos))
+}
+
+/// Convert a [`DurationSigned`] into a [`SignedDuration`]
+fn duration_to_signed_duration<'de, D>(sdur: DurationSigned) -> Result<SignedDuration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    checked_signed_duration_from_duration_signed(sdur)
+        .ok_or_else(|| DeError::custom("Duration is outside of the representable range"))
+}
+
+/// Convert a [`DurationSigned`] into a [`SignedDuration`], clamping it to the representable range
+fn duration_to_signed_duration_saturating<'de, D>(
+    sdur: DurationSigned,
+) -> Result<SignedDuration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(sdur.saturating_convert(checked_signed_duration_from_duration_signed))
+}
+
+fn signed_duration_to_duration<S>(source: &SignedDuration) -> Result<DurationSigned, S::Error>
+where
+    S: Serializer,
+{
+    Ok(signed_duration_into_duration_signed(source))
+}
+
+fn span_to_duration<S>(source: &Span) -> Result<DurationSigned, S::Error>
+where
+    S: Serializer,
+{
+    let dur = SignedDuration::try_from(*source).map_err(S::Error::custom)?;
+    Ok(signed_duration_into_duration_signed(&dur))
+}
+
+fn duration_to_span<'de, D>(sdur: DurationSigned) -> Result<Span, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Span::try_from(duration_to_signed_duration::<D>(sdur)?).map_err(DeError::custom)
+}
+
+fn duration_to_span_saturating<'de, D>(sdur: DurationSigned) -> Result<Span, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(sdur.saturating_convert(|sdur| {
+        Span::try_from(checked_signed_duration_from_duration_signed(sdur)?).ok()
+    }))
+}
+
+fn timestamp_to_duration<S>(source: &Timestamp) -> Result<DurationSigned, S::Error>
+where
+    S: Serializer,
+{
+    Ok(signed_duration_into_duration_signed(&source.as_duration()))
+}
+
+fn duration_to_timestamp<'de, D>(sdur: DurationSigned) -> Result<Timestamp, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Timestamp::from_duration(duration_to_signed_duration::<D>(sdur)?).map_err(DeError::custom)
+}
+
+fn duration_to_timestamp_saturating<'de, D>(sdur: DurationSigned) -> Result<Timestamp, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(sdur.saturating_convert(|sdur| {
+        Timestamp::from_duration(checked_signed_duration_from_duration_signed(sdur)?).ok()
+    }))
+}
+
+macro_rules! use_duration_signed_ser {
+    (
+        $main_trait:ident $internal_trait:ident =>
+        {
+            $ty:ty; $converter:ident =>
+            $({
+                $format:ty, $strictness:ty =>
+                $($tbound:ident: $bound:ident $(,)?)*
+            })*
+        }
+    ) => {
+        $(
+            impl<$($tbound ,)*> SerializeAs<$ty> for $main_trait<$format, $strictness>
+            where
+                $($tbound: $bound,)*
+            {
+                fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let dur: DurationSigned = $converter::<S>(source)?;
+                    $internal_trait::<$format, $strictness>::serialize_as(
+                        &dur,
+                        serializer,
+                    )
+                }
+            }
+        )*
+    };
+    (
+        $( $main_trait:ident $internal_trait:ident, )+ => $rest:tt
+    ) => {
+        $( use_duration_signed_ser!($main_trait $internal_trait => $rest); )+
+    };
+}
+
+use_duration_signed_ser!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    => {
+        SignedDuration; signed_duration_to_duration =>
+        {i64, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+#[cfg(feature = "alloc")]
+use_duration_signed_ser!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    => {
+        SignedDuration; signed_duration_to_duration =>
+        {String, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+#[cfg(feature = "std")]
+use_duration_signed_ser!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    => {
+        SignedDuration; signed_duration_to_duration =>
+        {f64, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+use_duration_signed_ser!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    => {
+        Span; span_to_duration =>
+        {i64, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+#[cfg(feature = "alloc")]
+use_duration_signed_ser!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    => {
+        Span; span_to_duration =>
+        {String, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+#[cfg(feature = "std")]
+use_duration_signed_ser!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    => {
+        Span; span_to_duration =>
+        {f64, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+use_duration_signed_ser!(
+    TimestampSeconds DurationSeconds,
+    TimestampMilliSeconds DurationMilliSeconds,
+    TimestampMicroSeconds DurationMicroSeconds,
+    TimestampNanoSeconds DurationNanoSeconds,
+    => {
+        Timestamp; timestamp_to_duration =>
+        {i64, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+#[cfg(feature = "alloc")]
+use_duration_signed_ser!(
+    TimestampSeconds DurationSeconds,
+    TimestampMilliSeconds DurationMilliSeconds,
+    TimestampMicroSeconds DurationMicroSeconds,
+    TimestampNanoSeconds DurationNanoSeconds,
+    => {
+        Timestamp; timestamp_to_duration =>
+        {String, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+#[cfg(feature = "std")]
+use_duration_signed_ser!(
+    TimestampSeconds DurationSeconds,
+    TimestampMilliSeconds DurationMilliSeconds,
+    TimestampMicroSeconds DurationMicroSeconds,
+    TimestampNanoSeconds DurationNanoSeconds,
+    => {
+        Timestamp; timestamp_to_duration =>
+        {f64, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+
+// Duration/Timestamp WITH FRACTIONS
+#[cfg(feature = "alloc")]
+use_duration_signed_ser!(
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        SignedDuration; signed_duration_to_duration =>
+        {String, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+#[cfg(feature = "std")]
+use_duration_signed_ser!(
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        SignedDuration; signed_duration_to_duration =>
+        {f64, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+#[cfg(feature = "alloc")]
+use_duration_signed_ser!(
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Span; span_to_duration =>
+        {String, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+#[cfg(feature = "std")]
+use_duration_signed_ser!(
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Span; span_to_duration =>
+        {f64, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+#[cfg(feature = "alloc")]
+use_duration_signed_ser!(
+    TimestampSecondsWithFrac DurationSecondsWithFrac,
+    TimestampMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    TimestampMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    TimestampNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Timestamp; timestamp_to_duration =>
+        {String, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+#[cfg(feature = "std")]
+use_duration_signed_ser!(
+    TimestampSecondsWithFrac DurationSecondsWithFrac,
+    TimestampMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    TimestampMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    TimestampNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Timestamp; timestamp_to_duration =>
+        {f64, STRICTNESS => STRICTNESS: Strictness}
+    }
+);
+
+macro_rules! use_duration_signed_de {
+    (
+        $main_trait:ident $internal_trait:ident =>
+        {
+            $ty:ty; $converter:ident =>
+            $({
+                $format:ty, $strictness:ty =>
+                $($tbound:ident: $bound:ident)*
+            })*
+        }
+    ) =>{
+        $(
+            impl<'de, $($tbound,)*> DeserializeAs<'de, $ty> for $main_trait<$format, $strictness>
+            where
+                $($tbound: $bound,)*
+            {
+                fn deserialize_as<D>(deserializer: D) -> Result<$ty, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let dur: DurationSigned = $internal_trait::<$format, $strictness>::deserialize_as(deserializer)?;
+                    $converter::<D>(dur)
+                }
+            }
+        )*
+    };
+    (
+        $( $main_trait:ident $internal_trait:ident, )+ => $rest:tt
+    ) => {
+        $( use_duration_signed_de!($main_trait $internal_trait => $rest); )+
+    };
+}
+
+// No subsecond precision
+use_duration_signed_de!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    => {
+        SignedDuration; duration_to_signed_duration =>
+        {i64, Strict =>}
+        {FORMAT, Flexible => FORMAT: Format}
+    }
+);
+#[cfg(feature = "alloc")]
+use_duration_signed_de!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    => {
+        SignedDuration; duration_to_signed_duration =>
+        {String, Strict =>}
+    }
+);
+#[cfg(feature = "std")]
+use_duration_signed_de!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    => {
+        SignedDuration; duration_to_signed_duration =>
+        {f64, Strict =>}
+    }
+);
+use_duration_signed_de!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    => {
+        Span; duration_to_span =>
+        {i64, Strict =>}
+        {FORMAT, Flexible => FORMAT: Format}
+    }
+);
+#[cfg(feature = "alloc")]
+use_duration_signed_de!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    => {
+        Span; duration_to_span =>
+        {String, Strict =>}
+    }
+);
+#[cfg(feature = "std")]
+use_duration_signed_de!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    => {
+        Span; duration_to_span =>
+        {f64, Strict =>}
+    }
+);
+use_duration_signed_de!(
+    TimestampSeconds DurationSeconds,
+    TimestampMilliSeconds DurationMilliSeconds,
+    TimestampMicroSeconds DurationMicroSeconds,
+    TimestampNanoSeconds DurationNanoSeconds,
+    => {
+        Timestamp; duration_to_timestamp =>
+        {i64, Strict =>}
+        {FORMAT, Flexible => FORMAT: Format}
+    }
+);
+#[cfg(feature = "alloc")]
+use_duration_signed_de!(
+    TimestampSeconds DurationSeconds,
+    TimestampMilliSeconds DurationMilliSeconds,
+    TimestampMicroSeconds DurationMicroSeconds,
+    TimestampNanoSeconds DurationNanoSeconds,
+    => {
+        Timestamp; duration_to_timestamp =>
+        {String, Strict =>}
+    }
+);
+#[cfg(feature = "std")]
+use_duration_signed_de!(
+    TimestampSeconds DurationSeconds,
+    TimestampMilliSeconds DurationMilliSeconds,
+    TimestampMicroSeconds DurationMicroSeconds,
+    TimestampNanoSeconds DurationNanoSeconds,
+    => {
+        Timestamp; duration_to_timestamp =>
+        {f64, Strict =>}
+    }
+);
+
+// Duration/Timestamp WITH FRACTIONS
+use_duration_signed_de!(
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        SignedDuration; duration_to_signed_duration =>
+        {FORMAT, Flexible => FORMAT: Format}
+    }
+);
+#[cfg(feature = "alloc")]
+use_duration_signed_de!(
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        SignedDuration; duration_to_signed_duration =>
+        {String, Strict =>}
+    }
+);
+#[cfg(feature = "std")]
+use_duration_signed_de!(
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        SignedDuration; duration_to_signed_duration =>
+        {f64, Strict =>}
+    }
+);
+use_duration_signed_de!(
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Span; duration_to_span =>
+        {FORMAT, Flexible => FORMAT: Format}
+    }
+);
+#[cfg(feature = "alloc")]
+use_duration_signed_de!(
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Span; duration_to_span =>
+        {String, Strict =>}
+    }
+);
+#[cfg(feature = "std")]
+use_duration_signed_de!(
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Span; duration_to_span =>
+        {f64, Strict =>}
+    }
+);
+use_duration_signed_de!(
+    TimestampSecondsWithFrac DurationSecondsWithFrac,
+    TimestampMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    TimestampMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    TimestampNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Timestamp; duration_to_timestamp =>
+        {FORMAT, Flexible => FORMAT: Format}
+    }
+);
+#[cfg(feature = "alloc")]
+use_duration_signed_de!(
+    TimestampSecondsWithFrac DurationSecondsWithFrac,
+    TimestampMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    TimestampMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    TimestampNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Timestamp; duration_to_timestamp =>
+        {String, Strict =>}
+    }
+);
+#[cfg(feature = "std")]
+use_duration_signed_de!(
+    TimestampSecondsWithFrac DurationSecondsWithFrac,
+    TimestampMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    TimestampMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    TimestampNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Timestamp; duration_to_timestamp =>
+        {f64, Strict =>}
+    }
+);
+
+// Saturating conversions clamp out-of-range values
+use_duration_signed_de!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        SignedDuration; duration_to_signed_duration_saturating =>
+        {FORMAT, Saturating => FORMAT: Format}
+    }
+);
+use_duration_signed_de!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Span; duration_to_span_saturating =>
+        {FORMAT, Saturating => FORMAT: Format}
+    }
+);
+use_duration_signed_de!(
+    TimestampSeconds DurationSeconds,
+    TimestampMilliSeconds DurationMilliSeconds,
+    TimestampMicroSeconds DurationMicroSeconds,
+    TimestampNanoSeconds DurationNanoSeconds,
+    TimestampSecondsWithFrac DurationSecondsWithFrac,
+    TimestampMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    TimestampMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    TimestampNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Timestamp; duration_to_timestamp_saturating =>
+        {FORMAT, Saturating => FORMAT: Format}
+    }
+);
+
+macro_rules! use_timestamp_any_ser {
+    ($($format:ty),* $(,)?) => {
+        $(
+            impl SerializeAs<Timestamp> for TimestampAny<$format> {
+                fn serialize_as<S>(source: &Timestamp, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let dur: DurationSigned = timestamp_to_duration::<S>(source)?;
+                    TimestampAny::<$format>::serialize_as(&dur, serializer)
+                }
+            }
+        )*
+    };
+}
+
+use_timestamp_any_ser!(i64, f64, formats::Rfc3339);
+#[cfg(feature = "alloc")]
+use_timestamp_any_ser!(String);
+
+impl<'de, FORMAT> DeserializeAs<'de, Timestamp> for TimestampAny<FORMAT>
+where
+    FORMAT: Format,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Timestamp, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let dur: DurationSigned = TimestampAny::<FORMAT>::deserialize_as(deserializer)?;
+        duration_to_timestamp::<D>(dur)
+    }
+}
+
+/// Visitor parsing a string with a [`FromStr`](core::str::FromStr) implementation
+struct FromStrVisitor<T> {
+    expecting: &'static str,
+    marker: PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T> Visitor<'de> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+/// Parse an RFC 3339 string into a [`Zoned`] with a fixed offset time zone
+fn parse_rfc3339_zoned<E: DeError>(value: &str) -> Result<Zoned, E> {
+    let pieces = Pieces::parse(value).map_err(E::custom)?;
+    let offset = match pieces.offset() {
+        Some(PiecesOffset::Zulu) => Offset::UTC,
+        Some(PiecesOffset::Numeric(offset)) => offset.offset(),
+        _ => return Err(E::custom("RFC3339 timestamp requires a UTC offset")),
+    };
+    let time = pieces
+        .time()
+        .ok_or_else(|| E::custom("RFC3339 timestamp requires a time"))?;
+    pieces
+        .date()
+        .to_datetime(time)
+        .to_zoned(TimeZone::fixed(offset))
+        .map_err(E::custom)
+}
+
+impl SerializeAs<Timestamp> for Rfc3339 {
+    fn serialize_as<S>(source: &Timestamp, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(source)
+    }
+}
+
+impl<'de> DeserializeAs<'de, Timestamp> for Rfc3339 {
+    fn deserialize_as<D>(deserializer: D) -> Result<Timestamp, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(FromStrVisitor::new("a RFC3339-formatted `Timestamp`"))
+    }
+}
+
+impl SerializeAs<Zoned> for Rfc3339 {
+    fn serialize_as<S>(source: &Zoned, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&source.timestamp().display_with_offset(source.offset()))
+    }
+}
+
+impl<'de> DeserializeAs<'de, Zoned> for Rfc3339 {
+    fn deserialize_as<D>(deserializer: D) -> Result<Zoned, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Helper;
+        impl<'de> Visitor<'de> for Helper {
+            type Value = Zoned;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a RFC3339-formatted `Zoned`")
+            }
+
+            fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+                parse_rfc3339_zoned(value)
+            }
+        }
+
+        deserializer.deserialize_str(Helper)
+    }
+}
+
+impl SerializeAs<civil::DateTime> for Rfc3339 {
+    fn serialize_as<S>(source: &civil::DateTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let timestamp = Offset::UTC
+            .to_timestamp(*source)
+            .map_err(S::Error::custom)?;
+        serializer.collect_str(&timestamp)
+    }
+}
+
+impl<'de> DeserializeAs<'de, civil::DateTime> for Rfc3339 {
+    fn deserialize_as<D>(deserializer: D) -> Result<civil::DateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let timestamp: Timestamp = Rfc3339::deserialize_as(deserializer)?;
+        Ok(Offset::UTC.to_datetime(timestamp))
+    }
+}
+
+macro_rules! use_iso8601 {
+    ($($ty:ty => $expecting:literal),* $(,)?) => {
+        $(
+            impl SerializeAs<$ty> for Iso8601 {
+                fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.collect_str(source)
+                }
+            }
+
+            impl<'de> DeserializeAs<'de, $ty> for Iso8601 {
+                fn deserialize_as<D>(deserializer: D) -> Result<$ty, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    deserializer.deserialize_str(FromStrVisitor::new($expecting))
+                }
+            }
+        )*
+    };
+}
+
+use_iso8601!(
+    Timestamp => "a ISO8601-formatted `Timestamp`",
+    Zoned => "a ISO8601-formatted `Zoned`",
+    civil::DateTime => "a ISO8601-formatted `DateTime`",
+);
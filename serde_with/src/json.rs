@@ -119,3 +119,143 @@ where
         deserializer.deserialize_str(Helper::<T, TAs>(PhantomData))
     }
 }
+
+/// Serialize map keys as strings containing JSON
+///
+/// JSON only supports strings as map keys, so maps keyed by tuples, structs, or numbers fail to serialize with `serde_json`.
+/// `JsonKey` serializes the key as compact JSON and parses it back during deserialization.
+/// Any `T` implementing `Serialize` and `Deserialize` can be used as key, without implementing `Display` and `FromStr` like for [`DisplayFromStr`](crate::DisplayFromStr).
+///
+/// With the default [`QuotedStrings`], `JsonKey` behaves like [`JsonString`], such that keys serializing as JSON strings, like `String` or unit enum variants, keep their quotes and `a` becomes `"a"`.
+/// With [`UnquotedStrings`] as `STRINGS`, the quotes are removed, unless the string itself is valid JSON, like `"1"` or `"true"`, to distinguish it from the number or boolean.
+/// During deserialization, keys which are not valid JSON are then treated as strings.
+///
+/// The type argument `T` is used to de/serialize the key and defaults to [`Same`].
+///
+/// [`QuotedStrings`]: crate::formats::QuotedStrings
+/// [`UnquotedStrings`]: crate::formats::UnquotedStrings
+///
+/// # Errors
+///
+/// Serialization can fail if `T`'s implementation of `Serialize` decides to
+/// fail, or if `T` contains a map with non-string keys.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{formats::UnquotedStrings, serde_as, json::JsonKey, Map, Same};
+/// # use std::collections::BTreeMap;
+/// #
+/// #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[serde_as]
+/// #[derive(Debug, Serialize, Deserialize, PartialEq)]
+/// struct Data {
+///     #[serde_as(as = "BTreeMap<JsonKey, _>")]
+///     distances: BTreeMap<(u32, String), f64>,
+///     #[serde_as(as = "Map<JsonKey, _>")]
+///     points: Vec<(Point, String)>,
+///     #[serde_as(as = "BTreeMap<JsonKey<Same, UnquotedStrings>, _>")]
+///     names: BTreeMap<String, u32>,
+/// }
+///
+/// let data = Data {
+///     distances: BTreeMap::from([((1, "a".into()), 0.5)]),
+///     points: vec![(Point { x: 1, y: 2 }, "origin".into())],
+///     names: BTreeMap::from([("1".into(), 1), ("one".into(), 1)]),
+/// };
+/// let json = json!({
+///     "distances": {
+///         "[1,\"a\"]": 0.5,
+///     },
+///     "points": {
+///         "{\"x\":1,\"y\":2}": "origin",
+///     },
+///     "names": {
+///         // Quoted, since it would be read as a number otherwise
+///         "\"1\"": 1,
+///         "one": 1,
+///     },
+/// });
+/// assert_eq!(json, serde_json::to_value(&data).unwrap());
+/// assert_eq!(data, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+pub struct JsonKey<T = Same, STRINGS = formats::QuotedStrings>(PhantomData<(T, STRINGS)>);
+
+impl<T, TAs, STRINGS> SerializeAs<T> for JsonKey<TAs, STRINGS>
+where
+    TAs: SerializeAs<T>,
+    STRINGS: formats::StringQuoting,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if !STRINGS::unquoted() {
+            return JsonString::<TAs>::serialize_as(source, serializer);
+        }
+
+        let json = serde_json::to_string(&SerializeAsWrap::<T, TAs>::new(source))
+            .map_err(SerError::custom)?;
+        // Strip the quotes from strings, unless the string could be mistaken for another JSON value
+        if json.starts_with('"') {
+            let string: String = serde_json::from_str(&json).map_err(SerError::custom)?;
+            if serde_json::from_str::<IgnoredAny>(&string).is_err() {
+                return serializer.serialize_str(&string);
+            }
+        }
+        serializer.serialize_str(&json)
+    }
+}
+
+impl<'de, T, TAs, STRINGS> DeserializeAs<'de, T> for JsonKey<TAs, STRINGS>
+where
+    TAs: for<'a> DeserializeAs<'a, T>,
+    STRINGS: formats::StringQuoting,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Helper<S, SAs>(PhantomData<(S, SAs)>);
+
+        impl<'de, S, SAs> Visitor<'de> for Helper<S, SAs>
+        where
+            SAs: for<'a> DeserializeAs<'a, S>,
+        {
+            type Value = S;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a string containing JSON or an unquoted string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<S, E>
+            where
+                E: DeError,
+            {
+                match serde_json::from_str(value) {
+                    Ok(value) => Ok(DeserializeAsWrap::<S, SAs>::into_inner(value)),
+                    // Not valid JSON, so it must be a string without the quotes
+                    Err(_) if serde_json::from_str::<IgnoredAny>(value).is_err() => {
+                        SAs::deserialize_as(serde_json::Value::String(value.into()))
+                            .map_err(DeError::custom)
+                    }
+                    Err(err) => Err(DeError::custom(err)),
+                }
+            }
+        }
+
+        if !STRINGS::unquoted() {
+            return JsonString::<TAs>::deserialize_as(deserializer);
+        }
+        deserializer.deserialize_str(Helper::<T, TAs>(PhantomData))
+    }
+}
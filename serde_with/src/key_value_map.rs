@@ -3,8 +3,15 @@ use crate::{
         de::{Content as DeContent, ContentDeserializer},
         ser::{Content as SerContent, ContentSerializer},
     },
+    duplicate_key_impls::{DuplicateInsertsMergeMap, DuplicateKeyPolicy, Scalar},
     prelude::*,
 };
+#[cfg(feature = "hashbrown_0_14")]
+use hashbrown_0_14::HashMap as HashbrownMap014;
+#[cfg(feature = "indexmap_1")]
+use indexmap_1::IndexMap;
+#[cfg(feature = "indexmap_2")]
+use indexmap_2::IndexMap as IndexMap2;
 
 /// Convert `Vec` elements into key-value map entries
 ///
@@ -18,6 +25,23 @@ use crate::{
 /// Maps similarly need a map-key that is named `$key$`.
 /// For tuples, tuple structs, and sequences the first element is used as the map key.
 ///
+/// Instead of a `Vec`, the elements can also be collected into a map, like a `BTreeMap`, `HashMap`, or `IndexMap`.
+/// The map is keyed by the same value as the key field, which allows fast lookups.
+/// During serialization only the map values are used, such that the key field determines the serialized map key.
+///
+/// The `POLICY` determines how duplicate keys are handled for map targets:
+///
+/// * [`LastValueWins`], the default, keeps the last element.
+/// * [`FirstValueWins`] keeps the first element.
+/// * [`PreventDuplicates`] returns an error.
+///
+/// A `Vec` keeps all elements with the default policy, even if their keys repeat.
+/// With [`FirstValueWins`] or [`PreventDuplicates`] the repeated keys are detected by comparing the serialized map keys.
+///
+/// [`LastValueWins`]: crate::formats::LastValueWins
+/// [`FirstValueWins`]: crate::formats::FirstValueWins
+/// [`PreventDuplicates`]: crate::formats::PreventDuplicates
+///
 /// # Examples
 ///
 /// ## Struct with String key in JSON
@@ -154,9 +178,44 @@ use crate::{
 /// assert_eq!(values, deserialized);
 /// # }
 /// ```
-pub struct KeyValueMap<T>(PhantomData<T>);
+///
+/// ## Struct in a map keyed by the key field
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// use serde_with::{formats::PreventDuplicates, serde_as, KeyValueMap};
+/// use std::collections::BTreeMap;
+///
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct SimpleStruct {
+///     #[serde(rename = "$key$")]
+///     id: String,
+///     i: i32,
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct KVMap(
+///     #[serde_as(as = "KeyValueMap<_, PreventDuplicates>")]
+///     BTreeMap<String, SimpleStruct>,
+/// );
+///
+/// let json = r#"{"id-0000":{"i":123},"id-0001":{"i":555}}"#;
+/// let values: KVMap = serde_json::from_str(json).unwrap();
+/// assert_eq!(555, values.0["id-0001"].i);
+/// assert_eq!(json, serde_json::to_string(&values).unwrap());
+///
+/// // Duplicate keys are rejected
+/// let json = r#"{"id-0000":{"i":123},"id-0000":{"i":555}}"#;
+/// assert!(serde_json::from_str::<KVMap>(json).is_err());
+/// # }
+/// ```
+pub struct KeyValueMap<T, POLICY = formats::LastValueWins>(PhantomData<(T, POLICY)>);
 
-impl<T, TAs> SerializeAs<Vec<T>> for KeyValueMap<TAs>
+impl<T, TAs, POLICY> SerializeAs<Vec<T>> for KeyValueMap<TAs, POLICY>
 where
     TAs: SerializeAs<T>,
 {
@@ -164,7 +223,7 @@ where
     where
         S: Serializer,
     {
-        <Vec<TAs>>::serialize_as(source, SeqAsMapSerializer(serializer))
+        serialize_key_value_map::<T, TAs, S>(source, serializer, MAP_KEY_FIELD)
     }
 }
 
@@ -176,49 +235,479 @@ where
     where
         D: Deserializer<'de>,
     {
-        struct KeyValueMapVisitor<T, TAs> {
-            is_human_readable: bool,
-            phantom: PhantomData<(T, TAs)>,
-        }
+        deserialize_key_value_map::<T, TAs, D>(deserializer, MAP_KEY_FIELD)
+    }
+}
 
-        impl<'de, T, TAs> Visitor<'de> for KeyValueMapVisitor<T, TAs>
+/// Convert `Vec` elements into key-value map entries, using a configurable key field
+///
+/// This works like [`KeyValueMap`], but the name of the struct field or map key is given by `KEY`, which implements [`FieldName`].
+/// The structs can then keep their normal field names, instead of renaming the key field to `$key$`.
+/// For tuples, tuple structs, and sequences the first element is still used as the map key.
+///
+/// By default, the key is removed from the value during serialization.
+/// With [`KeepKey`] as `RETENTION`, the value keeps the key field, such that `{"id-1": {"id": "id-1", ...}}` is produced.
+/// During deserialization, the map key always determines the key field.
+/// With [`KeepKey`], a copy of the key inside the value is ignored.
+/// Otherwise, the value must not contain the key field, since it would be a duplicate field.
+///
+/// Maps are supported as targets, and the duplicate key `POLICY` works the same as for [`KeyValueMap`], also for `Vec`.
+///
+/// [`FieldName`]: crate::formats::FieldName
+/// [`KeepKey`]: crate::formats::KeepKey
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_with::{formats::{FieldName, KeepKey}, serde_as, KeyValueMapBy};
+///
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     id: String,
+///     name: String,
+/// }
+///
+/// struct Id;
+///
+/// impl FieldName for Id {
+///     fn name() -> &'static str {
+///         "id"
+///     }
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Users {
+///     #[serde_as(as = "KeyValueMapBy<Id, _>")]
+///     users: Vec<User>,
+///     #[serde_as(as = "KeyValueMapBy<Id, _, KeepKey>")]
+///     admins: Vec<User>,
+/// }
+///
+/// let users = Users {
+///     users: vec![User {
+///         id: "id-1".into(),
+///         name: "Alice".into(),
+///     }],
+///     admins: vec![User {
+///         id: "id-2".into(),
+///         name: "Bob".into(),
+///     }],
+/// };
+/// let json = json!({
+///     "users": {
+///         "id-1": {"name": "Alice"},
+///     },
+///     "admins": {
+///         "id-2": {"id": "id-2", "name": "Bob"},
+///     },
+/// });
+///
+/// assert_eq!(json, serde_json::to_value(&users).unwrap());
+/// assert_eq!(users, serde_json::from_value(json).unwrap());
+///
+/// // The `User` struct still has its normal representation
+/// assert_eq!(
+///     json!({"id": "id-1", "name": "Alice"}),
+///     serde_json::to_value(&users.users[0]).unwrap(),
+/// );
+/// # }
+/// ```
+pub struct KeyValueMapBy<KEY, T, RETENTION = formats::RemoveKey, POLICY = formats::LastValueWins>(
+    PhantomData<(KEY, T, RETENTION, POLICY)>,
+);
+
+impl<KEY, T, TAs, RETENTION, POLICY> SerializeAs<Vec<T>>
+    for KeyValueMapBy<KEY, TAs, RETENTION, POLICY>
+where
+    KEY: formats::FieldName,
+    TAs: SerializeAs<T>,
+    RETENTION: formats::KeyRetention,
+{
+    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_key_value_map::<T, TAs, S>(source, serializer, KeyField::new::<KEY, RETENTION>())
+    }
+}
+
+impl<'de, KEY, T, TAs, RETENTION> DeserializeAs<'de, Vec<T>> for KeyValueMapBy<KEY, TAs, RETENTION>
+where
+    KEY: formats::FieldName,
+    TAs: DeserializeAs<'de, T>,
+    RETENTION: formats::KeyRetention,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_key_value_map::<T, TAs, D>(deserializer, KeyField::new::<KEY, RETENTION>())
+    }
+}
+
+macro_rules! key_value_map_into_vec {
+    ($policy:ident) => {
+        impl<'de, T, TAs> DeserializeAs<'de, Vec<T>> for KeyValueMap<TAs, formats::$policy>
         where
             TAs: DeserializeAs<'de, T>,
         {
-            type Value = Vec<T>;
+            fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let values: KeyedVec<'de, T> = deserialize_key_value_map_into::<
+                    _,
+                    DeContent<'de>,
+                    T,
+                    TAs,
+                    formats::$policy,
+                    D,
+                >(deserializer, MAP_KEY_FIELD)?;
+                Ok(values.values)
+            }
+        }
 
-            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("a map")
+        impl<'de, KEY, T, TAs, RETENTION> DeserializeAs<'de, Vec<T>>
+            for KeyValueMapBy<KEY, TAs, RETENTION, formats::$policy>
+        where
+            KEY: formats::FieldName,
+            TAs: DeserializeAs<'de, T>,
+            RETENTION: formats::KeyRetention,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let values: KeyedVec<'de, T> = deserialize_key_value_map_into::<
+                    _,
+                    DeContent<'de>,
+                    T,
+                    TAs,
+                    formats::$policy,
+                    D,
+                >(
+                    deserializer, KeyField::new::<KEY, RETENTION>()
+                )?;
+                Ok(values.values)
             }
+        }
+    };
+}
+key_value_map_into_vec!(FirstValueWins);
+key_value_map_into_vec!(PreventDuplicates);
+
+/// Collect the elements of a `Vec` together with their serialized map keys
+///
+/// The elements cannot be compared, so the buffered keys are used to find repeated keys.
+struct KeyedVec<'de, T> {
+    keys: Vec<DeContent<'de>>,
+    /// Indices into `keys`, sorted by the key
+    sorted: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<'de, T> DuplicateInsertsMergeMap<DeContent<'de>, T> for KeyedVec<'de, T> {
+    fn new(size_hint: Option<usize>) -> Self {
+        let capacity = utils::size_hint_cautious::<(DeContent<'de>, T)>(size_hint);
+        Self {
+            keys: Vec::with_capacity(capacity),
+            sorted: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+        }
+    }
 
-            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
-                <Vec<TAs>>::deserialize_as(SeqDeserializer {
-                    delegate: map,
-                    is_human_readable: self.is_human_readable,
-                })
+    fn insert<F>(&mut self, key: DeContent<'de>, value: T, merge: F)
+    where
+        F: FnOnce(&mut T, T),
+    {
+        match self
+            .sorted
+            .binary_search_by(|&idx| self.keys[idx].total_cmp(&key))
+        {
+            Ok(pos) => merge(&mut self.values[self.sorted[pos]], value),
+            Err(pos) => {
+                self.sorted.insert(pos, self.values.len());
+                self.keys.push(key);
+                self.values.push(value);
             }
         }
+    }
+}
 
-        let is_human_readable = deserializer.is_human_readable();
-        deserializer.deserialize_map(KeyValueMapVisitor::<T, TAs> {
-            is_human_readable,
-            phantom: PhantomData,
-        })
+fn serialize_key_value_map<T, TAs, S>(
+    source: &Vec<T>,
+    serializer: S,
+    key: KeyField,
+) -> Result<S::Ok, S::Error>
+where
+    TAs: SerializeAs<T>,
+    S: Serializer,
+{
+    <Vec<TAs>>::serialize_as(source, SeqAsMapSerializer(serializer, key))
+}
+
+fn deserialize_key_value_map<'de, T, TAs, D>(
+    deserializer: D,
+    key: KeyField,
+) -> Result<Vec<T>, D::Error>
+where
+    TAs: DeserializeAs<'de, T>,
+    D: Deserializer<'de>,
+{
+    struct KeyValueMapVisitor<T, TAs> {
+        is_human_readable: bool,
+        key: KeyField,
+        phantom: PhantomData<(T, TAs)>,
+    }
+
+    impl<'de, T, TAs> Visitor<'de> for KeyValueMapVisitor<T, TAs>
+    where
+        TAs: DeserializeAs<'de, T>,
+    {
+        type Value = Vec<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+            <Vec<TAs>>::deserialize_as(SeqDeserializer {
+                delegate: map,
+                is_human_readable: self.is_human_readable,
+                key: self.key,
+            })
+        }
+    }
+
+    let is_human_readable = deserializer.is_human_readable();
+    deserializer.deserialize_map(KeyValueMapVisitor::<T, TAs> {
+        is_human_readable,
+        key,
+        phantom: PhantomData,
+    })
+}
+
+fn serialize_key_value_map_values<'a, T, TAs, S>(
+    values: impl ExactSizeIterator<Item = &'a T>,
+    serializer: S,
+    key: KeyField,
+) -> Result<S::Ok, S::Error>
+where
+    T: 'a,
+    TAs: SerializeAs<T>,
+    S: Serializer,
+{
+    SeqAsMapSerializer(serializer, key).collect_seq(values.map(SerializeAsWrap::<T, TAs>::new))
+}
+
+fn deserialize_key_value_map_into<'de, MAP, K, T, TAs, POLICY, D>(
+    deserializer: D,
+    key: KeyField,
+) -> Result<MAP, D::Error>
+where
+    MAP: DuplicateInsertsMergeMap<K, T>,
+    K: Deserialize<'de>,
+    TAs: DeserializeAs<'de, T>,
+    POLICY: DuplicateKeyPolicy,
+    D: Deserializer<'de>,
+{
+    struct KeyValueMapVisitor<MAP, K, T, TAs, POLICY> {
+        is_human_readable: bool,
+        key: KeyField,
+        phantom: PhantomData<(MAP, K, T, TAs, POLICY)>,
+    }
+
+    impl<'de, MAP, K, T, TAs, POLICY> Visitor<'de> for KeyValueMapVisitor<MAP, K, T, TAs, POLICY>
+    where
+        MAP: DuplicateInsertsMergeMap<K, T>,
+        K: Deserialize<'de>,
+        TAs: DeserializeAs<'de, T>,
+        POLICY: DuplicateKeyPolicy,
+    {
+        type Value = MAP;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut values = MAP::new(map.size_hint());
+            let mut index = 0;
+
+            while let Some(key_value) = map.next_key::<DeContent<'de>>()? {
+                // The map key is needed twice, for the map itself and as the key field of the value
+                let map_key = K::deserialize(ContentDeserializer::new(
+                    key_value.clone(),
+                    self.is_human_readable,
+                ))?;
+                let value: DeserializeAsWrap<T, TAs> =
+                    DeserializeAsWrap::deserialize(MapKeyDeserializer {
+                        delegate: &mut map,
+                        is_human_readable: self.is_human_readable,
+                        key: self.key,
+                        key_value: key_value.clone(),
+                    })?;
+                POLICY::insert(
+                    &mut values,
+                    map_key,
+                    value.into_inner(),
+                    index,
+                    Scalar::from_content(&key_value),
+                )?;
+                index += 1;
+            }
+
+            Ok(values)
+        }
     }
+
+    let is_human_readable = deserializer.is_human_readable();
+    deserializer.deserialize_map(KeyValueMapVisitor::<MAP, K, T, TAs, POLICY> {
+        is_human_readable,
+        key,
+        phantom: PhantomData,
+    })
+}
+
+macro_rules! key_value_map_into_map {
+    (
+        $(#[$attr:meta])*
+        $ty:ident < K $(: $kbound1:ident $(+ $kbound2:ident)*)?, V $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)*)* >
+    ) => {
+        $(#[$attr])*
+        impl<K, T, TAs, POLICY $(, $typaram)*> SerializeAs<$ty<K, T $(, $typaram)*>> for KeyValueMap<TAs, POLICY>
+        where
+            TAs: SerializeAs<T>,
+        {
+            fn serialize_as<S>(source: &$ty<K, T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_key_value_map_values::<T, TAs, S>(source.values(), serializer, MAP_KEY_FIELD)
+            }
+        }
+
+        $(#[$attr])*
+        impl<'de, K, T, TAs, POLICY $(, $typaram)*> DeserializeAs<'de, $ty<K, T $(, $typaram)*>> for KeyValueMap<TAs, POLICY>
+        where
+            K: Deserialize<'de> $(+ $kbound1 $(+ $kbound2)*)?,
+            TAs: DeserializeAs<'de, T>,
+            POLICY: DuplicateKeyPolicy,
+            $($typaram: $bound1 $(+ $bound2)*,)*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<K, T $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserialize_key_value_map_into::<_, K, T, TAs, POLICY, D>(deserializer, MAP_KEY_FIELD)
+            }
+        }
+
+        $(#[$attr])*
+        impl<KEY, K, T, TAs, RETENTION, POLICY $(, $typaram)*> SerializeAs<$ty<K, T $(, $typaram)*>>
+            for KeyValueMapBy<KEY, TAs, RETENTION, POLICY>
+        where
+            KEY: formats::FieldName,
+            TAs: SerializeAs<T>,
+            RETENTION: formats::KeyRetention,
+        {
+            fn serialize_as<S>(source: &$ty<K, T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_key_value_map_values::<T, TAs, S>(
+                    source.values(),
+                    serializer,
+                    KeyField::new::<KEY, RETENTION>(),
+                )
+            }
+        }
+
+        $(#[$attr])*
+        impl<'de, KEY, K, T, TAs, RETENTION, POLICY $(, $typaram)*> DeserializeAs<'de, $ty<K, T $(, $typaram)*>>
+            for KeyValueMapBy<KEY, TAs, RETENTION, POLICY>
+        where
+            KEY: formats::FieldName,
+            K: Deserialize<'de> $(+ $kbound1 $(+ $kbound2)*)?,
+            TAs: DeserializeAs<'de, T>,
+            RETENTION: formats::KeyRetention,
+            POLICY: DuplicateKeyPolicy,
+            $($typaram: $bound1 $(+ $bound2)*,)*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<K, T $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserialize_key_value_map_into::<_, K, T, TAs, POLICY, D>(
+                    deserializer,
+                    KeyField::new::<KEY, RETENTION>(),
+                )
+            }
+        }
+    };
+}
+key_value_map_into_map!(BTreeMap<K: Ord, V>);
+key_value_map_into_map!(
+    #[cfg(feature = "std")]
+    HashMap<K: Eq + Hash, V, H: BuildHasher + Default>
+);
+key_value_map_into_map!(
+    #[cfg(feature = "hashbrown_0_14")]
+    HashbrownMap014<K: Eq + Hash, V, H: BuildHasher + Default>
+);
+key_value_map_into_map!(
+    #[cfg(feature = "indexmap_1")]
+    IndexMap<K: Eq + Hash, V, H: BuildHasher + Default>
+);
+key_value_map_into_map!(
+    #[cfg(feature = "indexmap_2")]
+    IndexMap2<K: Eq + Hash, V, H: BuildHasher + Default>
+);
+
+/// The key field used by [`KeyValueMap`]
+static MAP_KEY_FIELD: KeyField = KeyField {
+    name: "$key$",
+    keep: false,
+};
+
+/// The name of the field which becomes the map key and whether it stays in the value
+#[derive(Clone, Copy)]
+struct KeyField {
+    name: &'static str,
+    keep: bool,
 }
 
-// TODO Replace this with a const generic string once adt_const_params is stable.
-// This will allow something like this.
-// The `"id"` part is the field name, which gets converted to/from the map key.
-// #[serde_as(as = r#"KeyValueMap<"id", _>"#)]
-// Vec<SimpleStruct>,
-static MAP_KEY_IDENTIFIER: &str = "$key$";
+impl KeyField {
+    fn new<KEY, RETENTION>() -> Self
+    where
+        KEY: formats::FieldName,
+        RETENTION: formats::KeyRetention,
+    {
+        Self {
+            name: KEY::name(),
+            keep: RETENTION::keep_key(),
+        }
+    }
+
+    fn missing_error<E>(self) -> E
+    where
+        E: SerError,
+    {
+        E::custom(format_args!("missing value for `{}` field", self.name))
+    }
+}
 
 /// Convert a sequence to a map during serialization.
 ///
 /// Only `serialize_seq` is implemented and forwarded to `serialize_map` on the inner `Serializer`.
 /// The elements are serialized with [`SerializeSeqElement`].
-struct SeqAsMapSerializer<S>(S);
+struct SeqAsMapSerializer<S>(S, KeyField);
 
 impl<S> Serializer for SeqAsMapSerializer<S>
 where
@@ -357,11 +846,13 @@ where
 
     fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
         let is_human_readable = self.0.is_human_readable();
+        let key = self.1;
         self.0
             .serialize_map(len)
             .map(|delegate| SerializeSeqElement {
                 delegate,
                 is_human_readable,
+                key,
             })
     }
 
@@ -419,6 +910,7 @@ where
 struct SerializeSeqElement<M> {
     delegate: M,
     is_human_readable: bool,
+    key: KeyField,
 }
 
 impl<M> SerializeSeq for SerializeSeqElement<M>
@@ -435,6 +927,7 @@ where
         value.serialize(ElementAsKeyValueSerializer {
             delegate: &mut self.delegate,
             is_human_readable: self.is_human_readable,
+            key: self.key,
         })?;
         Ok(())
     }
@@ -447,6 +940,7 @@ where
 struct ElementAsKeyValueSerializer<'a, M> {
     delegate: &'a mut M,
     is_human_readable: bool,
+    key: KeyField,
 }
 
 impl<'a, M> Serializer for ElementAsKeyValueSerializer<'a, M>
@@ -589,6 +1083,7 @@ where
             delegate: self.delegate,
             is_human_readable: self.is_human_readable,
             content: Vec::with_capacity(len.unwrap_or(17) - 1),
+            key_field: self.key,
             key: None,
         })
     }
@@ -598,6 +1093,7 @@ where
             delegate: self.delegate,
             is_human_readable: self.is_human_readable,
             content: Vec::with_capacity(len - 1),
+            key_field: self.key,
             key: None,
         })
     }
@@ -612,6 +1108,7 @@ where
             is_human_readable: self.is_human_readable,
             name,
             content: Vec::with_capacity(len - 1),
+            key_field: self.key,
             key: None,
         })
     }
@@ -632,6 +1129,7 @@ where
             is_human_readable: self.is_human_readable,
             content: Vec::with_capacity(len.unwrap_or(17) - 1),
             next_is_magic_key: false,
+            key_field: self.key,
             key: None,
             tmp: None,
         })
@@ -647,6 +1145,7 @@ where
             is_human_readable: self.is_human_readable,
             name,
             content: Vec::with_capacity(len - 1),
+            key_field: self.key,
             key: None,
         })
     }
@@ -669,6 +1168,7 @@ struct KeyValueSeqSerializer<'a, M> {
     delegate: &'a mut M,
     is_human_readable: bool,
     content: Vec<SerContent>,
+    key_field: KeyField,
     key: Option<SerContent>,
 }
 
@@ -686,6 +1186,10 @@ where
         let element: SerContent =
             element.serialize(ContentSerializer::new(self.is_human_readable))?;
         if self.key.is_none() {
+            if self.key_field.keep {
+                self.content
+                    .push(element.serialize(ContentSerializer::new(self.is_human_readable))?);
+            }
             self.key = Some(element);
             return Ok(());
         }
@@ -698,7 +1202,7 @@ where
             self.delegate
                 .serialize_entry(&key, &SerContent::Seq(self.content))
         } else {
-            Err(SerError::custom("missing value for `$key$` field"))
+            Err(self.key_field.missing_error())
         }
     }
 }
@@ -710,6 +1214,7 @@ struct KeyValueTupleSerializer<'a, M> {
     delegate: &'a mut M,
     is_human_readable: bool,
     content: Vec<SerContent>,
+    key_field: KeyField,
     key: Option<SerContent>,
 }
 
@@ -727,6 +1232,10 @@ where
         let element: SerContent =
             element.serialize(ContentSerializer::new(self.is_human_readable))?;
         if self.key.is_none() {
+            if self.key_field.keep {
+                self.content
+                    .push(element.serialize(ContentSerializer::new(self.is_human_readable))?);
+            }
             self.key = Some(element);
             return Ok(());
         }
@@ -739,7 +1248,7 @@ where
             self.delegate
                 .serialize_entry(&key, &SerContent::Tuple(self.content))
         } else {
-            Err(SerError::custom("missing value for `$key$` field"))
+            Err(self.key_field.missing_error())
         }
     }
 }
@@ -752,6 +1261,7 @@ struct KeyValueTupleStructSerializer<'a, M> {
     is_human_readable: bool,
     name: &'static str,
     content: Vec<SerContent>,
+    key_field: KeyField,
     key: Option<SerContent>,
 }
 
@@ -768,6 +1278,10 @@ where
     {
         let field: SerContent = field.serialize(ContentSerializer::new(self.is_human_readable))?;
         if self.key.is_none() {
+            if self.key_field.keep {
+                self.content
+                    .push(field.serialize(ContentSerializer::new(self.is_human_readable))?);
+            }
             self.key = Some(field);
             return Ok(());
         }
@@ -780,19 +1294,20 @@ where
             self.delegate
                 .serialize_entry(&key, &SerContent::TupleStruct(self.name, self.content))
         } else {
-            Err(SerError::custom("missing value for `$key$` field"))
+            Err(self.key_field.missing_error())
         }
     }
 }
 
 /// Serialize a map to a key and value pair of a map.
 ///
-/// This requires that the map has one element which serializes using the magic `$key$` key.
+/// This requires that the map has one element which serializes using the key field name, by default the magic `$key$` key.
 struct KeyValueMapSerializer<'a, M> {
     delegate: &'a mut M,
     is_human_readable: bool,
     content: Vec<(SerContent, SerContent)>,
     next_is_magic_key: bool,
+    key_field: KeyField,
     key: Option<SerContent>,
     tmp: Option<SerContent>,
 }
@@ -809,9 +1324,11 @@ where
         T: Serialize,
     {
         let key: SerContent = key.serialize(ContentSerializer::new(self.is_human_readable))?;
-        if key.as_str() == Some(MAP_KEY_IDENTIFIER) {
+        if key.as_str() == Some(self.key_field.name) {
             self.next_is_magic_key = true;
-            return Ok(());
+            if !self.key_field.keep {
+                return Ok(());
+            }
         }
         self.tmp = Some(key);
         Ok(())
@@ -825,6 +1342,14 @@ where
 
         if self.next_is_magic_key {
             self.next_is_magic_key = false;
+            if self.key_field.keep {
+                self.content.push((
+                    self.tmp
+                        .take()
+                        .expect("serialize_value called before serialize_key"),
+                    value.serialize(ContentSerializer::new(self.is_human_readable))?,
+                ));
+            }
             self.key = Some(value);
             return Ok(());
         }
@@ -842,19 +1367,20 @@ where
             self.delegate
                 .serialize_entry(&key, &SerContent::Map(self.content))
         } else {
-            Err(SerError::custom("missing value for `$key$` field"))
+            Err(self.key_field.missing_error())
         }
     }
 }
 
 /// Serialize a struct to a key and value pair of a map.
 ///
-/// This requires that the struct has one field named `$key$`.
+/// This requires that the struct has one field named like the key field, by default `$key$`.
 struct KeyValueStructSerializer<'a, M> {
     delegate: &'a mut M,
     is_human_readable: bool,
     name: &'static str,
     content: Vec<(&'static str, SerContent)>,
+    key_field: KeyField,
     key: Option<SerContent>,
 }
 
@@ -876,7 +1402,13 @@ where
         // Serialize to a Content type first
         let value: SerContent = value.serialize(ContentSerializer::new(self.is_human_readable))?;
 
-        if key == MAP_KEY_IDENTIFIER {
+        if key == self.key_field.name {
+            if self.key_field.keep {
+                self.content.push((
+                    key,
+                    value.serialize(ContentSerializer::new(self.is_human_readable))?,
+                ));
+            }
             self.key = Some(value);
             return Ok(());
         }
@@ -889,7 +1421,7 @@ where
             self.delegate
                 .serialize_entry(&key, &SerContent::Struct(self.name, self.content))
         } else {
-            Err(SerError::custom("missing value for `$key$` field"))
+            Err(self.key_field.missing_error())
         }
     }
 }
@@ -902,6 +1434,7 @@ where
 struct SeqDeserializer<M> {
     delegate: M,
     is_human_readable: bool,
+    key: KeyField,
 }
 
 impl<'de, M> Deserializer<'de> for SeqDeserializer<M>
@@ -950,6 +1483,7 @@ where
             seed.deserialize(MapKeyDeserializer {
                 delegate: &mut self.delegate,
                 is_human_readable: self.is_human_readable,
+                key: self.key,
                 key_value,
             })
             .map(Some)
@@ -966,6 +1500,7 @@ where
 struct MapKeyDeserializer<'de, M> {
     delegate: M,
     is_human_readable: bool,
+    key: KeyField,
     key_value: DeContent<'de>,
 }
 
@@ -992,6 +1527,7 @@ where
     {
         self.delegate.next_value_seed(KeyValueSeqDeserialize {
             delegate: visitor,
+            key: self.key,
             first: Some(self.key_value),
         })
     }
@@ -1003,6 +1539,7 @@ where
         self.delegate.next_value_seed(KeyValueTupleDeserialize {
             delegate: visitor,
             len,
+            key: self.key,
             first: Some(self.key_value),
         })
     }
@@ -1021,6 +1558,7 @@ where
                 delegate: visitor,
                 name,
                 len,
+                key: self.key,
                 first: Some(self.key_value),
             })
     }
@@ -1031,6 +1569,7 @@ where
     {
         self.delegate.next_value_seed(KeyValueMapDeserialize {
             delegate: visitor,
+            key: self.key,
             first: Some(self.key_value),
         })
     }
@@ -1048,6 +1587,7 @@ where
             delegate: visitor,
             name,
             fields,
+            key: self.key,
             first: Some(self.key_value),
         })
     }
@@ -1061,6 +1601,7 @@ where
 
 struct KeyValueSeqDeserialize<'de, V> {
     delegate: V,
+    key: KeyField,
     first: Option<DeContent<'de>>,
 }
 
@@ -1078,6 +1619,7 @@ where
         deserializer.deserialize_seq(VisitorWrapper {
             delegate: self.delegate,
             is_human_readable,
+            key: self.key,
             first: self.first.take(),
         })
     }
@@ -1086,6 +1628,7 @@ where
 struct KeyValueTupleDeserialize<'de, V> {
     delegate: V,
     len: usize,
+    key: KeyField,
     first: Option<DeContent<'de>>,
 }
 
@@ -1105,6 +1648,7 @@ where
             VisitorWrapper {
                 delegate: self.delegate,
                 is_human_readable,
+                key: self.key,
                 first: self.first.take(),
             },
         )
@@ -1115,6 +1659,7 @@ struct KeyValueTupleStructDeserialize<'de, V> {
     delegate: V,
     name: &'static str,
     len: usize,
+    key: KeyField,
     first: Option<DeContent<'de>>,
 }
 
@@ -1135,6 +1680,7 @@ where
             VisitorWrapper {
                 delegate: self.delegate,
                 is_human_readable,
+                key: self.key,
                 first: self.first.take(),
             },
         )
@@ -1143,6 +1689,7 @@ where
 
 struct KeyValueMapDeserialize<'de, V> {
     delegate: V,
+    key: KeyField,
     first: Option<DeContent<'de>>,
 }
 
@@ -1160,6 +1707,7 @@ where
         deserializer.deserialize_map(VisitorWrapper {
             delegate: self.delegate,
             is_human_readable,
+            key: self.key,
             first: self.first.take(),
         })
     }
@@ -1169,6 +1717,7 @@ struct KeyValueStructDeserialize<'de, V> {
     delegate: V,
     name: &'static str,
     fields: &'static [&'static str],
+    key: KeyField,
     first: Option<DeContent<'de>>,
 }
 
@@ -1189,6 +1738,7 @@ where
             VisitorWrapper {
                 delegate: self.delegate,
                 is_human_readable,
+                key: self.key,
                 first: self.first.take(),
             },
         )
@@ -1198,6 +1748,7 @@ where
 struct VisitorWrapper<'de, V> {
     delegate: V,
     is_human_readable: bool,
+    key: KeyField,
     first: Option<DeContent<'de>>,
 }
 
@@ -1218,6 +1769,7 @@ where
         self.delegate.visit_map(MapAccessWrapper {
             delegate: map,
             is_human_readable: self.is_human_readable,
+            key: self.key,
             first: self.first,
         })
     }
@@ -1229,6 +1781,7 @@ where
         self.delegate.visit_seq(SeqAccessWrapper {
             delegate: seq,
             is_human_readable: self.is_human_readable,
+            key: self.key,
             first: self.first,
         })
     }
@@ -1237,6 +1790,7 @@ where
 struct MapAccessWrapper<'de, M> {
     delegate: M,
     is_human_readable: bool,
+    key: KeyField,
     first: Option<DeContent<'de>>,
 }
 
@@ -1251,10 +1805,22 @@ where
         K: DeserializeSeed<'de>,
     {
         if self.first.is_some() {
-            seed.deserialize(serde::de::value::StringDeserializer::new(
-                MAP_KEY_IDENTIFIER.to_string(),
+            seed.deserialize(serde::de::value::BorrowedStrDeserializer::new(
+                self.key.name,
             ))
             .map(Some)
+        } else if self.key.keep {
+            // The map key is authoritative, so skip the copy of the key field in the value
+            while let Some(key) = self.delegate.next_key::<DeContent<'de>>()? {
+                if key.as_str() == Some(self.key.name) {
+                    self.delegate.next_value::<IgnoredAny>()?;
+                    continue;
+                }
+                return seed
+                    .deserialize(ContentDeserializer::new(key, self.is_human_readable))
+                    .map(Some);
+            }
+            Ok(None)
         } else {
             self.delegate.next_key_seed(seed)
         }
@@ -1275,6 +1841,7 @@ where
 struct SeqAccessWrapper<'de, M> {
     delegate: M,
     is_human_readable: bool,
+    key: KeyField,
     first: Option<DeContent<'de>>,
 }
 
@@ -1289,6 +1856,10 @@ where
         K: DeserializeSeed<'de>,
     {
         if let Some(first) = self.first.take() {
+            // The map key is authoritative, so skip the copy of the key in the value
+            if self.key.keep {
+                self.delegate.next_element::<IgnoredAny>()?;
+            }
             seed.deserialize(ContentDeserializer::new(first, self.is_human_readable))
                 .map(Some)
         } else {
@@ -317,18 +317,27 @@ pub mod chrono {
     pub use chrono_0_4::*;
 }
 #[cfg(feature = "alloc")]
+mod columnar;
+#[cfg(feature = "alloc")]
 mod content;
 pub mod de;
 #[cfg(feature = "alloc")]
 mod duplicate_key_impls;
 #[cfg(feature = "alloc")]
 mod enum_map;
+#[cfg(feature = "alloc")]
+mod flatten_keys;
 #[cfg(feature = "std")]
 mod flatten_maybe;
 pub mod formats;
+#[cfg(feature = "alloc")]
+mod grouped_by;
 #[cfg(feature = "hex")]
 #[cfg_attr(docsrs, doc(cfg(feature = "hex")))]
 pub mod hex;
+#[cfg(feature = "jiff_0_2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_2")))]
+pub mod jiff_0_2;
 #[cfg(feature = "json")]
 #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
 pub mod json;
@@ -340,10 +349,13 @@ pub mod schemars_0_8;
 pub mod ser;
 #[cfg(feature = "std")]
 mod serde_conv;
+#[cfg(feature = "alloc")]
+mod table;
 #[cfg(feature = "time_0_3")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time_0_3")))]
 pub mod time_0_3;
 mod utils;
+pub mod validate;
 #[cfg(feature = "std")]
 #[doc(hidden)]
 pub mod with_prefix;
@@ -447,10 +459,22 @@ pub mod __private__ {
 
 #[cfg(feature = "alloc")]
 #[doc(inline)]
-pub use crate::enum_map::EnumMap;
+pub use crate::columnar::Columnar;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use crate::enum_map::{EnumMap, EnumMapUnique, TaggedEnumMap};
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use crate::flatten_keys::FlattenKeys;
 #[cfg(feature = "alloc")]
 #[doc(inline)]
-pub use crate::key_value_map::KeyValueMap;
+pub use crate::grouped_by::GroupedBy;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use crate::key_value_map::{KeyValueMap, KeyValueMapBy};
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use crate::table::Table;
 #[doc(inline)]
 pub use crate::{de::DeserializeAs, ser::SerializeAs};
 use core::marker::PhantomData;
@@ -844,26 +868,32 @@ pub struct BytesOrString;
 /// Subsecond precision is *only* supported for [`DurationSecondsWithFrac`], but not for [`DurationSeconds`].
 /// You can configure the serialization format between integers, floats, and stringified numbers with the `FORMAT` specifier and configure the deserialization with the `STRICTNESS` specifier.
 ///
-/// The `STRICTNESS` specifier can either be [`formats::Strict`] or [`formats::Flexible`] and defaults to [`formats::Strict`].
+/// The `STRICTNESS` specifier can either be [`formats::Strict`], [`formats::Flexible`], or [`formats::Saturating`] and defaults to [`formats::Strict`].
 /// [`formats::Strict`] means that deserialization only supports the type given in `FORMAT`, e.g., if `FORMAT` is `u64` deserialization from a `f64` will error.
 /// [`formats::Flexible`] means that deserialization will perform a best effort to extract the correct duration and allows deserialization from any type.
 /// For example, deserializing `DurationSeconds<f64, Flexible>` will discard any subsecond precision during deserialization from `f64` and will parse a `String` as an integer number.
+/// [`formats::Saturating`] behaves like [`formats::Flexible`], but clamps out-of-range values instead of failing, e.g., negative values become a zero duration.
 /// Serialization of integers will round the duration to the nearest value.
 ///
 /// This type also supports [`chrono::Duration`] with the `chrono_0_4`-[feature flag].
+/// This type also supports [`jiff::SignedDuration`][::jiff_0_2::SignedDuration] and [`jiff::Span`][::jiff_0_2::Span] with the `jiff_0_2`-[feature flag].
 /// This type also supports [`time::Duration`][::time_0_3::Duration] with the `time_0_3`-[feature flag].
 ///
 /// This table lists the available `FORMAT`s for the different duration types.
 /// The `FORMAT` specifier defaults to `u64`/`f64`.
 ///
-/// | Duration Type         | Converter                 | Available `FORMAT`s      |
-/// | --------------------- | ------------------------- | ------------------------ |
-/// | `std::time::Duration` | `DurationSeconds`         | *`u64`*, `f64`, `String` |
-/// | `std::time::Duration` | `DurationSecondsWithFrac` | *`f64`*, `String`        |
-/// | `chrono::Duration`    | `DurationSeconds`         | `i64`, `f64`, `String`   |
-/// | `chrono::Duration`    | `DurationSecondsWithFrac` | *`f64`*, `String`        |
-/// | `time::Duration`      | `DurationSeconds`         | `i64`, `f64`, `String`   |
-/// | `time::Duration`      | `DurationSecondsWithFrac` | *`f64`*, `String`        |
+/// | Duration Type          | Converter                 | Available `FORMAT`s      |
+/// | ---------------------- | ------------------------- | ------------------------ |
+/// | `std::time::Duration`  | `DurationSeconds`         | *`u64`*, `f64`, `String` |
+/// | `std::time::Duration`  | `DurationSecondsWithFrac` | *`f64`*, `String`        |
+/// | `chrono::Duration`     | `DurationSeconds`         | `i64`, `f64`, `String`   |
+/// | `chrono::Duration`     | `DurationSecondsWithFrac` | *`f64`*, `String`        |
+/// | `jiff::SignedDuration` | `DurationSeconds`         | `i64`, `f64`, `String`   |
+/// | `jiff::SignedDuration` | `DurationSecondsWithFrac` | *`f64`*, `String`        |
+/// | `jiff::Span`           | `DurationSeconds`         | `i64`, `f64`, `String`   |
+/// | `jiff::Span`           | `DurationSecondsWithFrac` | *`f64`*, `String`        |
+/// | `time::Duration`       | `DurationSeconds`         | `i64`, `f64`, `String`   |
+/// | `time::Duration`       | `DurationSecondsWithFrac` | *`f64`*, `String`        |
 ///
 /// # Examples
 ///
@@ -991,25 +1021,31 @@ pub struct DurationSeconds<
 /// You can configure the serialization format between integers, floats, and stringified numbers with the `FORMAT` specifier and configure the deserialization with the `STRICTNESS` specifier.
 /// Serialization of integers will round the duration to the nearest value.
 ///
-/// The `STRICTNESS` specifier can either be [`formats::Strict`] or [`formats::Flexible`] and defaults to [`formats::Strict`].
+/// The `STRICTNESS` specifier can either be [`formats::Strict`], [`formats::Flexible`], or [`formats::Saturating`] and defaults to [`formats::Strict`].
 /// [`formats::Strict`] means that deserialization only supports the type given in `FORMAT`, e.g., if `FORMAT` is `u64` deserialization from a `f64` will error.
 /// [`formats::Flexible`] means that deserialization will perform a best effort to extract the correct duration and allows deserialization from any type.
 /// For example, deserializing `DurationSeconds<f64, Flexible>` will discard any subsecond precision during deserialization from `f64` and will parse a `String` as an integer number.
+/// [`formats::Saturating`] behaves like [`formats::Flexible`], but clamps out-of-range values instead of failing, e.g., negative values become a zero duration.
 ///
 /// This type also supports [`chrono::Duration`] with the `chrono`-[feature flag].
+/// This type also supports [`jiff::SignedDuration`][::jiff_0_2::SignedDuration] and [`jiff::Span`][::jiff_0_2::Span] with the `jiff_0_2`-[feature flag].
 /// This type also supports [`time::Duration`][::time_0_3::Duration] with the `time_0_3`-[feature flag].
 ///
 /// This table lists the available `FORMAT`s for the different duration types.
 /// The `FORMAT` specifier defaults to `u64`/`f64`.
 ///
-/// | Duration Type         | Converter                 | Available `FORMAT`s      |
-/// | --------------------- | ------------------------- | ------------------------ |
-/// | `std::time::Duration` | `DurationSeconds`         | *`u64`*, `f64`, `String` |
-/// | `std::time::Duration` | `DurationSecondsWithFrac` | *`f64`*, `String`        |
-/// | `chrono::Duration`    | `DurationSeconds`         | `i64`, `f64`, `String`   |
-/// | `chrono::Duration`    | `DurationSecondsWithFrac` | *`f64`*, `String`        |
-/// | `time::Duration`      | `DurationSeconds`         | `i64`, `f64`, `String`   |
-/// | `time::Duration`      | `DurationSecondsWithFrac` | *`f64`*, `String`        |
+/// | Duration Type          | Converter                 | Available `FORMAT`s      |
+/// | ---------------------- | ------------------------- | ------------------------ |
+/// | `std::time::Duration`  | `DurationSeconds`         | *`u64`*, `f64`, `String` |
+/// | `std::time::Duration`  | `DurationSecondsWithFrac` | *`f64`*, `String`        |
+/// | `chrono::Duration`     | `DurationSeconds`         | `i64`, `f64`, `String`   |
+/// | `chrono::Duration`     | `DurationSecondsWithFrac` | *`f64`*, `String`        |
+/// | `jiff::SignedDuration` | `DurationSeconds`         | `i64`, `f64`, `String`   |
+/// | `jiff::SignedDuration` | `DurationSecondsWithFrac` | *`f64`*, `String`        |
+/// | `jiff::Span`           | `DurationSeconds`         | `i64`, `f64`, `String`   |
+/// | `jiff::Span`           | `DurationSecondsWithFrac` | *`f64`*, `String`        |
+/// | `time::Duration`       | `DurationSeconds`         | `i64`, `f64`, `String`   |
+/// | `time::Duration`       | `DurationSecondsWithFrac` | *`f64`*, `String`        |
 ///
 /// # Examples
 ///
@@ -1171,12 +1207,14 @@ pub struct DurationNanoSecondsWithFrac<
 /// You can configure the serialization format between integers, floats, and stringified numbers with the `FORMAT` specifier and configure the deserialization with the `STRICTNESS` specifier.
 /// Serialization of integers will round the timestamp to the nearest value.
 ///
-/// The `STRICTNESS` specifier can either be [`formats::Strict`] or [`formats::Flexible`] and defaults to [`formats::Strict`].
+/// The `STRICTNESS` specifier can either be [`formats::Strict`], [`formats::Flexible`], or [`formats::Saturating`] and defaults to [`formats::Strict`].
 /// [`formats::Strict`] means that deserialization only supports the type given in `FORMAT`, e.g., if `FORMAT` is `i64` deserialization from a `f64` will error.
 /// [`formats::Flexible`] means that deserialization will perform a best effort to extract the correct timestamp and allows deserialization from any type.
 /// For example, deserializing `TimestampSeconds<f64, Flexible>` will discard any subsecond precision during deserialization from `f64` and will parse a `String` as an integer number.
+/// [`formats::Saturating`] behaves like [`formats::Flexible`], but clamps out-of-range values to the earliest or latest representable timestamp instead of failing.
 ///
 /// This type also supports [`chrono::DateTime`] with the `chrono_0_4`-[feature flag].
+/// This type also supports [`jiff::Timestamp`][::jiff_0_2::Timestamp] with the `jiff_0_2`-[feature flag].
 /// This type also supports [`time::OffsetDateTime`][::time_0_3::OffsetDateTime] and [`time::PrimitiveDateTime`][::time_0_3::PrimitiveDateTime] with the `time_0_3`-[feature flag].
 ///
 /// This table lists the available `FORMAT`s for the different timestamp types.
@@ -1192,6 +1230,8 @@ pub struct DurationNanoSecondsWithFrac<
 /// | `chrono::DateTime<Local>` | `TimestampSecondsWithFrac` | *`f64`*, `String`        |
 /// | `chrono::NaiveDateTime`   | `TimestampSeconds`         | *`i64`*, `f64`, `String` |
 /// | `chrono::NaiveDateTime`   | `TimestampSecondsWithFrac` | *`f64`*, `String`        |
+/// | `jiff::Timestamp`         | `TimestampSeconds`         | *`i64`*, `f64`, `String` |
+/// | `jiff::Timestamp`         | `TimestampSecondsWithFrac` | *`f64`*, `String`        |
 /// | `time::OffsetDateTime`    | `TimestampSeconds`         | *`i64`*, `f64`, `String` |
 /// | `time::OffsetDateTime`    | `TimestampSecondsWithFrac` | *`f64`*, `String`        |
 /// | `time::PrimitiveDateTime` | `TimestampSeconds`         | *`i64`*, `f64`, `String` |
@@ -1325,12 +1365,14 @@ pub struct TimestampSeconds<
 /// You can configure the serialization format between integers, floats, and stringified numbers with the `FORMAT` specifier and configure the deserialization with the `STRICTNESS` specifier.
 /// Serialization of integers will round the timestamp to the nearest value.
 ///
-/// The `STRICTNESS` specifier can either be [`formats::Strict`] or [`formats::Flexible`] and defaults to [`formats::Strict`].
+/// The `STRICTNESS` specifier can either be [`formats::Strict`], [`formats::Flexible`], or [`formats::Saturating`] and defaults to [`formats::Strict`].
 /// [`formats::Strict`] means that deserialization only supports the type given in `FORMAT`, e.g., if `FORMAT` is `i64` deserialization from a `f64` will error.
 /// [`formats::Flexible`] means that deserialization will perform a best effort to extract the correct timestamp and allows deserialization from any type.
 /// For example, deserializing `TimestampSeconds<f64, Flexible>` will discard any subsecond precision during deserialization from `f64` and will parse a `String` as an integer number.
+/// [`formats::Saturating`] behaves like [`formats::Flexible`], but clamps out-of-range values to the earliest or latest representable timestamp instead of failing.
 ///
 /// This type also supports [`chrono::DateTime`] and [`chrono::NaiveDateTime`][NaiveDateTime] with the `chrono`-[feature flag].
+/// This type also supports [`jiff::Timestamp`][::jiff_0_2::Timestamp] with the `jiff_0_2`-[feature flag].
 /// This type also supports [`time::OffsetDateTime`][::time_0_3::OffsetDateTime] and [`time::PrimitiveDateTime`][::time_0_3::PrimitiveDateTime] with the `time_0_3`-[feature flag].
 ///
 /// This table lists the available `FORMAT`s for the different timestamp types.
@@ -1346,6 +1388,8 @@ pub struct TimestampSeconds<
 /// | `chrono::DateTime<Local>` | `TimestampSecondsWithFrac` | *`f64`*, `String`        |
 /// | `chrono::NaiveDateTime`   | `TimestampSeconds`         | *`i64`*, `f64`, `String` |
 /// | `chrono::NaiveDateTime`   | `TimestampSecondsWithFrac` | *`f64`*, `String`        |
+/// | `jiff::Timestamp`         | `TimestampSeconds`         | *`i64`*, `f64`, `String` |
+/// | `jiff::Timestamp`         | `TimestampSecondsWithFrac` | *`f64`*, `String`        |
 /// | `time::OffsetDateTime`    | `TimestampSeconds`         | *`i64`*, `f64`, `String` |
 /// | `time::OffsetDateTime`    | `TimestampSecondsWithFrac` | *`f64`*, `String`        |
 /// | `time::PrimitiveDateTime` | `TimestampSeconds`         | *`i64`*, `f64`, `String` |
@@ -1508,6 +1552,419 @@ pub struct TimestampNanoSecondsWithFrac<
     STRICTNESS: formats::Strictness = formats::Strict,
 >(PhantomData<(FORMAT, STRICTNESS)>);
 
+/// De/Serialize timestamps from any common representation
+///
+/// Deserialization accepts all the representations commonly used for timestamps:
+///
+/// * integers and floats with the seconds since the UNIX epoch, e.g., `1478563200` or `1478563200.123`,
+/// * strings containing such a number, e.g., `"1478563200.123"`,
+/// * RFC 3339 strings, e.g., `"2016-11-08T00:00:00.123Z"` or `"2016-11-08T05:30:00+05:30"`.
+///   Common ISO 8601 variations, like a space instead of the `T` or an offset without colon (`+0530`), are accepted too.
+///
+/// This is useful if upstream APIs mix epoch numbers and date strings in the same field.
+///
+/// Serialization always emits the same canonical form, which is configured with the `FORMAT` specifier.
+///
+/// | `FORMAT`             | Serialized as                                                | Example                      |
+/// | -------------------- | ------------------------------------------------------------ | ---------------------------- |
+/// | `i64` (default)      | Seconds as integer, like [`TimestampSeconds<i64>`]           | `1478563200`                 |
+/// | `f64`                | Seconds as float, like [`TimestampSecondsWithFrac<f64>`]     | `1478563200.123`             |
+/// | `String`             | Seconds as string, like [`TimestampSecondsWithFrac<String>`] | `"1478563200.123"`           |
+/// | [`formats::Rfc3339`] | RFC 3339 string in UTC                                       | `"2016-11-08T00:00:00.123Z"` |
+///
+/// The RFC 3339 serialization uses milli-, micro-, or nanosecond precision, whichever is the shortest without losing precision.
+/// It fails for timestamps outside of the years 0 to 9999.
+///
+/// This type is supported for these types:
+///
+/// | Type                                                   | Feature      |
+/// | ------------------------------------------------------ | ------------ |
+/// | [`SystemTime`]                                         | `std`        |
+/// | [`chrono::DateTime<Utc>`], [`chrono::DateTime<Local>`] | `chrono_0_4` |
+/// | [`chrono::NaiveDateTime`]                              | `chrono_0_4` |
+/// | [`jiff::Timestamp`]                                    | `jiff_0_2`   |
+/// | [`time::OffsetDateTime`], [`time::PrimitiveDateTime`]  | `time_0_3`   |
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{formats::Rfc3339, serde_as, TimestampAny};
+/// # use std::time::{Duration, SystemTime};
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Timestamps {
+///     #[serde_as(as = "TimestampAny")]
+///     st_i64: SystemTime,
+///     #[serde_as(as = "TimestampAny<Rfc3339>")]
+///     st_rfc3339: SystemTime,
+/// }
+///
+/// let expected = Timestamps {
+///     st_i64: SystemTime::UNIX_EPOCH + Duration::new(1478563200, 0),
+///     st_rfc3339: SystemTime::UNIX_EPOCH + Duration::new(1478563200, 123_000_000),
+/// };
+///
+/// // Deserialization accepts numbers and strings
+/// let json = json!({
+///     "st_i64": "2016-11-08T00:00:00Z",
+///     "st_rfc3339": "1478563200.123",
+/// });
+/// assert_eq!(expected, serde_json::from_value(json).unwrap());
+///
+/// // but serialization always uses the configured format
+/// let json = json!({
+///     "st_i64": 1478563200,
+///     "st_rfc3339": "2016-11-08T00:00:00.123Z",
+/// });
+/// assert_eq!(json, serde_json::to_value(&expected).unwrap());
+/// # }
+/// ```
+///
+/// [`SystemTime`]: std::time::SystemTime
+/// [`chrono::DateTime<Local>`]: ::chrono_0_4::DateTime
+/// [`chrono::DateTime<Utc>`]: ::chrono_0_4::DateTime
+/// [`chrono::NaiveDateTime`]: ::chrono_0_4::NaiveDateTime
+/// [`jiff::Timestamp`]: ::jiff_0_2::Timestamp
+/// [`time::OffsetDateTime`]: ::time_0_3::OffsetDateTime
+/// [`time::PrimitiveDateTime`]: ::time_0_3::PrimitiveDateTime
+/// [`TimestampSeconds<i64>`]: TimestampSeconds
+/// [`TimestampSecondsWithFrac<f64>`]: TimestampSecondsWithFrac
+/// [`TimestampSecondsWithFrac<String>`]: TimestampSecondsWithFrac
+pub struct TimestampAny<FORMAT: formats::Format = i64>(PhantomData<FORMAT>);
+
+/// De/Serialize UTC offsets as `"+HH:MM"` strings
+///
+/// The offset is serialized as a string like `"+05:30"` or `"-09:30"`.
+/// An offset of zero is serialized as `"Z"`.
+/// Seconds are only emitted if the offset is not a whole number of minutes, e.g., `"+00:09:21"`.
+///
+/// The `STRICTNESS` specifier can either be [`formats::Strict`] or [`formats::Flexible`] and defaults to [`formats::Strict`].
+/// [`formats::Strict`] only accepts the forms produced by serialization, i.e., `"Z"`, `"+HH:MM"`, and `"+HH:MM:SS"`, as well as `"+00:00"` and `"-00:00"`.
+/// [`formats::Flexible`] additionally accepts a lowercase `"z"` and the compact forms without separators, i.e., `"+HHMM"`, `"+HH"`, and `"+HHMMSS"`.
+///
+/// This type is supported for these types:
+///
+/// | Type                    | Feature      |
+/// | ----------------------- | ------------ |
+/// | [`chrono::FixedOffset`] | `chrono_0_4` |
+/// | [`time::UtcOffset`]     | `time_0_3`   |
+///
+/// See [`OffsetSeconds`] for an integer based representation.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "macros", feature = "chrono_0_4"))] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{formats::Flexible, serde_as, OffsetAsString};
+/// # use chrono_0_4::FixedOffset;
+/// # /* Ugliness to make the docs look nicer since I want to hide the rename of the chrono crate
+/// use chrono::FixedOffset;
+/// # */
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Offsets {
+///     #[serde_as(as = "OffsetAsString")]
+///     india: FixedOffset,
+///     #[serde_as(as = "OffsetAsString")]
+///     utc: FixedOffset,
+///     #[serde_as(as = "OffsetAsString<Flexible>")]
+///     flexible: FixedOffset,
+/// }
+///
+/// let offsets = Offsets {
+///     india: FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap(),
+///     utc: FixedOffset::east_opt(0).unwrap(),
+///     flexible: FixedOffset::west_opt(5 * 3600).unwrap(),
+/// };
+/// let expected = json!({
+///     "india": "+05:30",
+///     "utc": "Z",
+///     "flexible": "-05:00",
+/// });
+/// assert_eq!(expected, serde_json::to_value(&offsets).unwrap());
+///
+/// // The flexible version also accepts the compact forms
+/// let json = json!({
+///     "india": "+05:30",
+///     "utc": "+00:00",
+///     "flexible": "-05",
+/// });
+/// assert_eq!(offsets, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+///
+/// [`chrono::FixedOffset`]: ::chrono_0_4::FixedOffset
+/// [`time::UtcOffset`]: ::time_0_3::UtcOffset
+pub struct OffsetAsString<STRICTNESS: formats::Strictness = formats::Strict>(
+    PhantomData<STRICTNESS>,
+);
+
+/// De/Serialize UTC offsets as seconds east of UTC
+///
+/// The offset is serialized as a signed number of seconds, e.g., `19800` for `+05:30` and `-34200` for `-09:30`.
+/// You can configure the serialization format between integers and stringified numbers with the `FORMAT` specifier and configure the deserialization with the `STRICTNESS` specifier.
+///
+/// The `STRICTNESS` specifier can either be [`formats::Strict`] or [`formats::Flexible`] and defaults to [`formats::Strict`].
+/// [`formats::Strict`] means that deserialization only supports the type given in `FORMAT`, e.g., if `FORMAT` is `i64` deserialization from a string will error.
+/// [`formats::Flexible`] means that deserialization accepts both integers and strings containing an integer.
+///
+/// This type is supported for these types:
+///
+/// | Type                    | Feature      |
+/// | ----------------------- | ------------ |
+/// | [`chrono::FixedOffset`] | `chrono_0_4` |
+/// | [`time::UtcOffset`]     | `time_0_3`   |
+///
+/// See [`OffsetAsString`] for the `"+HH:MM"` representation.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "macros", feature = "time_0_3"))] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{formats::Flexible, serde_as, OffsetSeconds};
+/// # use time_0_3::UtcOffset;
+/// # /* Ugliness to make the docs look nicer since I want to hide the rename of the time crate
+/// use time::UtcOffset;
+/// # */
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Offsets {
+///     #[serde_as(as = "OffsetSeconds")]
+///     offset_i64: UtcOffset,
+///     #[serde_as(as = "OffsetSeconds<String>")]
+///     offset_string: UtcOffset,
+///     #[serde_as(as = "OffsetSeconds<i64, Flexible>")]
+///     flexible: UtcOffset,
+/// }
+///
+/// let offsets = Offsets {
+///     offset_i64: UtcOffset::from_hms(5, 30, 0).unwrap(),
+///     offset_string: UtcOffset::from_hms(-9, -30, 0).unwrap(),
+///     flexible: UtcOffset::UTC,
+/// };
+/// let expected = json!({
+///     "offset_i64": 19800,
+///     "offset_string": "-34200",
+///     "flexible": 0,
+/// });
+/// assert_eq!(expected, serde_json::to_value(&offsets).unwrap());
+///
+/// // The flexible version also accepts strings
+/// let json = json!({
+///     "offset_i64": 19800,
+///     "offset_string": "-34200",
+///     "flexible": "0",
+/// });
+/// assert_eq!(offsets, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+///
+/// [`chrono::FixedOffset`]: ::chrono_0_4::FixedOffset
+/// [`time::UtcOffset`]: ::time_0_3::UtcOffset
+pub struct OffsetSeconds<
+    FORMAT: formats::Format = i64,
+    STRICTNESS: formats::Strictness = formats::Strict,
+>(PhantomData<(FORMAT, STRICTNESS)>);
+
+/// De/Serialize months as numbers from 1 to 12
+///
+/// January is serialized as `1` and December as `12`.
+/// Deserialization rejects all numbers outside of this range.
+///
+/// The adapter is available for these types:
+///
+/// | Type                | Feature      |
+/// | ------------------- | ------------ |
+/// | [`chrono::Month`]   | `chrono_0_4` |
+/// | [`time::Month`]     | `time_0_3`   |
+///
+/// See [`AsName`] for a representation using the month names.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "macros", feature = "chrono_0_4"))] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{serde_as, MonthAsNumber};
+/// # use chrono_0_4::Month;
+/// # /* Ugliness to make the docs look nicer since I want to hide the rename of the chrono crate
+/// use chrono::Month;
+/// # */
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde_as(as = "MonthAsNumber")]
+///     month: Month,
+/// }
+///
+/// let data = Data {
+///     month: Month::March,
+/// };
+/// let json = json!({"month": 3});
+/// assert_eq!(json, serde_json::to_value(&data).unwrap());
+/// assert_eq!(data, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+///
+/// [`chrono::Month`]: ::chrono_0_4::Month
+/// [`time::Month`]: ::time_0_3::Month
+pub struct MonthAsNumber;
+
+/// De/Serialize weekdays as numbers
+///
+/// The `NUMBERING` specifier selects the convention and defaults to [`formats::MondayZero`].
+///
+/// | `NUMBERING`             | Monday | Sunday |
+/// | ----------------------- | ------ | ------ |
+/// | [`formats::MondayZero`] | `0`    | `6`    |
+/// | [`formats::SundayZero`] | `1`    | `0`    |
+/// | [`formats::MondayOne`]  | `1`    | `7`    |
+///
+/// [`formats::MondayOne`] is the numbering used by ISO 8601.
+/// Deserialization rejects all numbers outside of the range of the convention.
+///
+/// The adapter is available for these types:
+///
+/// | Type                | Feature      |
+/// | ------------------- | ------------ |
+/// | [`chrono::Weekday`] | `chrono_0_4` |
+/// | [`time::Weekday`]   | `time_0_3`   |
+///
+/// See [`AsName`] for a representation using the weekday names.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "macros", feature = "time_0_3"))] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{formats::{MondayOne, SundayZero}, serde_as, WeekdayAsNumber};
+/// # use time_0_3::Weekday;
+/// # /* Ugliness to make the docs look nicer since I want to hide the rename of the time crate
+/// use time::Weekday;
+/// # */
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde_as(as = "WeekdayAsNumber")]
+///     monday_zero: Weekday,
+///     #[serde_as(as = "WeekdayAsNumber<SundayZero>")]
+///     sunday_zero: Weekday,
+///     #[serde_as(as = "WeekdayAsNumber<MondayOne>")]
+///     iso: Weekday,
+/// }
+///
+/// let data = Data {
+///     monday_zero: Weekday::Sunday,
+///     sunday_zero: Weekday::Sunday,
+///     iso: Weekday::Sunday,
+/// };
+/// let json = json!({
+///     "monday_zero": 6,
+///     "sunday_zero": 0,
+///     "iso": 7,
+/// });
+/// assert_eq!(json, serde_json::to_value(&data).unwrap());
+/// assert_eq!(data, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+///
+/// [`chrono::Weekday`]: ::chrono_0_4::Weekday
+/// [`time::Weekday`]: ::time_0_3::Weekday
+pub struct WeekdayAsNumber<NUMBERING: formats::WeekdayNumbering = formats::MondayZero>(
+    PhantomData<NUMBERING>,
+);
+
+/// De/Serialize months and weekdays by their English names
+///
+/// The `CASE` specifier selects the letter case used during serialization.
+/// It can be [`formats::Titlecase`] (`"January"`), [`formats::Lowercase`] (`"january"`), or [`formats::Uppercase`] (`"JANUARY"`) and defaults to [`formats::Titlecase`].
+/// The `LENGTH` specifier selects between the [`formats::FullName`] (`"January"`) and the three letter [`formats::Abbreviated`] name (`"Jan"`) and defaults to [`formats::FullName`].
+///
+/// Deserialization ignores the letter case and accepts both the full and the abbreviated names, independent of the specifiers.
+///
+/// The adapter is available for these types:
+///
+/// | Type                                   | Feature      |
+/// | -------------------------------------- | ------------ |
+/// | [`chrono::Month`], [`chrono::Weekday`] | `chrono_0_4` |
+/// | [`time::Month`], [`time::Weekday`]     | `time_0_3`   |
+///
+/// See [`MonthAsNumber`] and [`WeekdayAsNumber`] for numeric representations.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "macros", feature = "chrono_0_4"))] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{formats::{Abbreviated, FullName, Lowercase, Uppercase}, serde_as, AsName};
+/// # use chrono_0_4::{Month, Weekday};
+/// # /* Ugliness to make the docs look nicer since I want to hide the rename of the chrono crate
+/// use chrono::{Month, Weekday};
+/// # */
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde_as(as = "AsName")]
+///     month: Month,
+///     #[serde_as(as = "AsName<Lowercase, Abbreviated>")]
+///     month_short: Month,
+///     #[serde_as(as = "AsName<Uppercase, FullName>")]
+///     weekday: Weekday,
+/// }
+///
+/// let data = Data {
+///     month: Month::September,
+///     month_short: Month::September,
+///     weekday: Weekday::Wed,
+/// };
+/// let json = json!({
+///     "month": "September",
+///     "month_short": "sep",
+///     "weekday": "WEDNESDAY",
+/// });
+/// assert_eq!(json, serde_json::to_value(&data).unwrap());
+///
+/// // Deserialization accepts any letter case and length
+/// let json = json!({
+///     "month": "SEP",
+///     "month_short": "september",
+///     "weekday": "wed",
+/// });
+/// assert_eq!(data, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+///
+/// [`chrono::Month`]: ::chrono_0_4::Month
+/// [`chrono::Weekday`]: ::chrono_0_4::Weekday
+/// [`time::Month`]: ::time_0_3::Month
+/// [`time::Weekday`]: ::time_0_3::Weekday
+pub struct AsName<
+    CASE: formats::Format = formats::Titlecase,
+    LENGTH: formats::Format = formats::FullName,
+>(PhantomData<(CASE, LENGTH)>);
+
 /// Optimized handling of owned and borrowed byte representations.
 ///
 /// Serialization of byte sequences like `&[u8]` or `Vec<u8>` is quite inefficient since each value will be serialized individually.
@@ -1668,6 +2125,9 @@ pub struct Bytes;
 /// The serialization behavior can be tweaked to either always serialize as a list using [`PreferMany`] or to serialize as the inner element if possible using [`PreferOne`].
 /// By default, [`PreferOne`] is assumed, which can also be omitted like `OneOrMany<_>`.
 ///
+/// Besides `Vec`, all sequence and set types are supported, like `VecDeque`, `Box<[T]>`, `BTreeSet`, or `HashSet`.
+/// [`OneOrManyMap`] provides the same for maps.
+///
 /// [`PreferMany`]: crate::formats::PreferMany
 /// [`PreferOne`]: crate::formats::PreferOne
 ///
@@ -1721,6 +2181,71 @@ pub struct Bytes;
 #[cfg(feature = "alloc")]
 pub struct OneOrMany<T, FORMAT: formats::Format = formats::PreferOne>(PhantomData<(T, FORMAT)>);
 
+/// Deserialize a map from either a single map or a sequence of maps
+///
+/// This is the equivalent of [`OneOrMany`] for maps.
+/// Some formats, like YAML or JSON configurations, express a map as a list of single-entry maps, e.g., to preserve the order or to allow repeated keys.
+/// This adapter accepts both a map and a sequence of maps and collects all entries into a single map.
+/// If a key repeats, the last value wins.
+///
+/// `K` and `V` are the adapters for the keys and values.
+/// The serialization behavior can be tweaked to either serialize as a single map using [`PreferOne`] (default), or as a sequence of single-entry maps using [`PreferMany`].
+/// The implementation supports all map types supported by `serde_with`.
+///
+/// [`PreferMany`]: crate::formats::PreferMany
+/// [`PreferOne`]: crate::formats::PreferOne
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{formats::PreferMany, serde_as, OneOrManyMap};
+/// # use std::collections::BTreeMap;
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde_as(as = "OneOrManyMap<_, _>")]
+///     env: BTreeMap<String, String>,
+///     #[serde_as(as = "OneOrManyMap<_, _, PreferMany>")]
+///     labels: BTreeMap<String, u32>,
+/// }
+///
+/// let data = Data {
+///     env: BTreeMap::from([("HOME".into(), "/root".into()), ("TERM".into(), "xterm".into())]),
+///     labels: BTreeMap::from([("a".into(), 1), ("b".into(), 2)]),
+/// };
+///
+/// // The maps can be deserialized from either a single map
+/// let j = json!({
+///     "env": {"HOME": "/root", "TERM": "xterm"},
+///     "labels": {"a": 1, "b": 2},
+/// });
+/// assert_eq!(data, serde_json::from_value(j).unwrap());
+///
+/// // or a list of maps.
+/// let j = json!({
+///     "env": [{"HOME": "/root"}, {"TERM": "xterm"}],
+///     "labels": [{"a": 1}, {"b": 2}],
+/// });
+/// assert_eq!(data, serde_json::from_value(j.clone()).unwrap());
+///
+/// // `PreferMany` serializes as a list of single-entry maps.
+/// let j = json!({
+///     "env": {"HOME": "/root", "TERM": "xterm"},
+///     "labels": [{"a": 1}, {"b": 2}],
+/// });
+/// assert_eq!(j, serde_json::to_value(&data).unwrap());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct OneOrManyMap<K, V, FORMAT: formats::Format = formats::PreferOne>(
+    PhantomData<(K, V, FORMAT)>,
+);
+
 /// Try multiple deserialization options until one succeeds.
 ///
 /// This adapter allows you to specify a list of deserialization options.
@@ -2228,30 +2753,322 @@ pub struct BorrowCow;
 #[cfg(feature = "alloc")]
 pub struct VecSkipError<T>(PhantomData<T>);
 
-/// Deserialize a boolean from a number
+/// Deserialize any sequence, skipping elements which fail to deserialize.
 ///
-/// Deserialize a number (of `u8`) and turn it into a boolean.
-/// The adapter supports a [`Strict`](crate::formats::Strict) and [`Flexible`](crate::formats::Flexible) format.
-/// In `Strict` mode, the number must be `0` or `1`.
-/// All other values produce an error.
-/// In `Flexible` mode, the number any non-zero value is converted to `true`.
+/// This generalizes [`VecSkipError`] to all sequence and set types, like `VecDeque`, `BTreeSet`, or `HashSet`.
+/// The serialization behavior is identical to the underlying collection.
 ///
-/// During serialization only `0` or `1` are ever emitted.
+/// Each element is buffered before it is deserialized.
+/// This means a failing element cannot leave the deserializer in an inconsistent state.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # #[cfg(feature = "macros")] {
 /// # use serde::{Deserialize, Serialize};
-/// # use serde_json::json;
-/// # use serde_with::{serde_as, BoolFromInt};
+/// # use serde_with::{serde_as, SeqSkipError};
+/// # use std::collections::BTreeSet;
 /// #
-/// #[serde_as]
 /// # #[derive(Debug, PartialEq)]
-/// #[derive(Deserialize, Serialize)]
-/// struct Data(#[serde_as(as = "BoolFromInt")] bool);
-///
-/// let data = Data(true);
+/// #[derive(Deserialize, Serialize, PartialOrd, Ord, Eq)]
+/// # #[non_exhaustive]
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+/// # use Color::*;
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Palette(#[serde_as(as = "SeqSkipError<_>")] BTreeSet<Color>);
+///
+/// let data = Palette(BTreeSet::from([Green, Blue]));
+/// let source_json = r#"["Blue", "Yellow", "Green"]"#;
+/// let data_json = r#"["Green","Blue"]"#;
+/// // Ensure serialization and deserialization produce the expected results
+/// assert_eq!(data_json, serde_json::to_string(&data).unwrap());
+/// assert_eq!(data, serde_json::from_str(source_json).unwrap());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct SeqSkipError<T>(PhantomData<T>);
+
+/// Deserialize a map, skipping entries where the key or the value fails to deserialize.
+///
+/// The serialization behavior is identical to the underlying map type.
+/// This is an alternative to the map type which is resilient against unexpected data.
+/// `K` is used for the keys and `V` for the values.
+///
+/// Both the key and the value of each entry are buffered before they are deserialized.
+/// An entry is only kept if both succeed.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_with::{serde_as, DisplayFromStr, MapSkipError};
+/// # use std::collections::BTreeMap;
+/// #
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// # #[non_exhaustive]
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+/// # use Color::*;
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Palette(#[serde_as(as = "MapSkipError<DisplayFromStr, _>")] BTreeMap<u32, Color>);
+///
+/// let data = Palette(BTreeMap::from([(1, Blue), (3, Green)]));
+/// let source_json = r#"{"1": "Blue", "2": "Yellow", "3": "Green", "four": "Red"}"#;
+/// let data_json = r#"{"1":"Blue","3":"Green"}"#;
+/// // Ensure serialization and deserialization produce the expected results
+/// assert_eq!(data_json, serde_json::to_string(&data).unwrap());
+/// assert_eq!(data, serde_json::from_str(source_json).unwrap());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct MapSkipError<K, V>(PhantomData<(K, V)>);
+
+/// Deserialize a sequence into `Vec<Result<T, String>>`, keeping the errors of elements which fail to deserialize.
+///
+/// Unlike [`VecSkipError`], which drops invalid elements silently, each element which fails to deserialize is kept as an `Err` with the error message.
+/// The position of each element in the sequence is preserved, so the index of a failure can be recovered with `iter().enumerate()`.
+/// This allows logging or counting dropped elements, while still accepting the rest of the data.
+///
+/// Serialization only writes the `Ok` elements, such that the output can be deserialized again.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_with::{serde_as, VecCollectErrors};
+/// #
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// # #[non_exhaustive]
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+/// # use Color::*;
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Palette(#[serde_as(as = "VecCollectErrors<_>")] Vec<Result<Color, String>>);
+///
+/// let source_json = r#"["Blue", "Yellow", "Green"]"#;
+/// let palette: Palette = serde_json::from_str(source_json).unwrap();
+/// assert_eq!(palette.0[0], Ok(Blue));
+/// assert!(palette.0[1].as_ref().unwrap_err().contains("unknown variant `Yellow`"));
+/// assert_eq!(palette.0[2], Ok(Green));
+///
+/// // Only the valid elements are serialized
+/// assert_eq!(r#"["Blue","Green"]"#, serde_json::to_string(&palette).unwrap());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct VecCollectErrors<T>(PhantomData<T>);
+
+/// Limit the number of elements during deserialization
+///
+/// `MaxLen<N, T>` rejects sequences and sets with more than `N` elements, maps with more than `N` entries, and strings and byte buffers longer than `N` bytes.
+/// Unlike checking the length after deserialization, the limit is enforced while visiting the input.
+/// Deserialization stops as soon as the limit is exceeded and fails with an [`invalid_length`](serde::de::Error::invalid_length) error.
+/// The capacity preallocated by the collection is capped at `N` as well.
+/// This makes it a cheap protection against oversized payloads.
+///
+/// `T` is the adapter for the whole value, e.g., `MaxLen<10, Vec<DisplayFromStr>>`, and defaults to [`Same`].
+/// Only the length of the outermost value is limited.
+/// Serialization is unchanged and delegates to `T`.
+///
+/// Use [`LenBetween`] to require a minimum length too.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{serde_as, DisplayFromStr, MaxLen};
+/// # use std::collections::BTreeMap;
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde_as(as = "MaxLen<3>")]
+///     tags: Vec<String>,
+///     #[serde_as(as = "MaxLen<2, BTreeMap<_, DisplayFromStr>>")]
+///     limits: BTreeMap<String, u32>,
+///     #[serde_as(as = "MaxLen<8>")]
+///     name: String,
+/// }
+///
+/// let data = Data {
+///     tags: vec!["a".into(), "b".into()],
+///     limits: BTreeMap::from([("cpu".into(), 2)]),
+///     name: "serde".into(),
+/// };
+/// let j = json!({"tags": ["a", "b"], "limits": {"cpu": "2"}, "name": "serde"});
+/// assert_eq!(data, serde_json::from_value(j).unwrap());
+///
+/// // Inputs exceeding the limits are rejected
+/// let j = r#"{"tags": ["a", "b", "c", "d"], "limits": {}, "name": ""}"#;
+/// let err = serde_json::from_str::<Data>(j).unwrap_err();
+/// assert_eq!(
+///     "invalid length 4, expected at most 3 elements at line 1 column 29",
+///     err.to_string()
+/// );
+/// let j = r#"{"tags": [], "limits": {}, "name": "serde_with"}"#;
+/// let err = serde_json::from_str::<Data>(j).unwrap_err();
+/// assert_eq!(
+///     "invalid length 10, expected at most 8 bytes at line 1 column 47",
+///     err.to_string()
+/// );
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct MaxLen<const N: usize, T = Same>(PhantomData<T>);
+
+/// Limit the number of elements to a range during deserialization
+///
+/// `LenBetween<MIN, MAX, T>` works like [`MaxLen`], but additionally rejects values with fewer than `MIN` elements.
+/// Both bounds are inclusive.
+/// Sequences and sets count their elements, maps their entries, and strings and byte buffers their length in bytes.
+/// Serialization is unchanged and delegates to `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_with::{serde_as, LenBetween};
+/// # use std::collections::BTreeSet;
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data(#[serde_as(as = "LenBetween<1, 2>")] BTreeSet<u32>);
+///
+/// assert_eq!(Data(BTreeSet::from([1, 2])), serde_json::from_str("[1, 2]").unwrap());
+///
+/// let err = serde_json::from_str::<Data>("[]").unwrap_err();
+/// assert_eq!(
+///     "invalid length 0, expected between 1 and 2 elements at line 1 column 2",
+///     err.to_string()
+/// );
+/// let err = serde_json::from_str::<Data>("[1, 2, 3]").unwrap_err();
+/// assert_eq!(
+///     "invalid length 3, expected between 1 and 2 elements at line 1 column 9",
+///     err.to_string()
+/// );
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct LenBetween<const MIN: usize, const MAX: usize, T = Same>(PhantomData<T>);
+
+/// Check a constraint on the value after deserializing it
+///
+/// `Validated<T, V>` deserializes the value using the adapter `T` and then checks it with the [`Validator`](validate::Validator) `V`.
+/// If the value violates the constraint, deserialization fails with an [`invalid_value`](serde::de::Error::invalid_value) error describing the value and the constraint.
+/// This replaces a newtype with a `TryFrom` implementation for simple invariants.
+/// Serialization is unchanged and delegates to `T`.
+///
+/// The [`validate`] module contains the built-in validators:
+///
+/// * [`NonEmpty`](validate::NonEmpty) for strings and collections
+/// * [`InRange<MIN, MAX>`](validate::InRange) for integers
+/// * [`Finite`](validate::Finite) for floats
+/// * [`MatchesRegex`](validate::MatchesRegex) for strings, with the `regex_1` feature
+///
+/// They can be combined with [`And`](validate::And), [`Or`](validate::Or), and [`Not`](validate::Not).
+/// With the `schemars_0_8` feature, the constraints are added to the JSON schema too.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{
+/// #     serde_as,
+/// #     validate::{And, Finite, InRange, NonEmpty, Not},
+/// #     DisplayFromStr, Validated,
+/// # };
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde_as(as = "Validated<_, NonEmpty>")]
+///     name: String,
+///     #[serde_as(as = "Validated<DisplayFromStr, InRange<1, 65535>>")]
+///     port: u16,
+///     #[serde_as(as = "Validated<_, Finite>")]
+///     ratio: f64,
+///     // Negative numbers need braces
+///     #[serde_as(as = "Validated<_, And<InRange<{-10}, 10>, Not<InRange<0, 0>>>>")]
+///     offset: i8,
+/// }
+///
+/// let data = Data {
+///     name: "localhost".into(),
+///     port: 8080,
+///     ratio: 0.5,
+///     offset: -5,
+/// };
+/// let j = json!({"name": "localhost", "port": "8080", "ratio": 0.5, "offset": -5});
+/// assert_eq!(data, serde_json::from_value(j.clone()).unwrap());
+/// assert_eq!(j, serde_json::to_value(&data).unwrap());
+///
+/// // Invalid values are rejected with a descriptive error
+/// let j = json!({"name": "", "port": "8080", "ratio": 0.5, "offset": -5});
+/// assert_eq!(
+///     r#"invalid value: string "", expected a non-empty value"#,
+///     serde_json::from_value::<Data>(j).unwrap_err().to_string()
+/// );
+/// let j = json!({"name": "localhost", "port": "0", "ratio": 0.5, "offset": -5});
+/// assert_eq!(
+///     "invalid value: integer `0`, expected an integer between 1 and 65535",
+///     serde_json::from_value::<Data>(j).unwrap_err().to_string()
+/// );
+/// # }
+/// ```
+pub struct Validated<T, V>(PhantomData<(T, V)>);
+
+/// Deserialize a boolean from a number
+///
+/// Deserialize a number (of `u8`) and turn it into a boolean.
+/// The adapter supports a [`Strict`](crate::formats::Strict) and [`Flexible`](crate::formats::Flexible) format.
+/// In `Strict` mode, the number must be `0` or `1`.
+/// All other values produce an error.
+/// In `Flexible` mode, the number any non-zero value is converted to `true`.
+///
+/// During serialization only `0` or `1` are ever emitted.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{serde_as, BoolFromInt};
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data(#[serde_as(as = "BoolFromInt")] bool);
+///
+/// let data = Data(true);
 /// let j = json!(1);
 /// // Ensure serialization and deserialization produce the expected results
 /// assert_eq!(j, serde_json::to_value(&data).unwrap());
@@ -2427,6 +3244,193 @@ pub struct Map<K, V>(PhantomData<(K, V)>);
 /// ```
 pub struct Seq<V>(PhantomData<V>);
 
+/// De/Serialize a [`Vec`] as a map from indices to values
+///
+/// Some formats and backends, for example PHP arrays or documents stored in NoSQL databases, encode lists as maps, whose keys are the indices of the elements.
+/// The indices are stringified during serialization, e.g., `{"0": "a", "3": "b"}`.
+/// Both string and integer keys are accepted during deserialization.
+///
+/// The strictness parameter selects the supported Rust type:
+///
+/// * [`Strict`], the default, works for `Vec<T>`.
+///   All elements are serialized and every index up to the highest one must be present during deserialization.
+/// * [`Flexible`] works for sparse vectors of type `Vec<Option<T>>`.
+///   `None` elements are skipped during serialization and missing indices are filled with `None` during deserialization.
+///
+/// The vector is allocated up to the highest index found in the map.
+/// To prevent huge allocations from small inputs, indices larger than `MAX_INDEX` are rejected.
+/// The limit defaults to `65_535`.
+///
+/// This is different from [`Map`] and [`Seq`], which convert between maps and sequences of key-value pairs.
+///
+/// [`Strict`]: formats::Strict
+/// [`Flexible`]: formats::Flexible
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{formats::Flexible, serde_as, VecAsIndexMap};
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct A {
+///     #[serde_as(as = "VecAsIndexMap<_>")]
+///     dense: Vec<u32>,
+///     #[serde_as(as = "VecAsIndexMap<_, Flexible>")]
+///     sparse: Vec<Option<String>>,
+///     #[serde_as(as = "VecAsIndexMap<_, Flexible, 10>")]
+///     limited: Vec<Option<bool>>,
+/// }
+///
+/// let data = A {
+///     dense: vec![1, 2, 3],
+///     sparse: vec![Some("a".into()), None, None, Some("b".into())],
+///     limited: vec![None, Some(true)],
+/// };
+/// let value = json!({
+///     "dense": {"0": 1, "1": 2, "2": 3},
+///     "sparse": {"0": "a", "3": "b"},
+///     "limited": {"1": true},
+/// });
+/// assert_eq!(value, serde_json::to_value(&data).unwrap());
+/// assert_eq!(data, serde_json::from_value(value).unwrap());
+///
+/// // Holes are rejected for `Vec<T>`
+/// let value = json!({
+///     "dense": {"0": 1, "2": 3},
+///     "sparse": {},
+///     "limited": {},
+/// });
+/// assert!(serde_json::from_value::<A>(value).is_err());
+///
+/// // and indices above the limit as well
+/// let value = json!({
+///     "dense": {},
+///     "sparse": {},
+///     "limited": {"11": true},
+/// });
+/// assert!(serde_json::from_value::<A>(value).is_err());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct VecAsIndexMap<
+    T,
+    S: formats::Strictness = formats::Strict,
+    const MAX_INDEX: usize = 65_535,
+>(PhantomData<(T, S)>);
+
+/// Serialize a struct as a sequence of its field values
+///
+/// The field names are omitted and the values are serialized in the order of the fields, like `[v1, v2, ...]`.
+/// This saves space in binary formats, like MessagePack, while the struct keeps using named fields in other places.
+/// During deserialization, the sequence is passed to the struct's `Deserialize` implementation, which for derived implementations accepts the fields in order.
+/// This works for any `T: Serialize + Deserialize` which serializes as a struct.
+///
+/// The fields are identified by their position.
+/// Skipping a field during serialization, e.g., using `#[serde(skip_serializing_if = "...")]`, is an error, since it would shift the positions of the following fields.
+/// Use [`StructAsFieldIndex`] for structs with skipped fields.
+///
+/// Combined with [`IfIsHumanReadable`], the struct can use named fields for human-readable formats and the compact form otherwise.
+/// See [`StructAsFieldIndex`] for a map with integer keys instead.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_with::{serde_as, IfIsHumanReadable, Same, StructAsSeq};
+///
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Data {
+///     #[serde_as(as = "StructAsSeq")]
+///     point: Point,
+///     #[serde_as(as = "IfIsHumanReadable<Same, StructAsSeq>")]
+///     compact: Point,
+/// }
+///
+/// let data = Data {
+///     point: Point { x: 1, y: 2 },
+///     compact: Point { x: 3, y: 4 },
+/// };
+/// let json = json!({
+///     "point": [1, 2],
+///     "compact": {"x": 3, "y": 4},
+/// });
+/// assert_eq!(json, serde_json::to_value(&data).unwrap());
+/// assert_eq!(data, serde_json::from_value(json).unwrap());
+///
+/// // MessagePack is not human-readable, so both points are serialized as sequences
+/// let rmp = rmp_serde::to_vec(&data).unwrap();
+/// assert_eq!(data, rmp_serde::from_slice(&rmp).unwrap());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct StructAsSeq;
+
+/// Serialize a struct as a map from field indices to field values
+///
+/// The field names are replaced by their index, like `{0: v1, 1: v2}`.
+/// This saves space in binary formats, like MessagePack, while the struct keeps using named fields in other places.
+/// During deserialization, the map is passed to the struct's `Deserialize` implementation, which for derived implementations accepts `u64` field indices as keys.
+/// This works for any `T: Serialize + Deserialize` which serializes as a struct.
+/// The format must support integer map keys, so JSON is not supported.
+///
+/// The index is the position of the field in the struct.
+/// Fields skipped during serialization, e.g., using `#[serde(skip_serializing_if = "...")]`, are omitted from the map without changing the indices of the following fields.
+///
+/// Combined with [`IfIsHumanReadable`], the struct can use named fields for human-readable formats and the compact form otherwise.
+/// See [`StructAsSeq`] for a sequence of the field values instead.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// use serde_with::{serde_as, IfIsHumanReadable, Same, StructAsFieldIndex};
+///
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Data {
+///     #[serde_as(as = "IfIsHumanReadable<Same, StructAsFieldIndex>")]
+///     point: Point,
+/// }
+///
+/// let data = Data {
+///     point: Point { x: 1, y: 2 },
+/// };
+/// assert_eq!(r#"{"point":{"x":1,"y":2}}"#, serde_json::to_string(&data).unwrap());
+///
+/// // MessagePack uses the field indices
+/// let rmp = rmp_serde::to_vec(&data).unwrap();
+/// assert_eq!(b"\x91\x82\x00\x01\x01\x02", &*rmp);
+/// assert_eq!(data, rmp_serde::from_slice(&rmp).unwrap());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct StructAsFieldIndex;
+
 /// Ensure no duplicate keys exist in a map.
 ///
 /// By default serde has a last-value-wins implementation, if duplicate keys for a map exist.
@@ -2487,6 +3491,129 @@ pub struct MapPreventDuplicates<K, V>(PhantomData<(K, V)>);
 #[cfg(feature = "alloc")]
 pub struct MapFirstKeyWins<K, V>(PhantomData<(K, V)>);
 
+/// Merge the values of duplicate map keys
+///
+/// By default serde has a last-value-wins implementation, if duplicate keys for a map exist.
+/// Some formats, like log formats or converted INI files, repeat keys on purpose and all values are relevant.
+/// This helper combines the values of a repeated key using the `STRATEGY`, which implements [`MergeStrategy`](formats::MergeStrategy).
+///
+/// The predefined strategies are
+///
+/// * [`Concat`](formats::Concat): Append the later value, e.g., for `Vec` or `String` values.
+/// * [`ExtendValues`](formats::ExtendValues): Extend the existing value with the elements of the later value, e.g., for sets.
+/// * [`DeepMerge`](formats::DeepMerge): Merge nested maps recursively.
+/// * [`Replace`](formats::Replace): Keep the last value, like without this helper.
+///
+/// Serialization is identical to the underlying map.
+/// The implementation supports all map types supported by `serde_with`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::Deserialize;
+/// # use std::collections::BTreeMap;
+/// # use serde_with::{formats::{Concat, MergeStrategy}, serde_as, MapMergeDuplicates};
+/// #
+/// /// Add up the values
+/// struct Sum;
+///
+/// impl MergeStrategy<u32> for Sum {
+///     fn merge(existing: &mut u32, value: u32) {
+///         *existing += value;
+///     }
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, Eq, PartialEq)]
+/// #[derive(Deserialize)]
+/// struct Doc {
+///     #[serde_as(as = "MapMergeDuplicates<_, _, Concat>")]
+///     tags: BTreeMap<String, Vec<String>>,
+///     #[serde_as(as = "MapMergeDuplicates<_, _, Sum>")]
+///     counters: BTreeMap<String, u32>,
+/// }
+///
+/// let s = r#"{
+///     "tags": {"a": ["x"], "b": ["y"], "a": ["z"]},
+///     "counters": {"hits": 1, "misses": 1, "hits": 2}
+/// }"#;
+/// let v = Doc {
+///     tags: BTreeMap::from([
+///         ("a".into(), vec!["x".into(), "z".into()]),
+///         ("b".into(), vec!["y".into()]),
+///     ]),
+///     counters: BTreeMap::from([("hits".into(), 3), ("misses".into(), 1)]),
+/// };
+/// assert_eq!(v, serde_json::from_str(s).unwrap());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct MapMergeDuplicates<K, V, STRATEGY>(PhantomData<(K, V, STRATEGY)>);
+
+/// Normalize the string keys of a map during deserialization
+///
+/// Some maps treat keys as equal, even though they are spelled differently, like the HTTP headers `Content-Type` and `content-type`.
+/// This helper applies the `NORMALIZER`, which implements [`KeyNormalizer`](formats::KeyNormalizer), to each key before it is deserialized with `K`.
+/// The predefined normalizers are
+///
+/// * [`AsciiLowercase`](formats::AsciiLowercase): Convert ASCII characters to lowercase.
+/// * [`Lowercase`](formats::Lowercase) and [`Uppercase`](formats::Uppercase): Convert all characters using the Unicode case mapping.
+///   This is the same as [`str::to_lowercase`], which is close to, but not identical with, full Unicode case folding.
+/// * [`Trim`](formats::Trim): Remove leading and trailing whitespace.
+///
+/// Combine multiple normalizers using a tuple, e.g., `(Trim, AsciiLowercase)`.
+///
+/// Normalization can map different keys onto the same key.
+/// The `POLICY` determines how these duplicates are handled:
+///
+/// * [`LastValueWins`](formats::LastValueWins) (default): Keep the last value, like a normal map.
+/// * [`FirstValueWins`](formats::FirstValueWins): Keep the first value, like [`MapFirstKeyWins`].
+/// * [`PreventDuplicates`](formats::PreventDuplicates): Return an error, like [`MapPreventDuplicates`].
+///
+/// The keys are deserialized from strings, so `K` must support deserialization from a string.
+/// Serialization is identical to the underlying map, the keys are not normalized.
+/// The implementation supports all map types supported by `serde_with`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::Deserialize;
+/// # use std::collections::BTreeMap;
+/// # use serde_with::{
+/// #     formats::{AsciiLowercase, PreventDuplicates, Trim},
+/// #     serde_as, MapNormalizeKeys,
+/// # };
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, Eq, PartialEq)]
+/// #[derive(Deserialize)]
+/// struct Request {
+///     #[serde_as(as = "MapNormalizeKeys<(Trim, AsciiLowercase), _, _, PreventDuplicates>")]
+///     headers: BTreeMap<String, String>,
+/// }
+///
+/// let s = r#"{"headers": {"Content-Type": "text/plain", " ACCEPT ": "*/*"}}"#;
+/// let v = Request {
+///     headers: BTreeMap::from([
+///         ("accept".into(), "*/*".into()),
+///         ("content-type".into(), "text/plain".into()),
+///     ]),
+/// };
+/// assert_eq!(v, serde_json::from_str(s).unwrap());
+///
+/// // Keys which are equal after normalization are duplicates
+/// let s = r#"{"headers": {"Content-Type": "text/plain", "content-type": "text/html"}}"#;
+/// let res: Result<Request, _> = serde_json::from_str(s);
+/// assert!(res.is_err());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct MapNormalizeKeys<NORMALIZER, K, V, POLICY = formats::LastValueWins>(
+    PhantomData<(NORMALIZER, K, V, POLICY)>,
+);
+
 /// Ensure no duplicate values exist in a set.
 ///
 /// By default serde has a last-value-wins implementation, if duplicate values for a set exist.
@@ -2544,6 +3671,87 @@ pub struct SetPreventDuplicates<T>(PhantomData<T>);
 #[cfg(feature = "alloc")]
 pub struct SetLastValueWins<T>(PhantomData<T>);
 
+/// Serialize a map with its entries sorted by key
+///
+/// The iteration order of [`HashMap`] and similar maps is random, which makes the serialized output non-deterministic.
+/// This helper sorts the entries by their key before serializing them.
+/// This is useful for snapshot tests, hashing of the serialized content, or config files which are tracked in version control.
+///
+/// `K` and `V` are the adapters for the key and the value, respectively.
+/// The sorting uses the [`Ord`] implementation of the original key type, not of the serialized key.
+///
+/// Deserialization is identical to the underlying map.
+/// The implementation supports all map types supported by `serde_with`, including the `hashbrown` and `indexmap` maps.
+///
+/// [`HashMap`]: std::collections::HashMap
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use std::collections::HashMap;
+/// # use serde_with::{serde_as, DisplayFromStr, SortedMap};
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, Eq, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Doc {
+///     #[serde_as(as = "SortedMap<DisplayFromStr, _>")]
+///     map: HashMap<u32, bool>,
+/// }
+///
+/// let v = Doc {
+///     map: HashMap::from([(10, true), (2, false), (1, true)]),
+/// };
+/// let s = r#"{"map":{"1":true,"2":false,"10":true}}"#;
+/// assert_eq!(s, serde_json::to_string(&v).unwrap());
+/// assert_eq!(v, serde_json::from_str(s).unwrap());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct SortedMap<K, V>(PhantomData<(K, V)>);
+
+/// Serialize a set with its values sorted
+///
+/// The iteration order of [`HashSet`] and similar sets is random, which makes the serialized output non-deterministic.
+/// This helper sorts the values before serializing them.
+///
+/// `T` is the adapter for the values.
+/// The sorting uses the [`Ord`] implementation of the original value type, not of the serialized value.
+///
+/// Deserialization is identical to the underlying set.
+/// The implementation supports all set types supported by `serde_with`, including the `hashbrown` and `indexmap` sets.
+///
+/// [`HashSet`]: std::collections::HashSet
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use std::collections::HashSet;
+/// # use serde_with::{serde_as, SortedSet};
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, Eq, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Doc {
+///     #[serde_as(as = "SortedSet<_>")]
+///     set: HashSet<u32>,
+/// }
+///
+/// let v = Doc {
+///     set: HashSet::from([4, 1, 3, 2]),
+/// };
+/// let s = r#"{"set":[1,2,3,4]}"#;
+/// assert_eq!(s, serde_json::to_string(&v).unwrap());
+/// assert_eq!(v, serde_json::from_str(s).unwrap());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct SortedSet<T>(PhantomData<T>);
+
 /// Helper for implementing [`JsonSchema`] on serializers whose output depends
 /// on the type of the concrete field.
 ///
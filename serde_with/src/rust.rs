@@ -225,7 +225,7 @@ pub mod unwrap_or_skip {
 #[cfg(feature = "alloc")]
 pub mod sets_duplicate_value_is_error {
     use super::*;
-    use crate::duplicate_key_impls::PreventDuplicateInsertsSet;
+    use crate::duplicate_key_impls::{duplicate_error, DescribeSeed, PreventDuplicateInsertsSet};
 
     /// Deserialize a set and return an error on duplicate values
     pub fn deserialize<'de, D, T, V>(deserializer: D) -> Result<T, D::Error>
@@ -256,11 +256,16 @@ pub mod sets_duplicate_value_is_error {
                 A: SeqAccess<'de>,
             {
                 let mut values = Self::Value::new(access.size_hint());
+                let mut description = None;
+                let mut index = 0;
 
-                while let Some(value) = access.next_element()? {
+                while let Some(value) =
+                    access.next_element_seed(DescribeSeed::<V, Same>::new(&mut description))?
+                {
                     if !values.insert(value) {
-                        return Err(DeError::custom("invalid entry: found duplicate value"));
+                        return Err(duplicate_error("value", description.as_ref(), index));
                     };
+                    index += 1;
                 }
 
                 Ok(values)
@@ -344,7 +349,7 @@ pub mod sets_duplicate_value_is_error {
 #[cfg(feature = "alloc")]
 pub mod maps_duplicate_key_is_error {
     use super::*;
-    use crate::duplicate_key_impls::PreventDuplicateInsertsMap;
+    use crate::duplicate_key_impls::{duplicate_error, DescribeSeed, PreventDuplicateInsertsMap};
 
     /// Deserialize a map and return an error on duplicate keys
     pub fn deserialize<'de, D, T, K, V>(deserializer: D) -> Result<T, D::Error>
@@ -378,11 +383,17 @@ pub mod maps_duplicate_key_is_error {
                 A: MapAccess<'de>,
             {
                 let mut values = Self::Value::new(access.size_hint());
+                let mut description = None;
+                let mut index = 0;
 
-                while let Some((key, value)) = access.next_entry()? {
+                while let Some(key) =
+                    access.next_key_seed(DescribeSeed::<K, Same>::new(&mut description))?
+                {
+                    let value = access.next_value()?;
                     if !values.insert(key, value) {
-                        return Err(DeError::custom("invalid entry: found duplicate key"));
+                        return Err(duplicate_error("key", description.as_ref(), index));
                     };
+                    index += 1;
                 }
 
                 Ok(values)
@@ -153,6 +153,36 @@ where
     }
 }
 
+/// A [`Validator`] which can describe its constraint in a JSON schema.
+///
+/// This trait is used by the [`Validated`] adapter to add the constraints of the validator to the schema of the inner type.
+/// For example, [`NonEmpty`] sets `minItems`, `minProperties`, or `minLength`, and [`InRange`] sets `minimum` and `maximum`.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate schemars_0_8 as schemars;
+/// use schemars::schema::SchemaObject;
+/// use serde_with::schemars_0_8::JsonSchemaValidator;
+///
+/// # #[allow(dead_code)]
+/// struct Even;
+///
+/// impl JsonSchemaValidator for Even {
+///     fn update_schema(schema: &mut SchemaObject) {
+///         schema.number().multiple_of = Some(2.0);
+///     }
+/// }
+/// ```
+///
+/// [`Validator`]: crate::validate::Validator
+/// [`NonEmpty`]: crate::validate::NonEmpty
+/// [`InRange`]: crate::validate::InRange
+pub trait JsonSchemaValidator {
+    /// Add the constraint of the validator to the schema of the validated value.
+    fn update_schema(schema: &mut SchemaObject);
+}
+
 //===================================================================
 // Macro helpers
 
@@ -366,6 +396,16 @@ impl<T> JsonSchemaAs<T> for DisplayFromStr {
     forward_schema!(String);
 }
 
+#[cfg(feature = "jiff_0_2")]
+impl<T> JsonSchemaAs<T> for jiff_0_2::Rfc3339 {
+    forward_schema!(String);
+}
+
+#[cfg(feature = "jiff_0_2")]
+impl<T> JsonSchemaAs<T> for jiff_0_2::Iso8601 {
+    forward_schema!(String);
+}
+
 impl JsonSchemaAs<bool> for BoolFromInt<Strict> {
     fn schema_name() -> String {
         "BoolFromInt<Strict>".into()
@@ -578,6 +618,21 @@ where
     forward_schema!(WrapSchema<BTreeMap<K, V>, BTreeMap<KA, VA>>);
 }
 
+impl<T, TA, const MAX_INDEX: usize> JsonSchemaAs<Vec<T>> for VecAsIndexMap<TA, Strict, MAX_INDEX>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(BTreeMap<String, WrapSchema<T, TA>>);
+}
+
+impl<T, TA, const MAX_INDEX: usize> JsonSchemaAs<Vec<Option<T>>>
+    for VecAsIndexMap<TA, Flexible, MAX_INDEX>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(BTreeMap<String, WrapSchema<T, TA>>);
+}
+
 macro_rules! map_first_last_wins_schema {
     ($(=> $extra:ident)? $type:ty) => {
         impl<K, V, $($extra,)? KA, VA> JsonSchemaAs<$type> for MapFirstKeyWins<KA, VA>
@@ -593,6 +648,20 @@ macro_rules! map_first_last_wins_schema {
         {
             forward_schema!(BTreeMap<WrapSchema<K, KA>, WrapSchema<V, VA>>);
         }
+
+        impl<K, V, $($extra,)? KA, VA, STRATEGY> JsonSchemaAs<$type> for MapMergeDuplicates<KA, VA, STRATEGY>
+        where
+            VA: JsonSchemaAs<V>,
+        {
+            forward_schema!(BTreeMap<WrapSchema<K, KA>, WrapSchema<V, VA>>);
+        }
+
+        impl<K, V, $($extra,)? NORMALIZER, KA, VA, POLICY> JsonSchemaAs<$type> for MapNormalizeKeys<NORMALIZER, KA, VA, POLICY>
+        where
+            VA: JsonSchemaAs<V>,
+        {
+            forward_schema!(BTreeMap<WrapSchema<K, KA>, WrapSchema<V, VA>>);
+        }
     }
 }
 
@@ -649,6 +718,231 @@ where
     forward_schema!(WrapSchema<T, TA>);
 }
 
+/// Add the length bounds to the array, object, or string validation of `schema`
+fn len_bounds_schema(schema: Schema, min: usize, max: usize) -> Schema {
+    let mut schema = schema.into_object();
+    let min = u32::try_from(min).ok().filter(|&min| min > 0);
+    let max = u32::try_from(max).ok();
+
+    if let Some(array) = &mut schema.array {
+        array.min_items = min;
+        array.max_items = max;
+    }
+    if let Some(object) = &mut schema.object {
+        object.min_properties = min;
+        object.max_properties = max;
+    }
+    if schema.has_type(InstanceType::String) {
+        let string = schema.string();
+        string.min_length = min;
+        string.max_length = max;
+    }
+
+    schema.into()
+}
+
+impl<T, TA, const N: usize> JsonSchemaAs<T> for MaxLen<N, TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    fn schema_id() -> Cow<'static, str> {
+        std::format!(
+            "serde_with::MaxLen<{}, {}>",
+            N,
+            <WrapSchema<T, TA> as JsonSchema>::schema_id()
+        )
+        .into()
+    }
+
+    fn schema_name() -> String {
+        std::format!(
+            "MaxLen<{}, {}>",
+            N,
+            <WrapSchema<T, TA> as JsonSchema>::schema_name()
+        )
+    }
+
+    fn json_schema(gen: &mut ::schemars_0_8::gen::SchemaGenerator) -> Schema {
+        len_bounds_schema(<WrapSchema<T, TA> as JsonSchema>::json_schema(gen), 0, N)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+impl<T, TA, const MIN: usize, const MAX: usize> JsonSchemaAs<T> for LenBetween<MIN, MAX, TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    fn schema_id() -> Cow<'static, str> {
+        std::format!(
+            "serde_with::LenBetween<{}, {}, {}>",
+            MIN,
+            MAX,
+            <WrapSchema<T, TA> as JsonSchema>::schema_id()
+        )
+        .into()
+    }
+
+    fn schema_name() -> String {
+        std::format!(
+            "LenBetween<{}, {}, {}>",
+            MIN,
+            MAX,
+            <WrapSchema<T, TA> as JsonSchema>::schema_name()
+        )
+    }
+
+    fn json_schema(gen: &mut ::schemars_0_8::gen::SchemaGenerator) -> Schema {
+        len_bounds_schema(
+            <WrapSchema<T, TA> as JsonSchema>::json_schema(gen),
+            MIN,
+            MAX,
+        )
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+impl<T, TA, V> JsonSchemaAs<T> for Validated<TA, V>
+where
+    TA: JsonSchemaAs<T>,
+    V: JsonSchemaValidator,
+{
+    fn schema_id() -> Cow<'static, str> {
+        std::format!(
+            "serde_with::Validated<{}, {}>",
+            <WrapSchema<T, TA> as JsonSchema>::schema_id(),
+            core::any::type_name::<V>()
+        )
+        .into()
+    }
+
+    fn schema_name() -> String {
+        std::format!(
+            "Validated<{}>",
+            <WrapSchema<T, TA> as JsonSchema>::schema_name()
+        )
+    }
+
+    fn json_schema(gen: &mut ::schemars_0_8::gen::SchemaGenerator) -> Schema {
+        let mut schema = <WrapSchema<T, TA> as JsonSchema>::json_schema(gen).into_object();
+        V::update_schema(&mut schema);
+        schema.into()
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+impl<A, B> JsonSchemaValidator for validate::And<A, B>
+where
+    A: JsonSchemaValidator,
+    B: JsonSchemaValidator,
+{
+    fn update_schema(schema: &mut SchemaObject) {
+        let mut a = schema.clone();
+        A::update_schema(&mut a);
+        let mut b = schema.clone();
+        B::update_schema(&mut b);
+        schema.subschemas().all_of = Some(std::vec![a.into(), b.into()]);
+    }
+}
+
+impl<A, B> JsonSchemaValidator for validate::Or<A, B>
+where
+    A: JsonSchemaValidator,
+    B: JsonSchemaValidator,
+{
+    fn update_schema(schema: &mut SchemaObject) {
+        let mut a = schema.clone();
+        A::update_schema(&mut a);
+        let mut b = schema.clone();
+        B::update_schema(&mut b);
+        schema.subschemas().any_of = Some(std::vec![a.into(), b.into()]);
+    }
+}
+
+impl<V> JsonSchemaValidator for validate::Not<V>
+where
+    V: JsonSchemaValidator,
+{
+    fn update_schema(schema: &mut SchemaObject) {
+        let mut not = schema.clone();
+        V::update_schema(&mut not);
+        schema.subschemas().not = Some(Box::new(not.into()));
+    }
+}
+
+impl JsonSchemaValidator for validate::NonEmpty {
+    fn update_schema(schema: &mut SchemaObject) {
+        if let Some(array) = &mut schema.array {
+            array.min_items = Some(array.min_items.unwrap_or(0).max(1));
+        }
+        if let Some(object) = &mut schema.object {
+            object.min_properties = Some(object.min_properties.unwrap_or(0).max(1));
+        }
+        if schema.has_type(InstanceType::String) {
+            let string = schema.string();
+            string.min_length = Some(string.min_length.unwrap_or(0).max(1));
+        }
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> JsonSchemaValidator for validate::InRange<MIN, MAX> {
+    fn update_schema(schema: &mut SchemaObject) {
+        // Bounds beyond ±2^53 are not exactly representable, so round them into the range
+        let number = schema.number();
+        number.minimum = Some(round_bound(MIN, true));
+        number.maximum = Some(round_bound(MAX, false));
+    }
+}
+
+/// Convert the bound to the closest float which does not widen the range
+///
+/// The float is rounded up for the minimum and down for the maximum.
+fn round_bound(bound: i128, is_minimum: bool) -> f64 {
+    // 2^127, the only float the conversion can produce which is outside of the `i128` range
+    const I128_END: f64 = 170_141_183_460_469_231_731_687_303_715_884_105_728.0;
+
+    let float = bound as f64;
+    let rounded = if float >= I128_END {
+        core::cmp::Ordering::Greater
+    } else {
+        (float as i128).cmp(&bound)
+    };
+    // A rounded float is an integer beyond ±2^53, so the neighboring float has the same sign
+    let bits = float.to_bits();
+    match (is_minimum, rounded, float > 0.0) {
+        (true, core::cmp::Ordering::Less, true) | (false, core::cmp::Ordering::Greater, false) => {
+            f64::from_bits(bits + 1)
+        }
+        (true, core::cmp::Ordering::Less, false) | (false, core::cmp::Ordering::Greater, true) => {
+            f64::from_bits(bits - 1)
+        }
+        _ => float,
+    }
+}
+
+impl JsonSchemaValidator for validate::Finite {
+    // JSON numbers are always finite
+    fn update_schema(_schema: &mut SchemaObject) {}
+}
+
+#[cfg(feature = "regex_1")]
+impl<P> JsonSchemaValidator for validate::MatchesRegex<P>
+where
+    P: validate::RegexPattern,
+{
+    fn update_schema(schema: &mut SchemaObject) {
+        schema.string().pattern = Some(P::PATTERN.into());
+    }
+}
+
 impl<SEP, T, TA> JsonSchemaAs<T> for StringWithSeparator<SEP, TA>
 where
     SEP: Separator,
@@ -663,6 +957,83 @@ where
     forward_schema!(Vec<WrapSchema<T, TA>>);
 }
 
+impl<T, TA> JsonSchemaAs<Vec<Result<T, String>>> for VecCollectErrors<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(Vec<WrapSchema<T, TA>>);
+}
+
+impl<T, TA> JsonSchemaAs<Vec<T>> for SeqSkipError<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(Vec<WrapSchema<T, TA>>);
+}
+
+impl<T, TA> JsonSchemaAs<VecDeque<T>> for SeqSkipError<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(VecDeque<WrapSchema<T, TA>>);
+}
+
+impl<T, TA> JsonSchemaAs<BTreeSet<T>> for SeqSkipError<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(BTreeSet<WrapSchema<T, TA>>);
+}
+
+impl<T, TA, S> JsonSchemaAs<HashSet<T, S>> for SeqSkipError<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(HashSet<WrapSchema<T, TA>, S>);
+}
+
+impl<K, V, KA, VA> JsonSchemaAs<BTreeMap<K, V>> for MapSkipError<KA, VA>
+where
+    VA: JsonSchemaAs<V>,
+{
+    forward_schema!(BTreeMap<WrapSchema<K, KA>, WrapSchema<V, VA>>);
+}
+
+impl<K, V, S, KA, VA> JsonSchemaAs<HashMap<K, V, S>> for MapSkipError<KA, VA>
+where
+    VA: JsonSchemaAs<V>,
+{
+    forward_schema!(HashMap<WrapSchema<K, KA>, WrapSchema<V, VA>, S>);
+}
+
+impl<K, V, KA, VA> JsonSchemaAs<BTreeMap<K, V>> for SortedMap<KA, VA>
+where
+    VA: JsonSchemaAs<V>,
+{
+    forward_schema!(BTreeMap<WrapSchema<K, KA>, WrapSchema<V, VA>>);
+}
+
+impl<K, V, S, KA, VA> JsonSchemaAs<HashMap<K, V, S>> for SortedMap<KA, VA>
+where
+    VA: JsonSchemaAs<V>,
+{
+    forward_schema!(HashMap<WrapSchema<K, KA>, WrapSchema<V, VA>, S>);
+}
+
+impl<T, TA> JsonSchemaAs<BTreeSet<T>> for SortedSet<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(BTreeSet<WrapSchema<T, TA>>);
+}
+
+impl<T, TA, S> JsonSchemaAs<HashSet<T, S>> for SortedSet<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(HashSet<WrapSchema<T, TA>, S>);
+}
+
 mod timespan {
     use super::*;
 
@@ -749,6 +1120,13 @@ mod timespan {
     #[cfg(feature = "chrono_0_4")]
     declare_timespan_target!(::chrono_0_4::NaiveDateTime { i64, f64, String });
 
+    #[cfg(feature = "jiff_0_2")]
+    declare_timespan_target!(::jiff_0_2::SignedDuration { i64, f64, String });
+    #[cfg(feature = "jiff_0_2")]
+    declare_timespan_target!(::jiff_0_2::Span { i64, f64, String });
+    #[cfg(feature = "jiff_0_2")]
+    declare_timespan_target!(::jiff_0_2::Timestamp { i64, f64, String });
+
     #[cfg(feature = "time_0_3")]
     declare_timespan_target!(::time_0_3::Duration { i64, f64, String });
     #[cfg(feature = "time_0_3")]
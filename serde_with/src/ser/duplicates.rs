@@ -1,6 +1,7 @@
 use super::impls::{foreach_map, foreach_set};
 use crate::{
-    prelude::*, MapFirstKeyWins, MapPreventDuplicates, SetLastValueWins, SetPreventDuplicates,
+    prelude::*, MapFirstKeyWins, MapMergeDuplicates, MapNormalizeKeys, MapPreventDuplicates,
+    SetLastValueWins, SetPreventDuplicates,
 };
 #[cfg(feature = "hashbrown_0_14")]
 use hashbrown_0_14::{HashMap as HashbrownMap014, HashSet as HashbrownSet014};
@@ -69,6 +70,34 @@ macro_rules! map_duplicate_handling {
                 <$tyorig<KAs, VAs $(, $typaram)*>>::serialize_as(value, serializer)
             }
         }
+
+        impl<K, KAs, V, VAs, STRATEGY $(, $typaram)*> SerializeAs<$tyorig<K, V $(, $typaram)*>> for MapMergeDuplicates<KAs, VAs, STRATEGY>
+        where
+            KAs: SerializeAs<K>,
+            VAs: SerializeAs<V>,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(value: &$tyorig<K, V $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                <$tyorig<KAs, VAs $(, $typaram)*>>::serialize_as(value, serializer)
+            }
+        }
+
+        impl<NORMALIZER, K, KAs, V, VAs, POLICY $(, $typaram)*> SerializeAs<$tyorig<K, V $(, $typaram)*>> for MapNormalizeKeys<NORMALIZER, KAs, VAs, POLICY>
+        where
+            KAs: SerializeAs<K>,
+            VAs: SerializeAs<V>,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(value: &$tyorig<K, V $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                <$tyorig<KAs, VAs $(, $typaram)*>>::serialize_as(value, serializer)
+            }
+        }
     }
 }
 foreach_map!(map_duplicate_handling);
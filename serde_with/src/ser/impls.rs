@@ -1,3 +1,5 @@
+#[cfg(feature = "alloc")]
+use super::struct_as::StructAsSerializer;
 use crate::{formats, formats::Strictness, prelude::*};
 #[cfg(feature = "hashbrown_0_14")]
 use hashbrown_0_14::{HashMap as HashbrownMap014, HashSet as HashbrownSet014};
@@ -472,6 +474,91 @@ tuple_seq_as_map_arr!([(K, V); N], BTreeMap<K, V>);
 #[cfg(feature = "std")]
 tuple_seq_as_map_arr!([(K, V); N], HashMap<K, V>);
 
+/// Serialize a map index as a string
+#[cfg(feature = "alloc")]
+struct MapIndex(usize);
+
+#[cfg(feature = "alloc")]
+impl Serialize for MapIndex {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&self.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U, const MAX_INDEX: usize> SerializeAs<Vec<T>>
+    for VecAsIndexMap<U, formats::Strict, MAX_INDEX>
+where
+    U: SerializeAs<T>,
+{
+    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(
+            source
+                .iter()
+                .enumerate()
+                .map(|(index, value)| (MapIndex(index), SerializeAsWrap::<T, U>::new(value))),
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U, const MAX_INDEX: usize> SerializeAs<Vec<Option<T>>>
+    for VecAsIndexMap<U, formats::Flexible, MAX_INDEX>
+where
+    U: SerializeAs<T>,
+{
+    fn serialize_as<S>(source: &Vec<Option<T>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(source.iter().flatten().count()))?;
+        for (index, value) in source.iter().enumerate() {
+            if let Some(value) = value {
+                map.serialize_entry(&MapIndex(index), &SerializeAsWrap::<T, U>::new(value))?;
+            }
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SerializeAs<T> for StructAsSeq
+where
+    T: Serialize,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        source.serialize(StructAsSerializer {
+            delegate: serializer,
+            field_index: false,
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SerializeAs<T> for StructAsFieldIndex
+where
+    T: Serialize,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        source.serialize(StructAsSerializer {
+            delegate: serializer,
+            field_index: true,
+        })
+    }
+}
+
 // endregion
 ///////////////////////////////////////////////////////////////////////////////
 // region: Conversion types which cause different serialization behavior
@@ -531,6 +618,175 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<T, U> SerializeAs<Vec<Result<T, String>>> for VecCollectErrors<U>
+where
+    U: SerializeAs<T>,
+{
+    fn serialize_as<S>(source: &Vec<Result<T, String>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(
+            source
+                .iter()
+                .filter_map(|res| res.as_ref().ok())
+                .map(SerializeAsWrap::<T, U>::new),
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! seq_skip_error_impl {
+    ($ty:ident < T $(: $tbound1:ident $(+ $tbound2:ident)*)* $(, $typaram:ident : $bound:ident )* >) => {
+        impl<T, U $(, $typaram)*> SerializeAs<$ty<T $(, $typaram)*>> for SeqSkipError<U>
+        where
+            U: SerializeAs<T>,
+            $(T: ?Sized + $tbound1 $(+ $tbound2)*,)*
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$ty<T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                $ty::<U $(, $typaram)*>::serialize_as(source, serializer)
+            }
+        }
+    }
+}
+#[cfg(feature = "alloc")]
+foreach_seq!(seq_skip_error_impl);
+
+#[cfg(feature = "alloc")]
+macro_rules! map_skip_error_impl {
+    ($ty:ident < K, V $(, $typaram:ident : $bound:ident)* >) => {
+        impl<K, KU, V, VU $(, $typaram)*> SerializeAs<$ty<K, V $(, $typaram)*>> for MapSkipError<KU, VU>
+        where
+            KU: SerializeAs<K>,
+            VU: SerializeAs<V>,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$ty<K, V $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                $ty::<KU, VU $(, $typaram)*>::serialize_as(source, serializer)
+            }
+        }
+    }
+}
+#[cfg(feature = "alloc")]
+foreach_map!(map_skip_error_impl);
+
+#[cfg(feature = "alloc")]
+macro_rules! sorted_set_impl {
+    ($ty:ident < T $(, $typaram:ident : $bound:ident)* >) => {
+        impl<T, U $(, $typaram)*> SerializeAs<$ty<T $(, $typaram)*>> for SortedSet<U>
+        where
+            T: Ord,
+            U: SerializeAs<T>,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$ty<T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut values: Vec<&T> = source.iter().collect();
+                values.sort_unstable();
+                serializer.collect_seq(values.into_iter().map(SerializeAsWrap::<T, U>::new))
+            }
+        }
+    }
+}
+#[cfg(feature = "alloc")]
+foreach_set!(sorted_set_impl);
+
+#[cfg(feature = "alloc")]
+macro_rules! sorted_map_impl {
+    ($ty:ident < K, V $(, $typaram:ident : $bound:ident)* >) => {
+        impl<K, KU, V, VU $(, $typaram)*> SerializeAs<$ty<K, V $(, $typaram)*>> for SortedMap<KU, VU>
+        where
+            K: Ord,
+            KU: SerializeAs<K>,
+            VU: SerializeAs<V>,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$ty<K, V $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut entries: Vec<(&K, &V)> = source.iter().collect();
+                entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+                serializer.collect_map(entries.into_iter().map(|(k, v)| {
+                    (
+                        SerializeAsWrap::<K, KU>::new(k),
+                        SerializeAsWrap::<V, VU>::new(v),
+                    )
+                }))
+            }
+        }
+    }
+}
+#[cfg(feature = "alloc")]
+foreach_map!(sorted_map_impl);
+
+#[cfg(feature = "alloc")]
+macro_rules! len_impl {
+    ($ty:ty $(, $typaram:ident)* $(; $lt:lifetime)?) => {
+        impl<$($lt,)? TAs, const N: usize $(, $typaram)*> SerializeAs<$ty> for MaxLen<N, TAs>
+        where
+            TAs: SerializeAs<$ty>,
+        {
+            fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                TAs::serialize_as(source, serializer)
+            }
+        }
+
+        impl<$($lt,)? TAs, const MIN: usize, const MAX: usize $(, $typaram)*> SerializeAs<$ty>
+            for LenBetween<MIN, MAX, TAs>
+        where
+            TAs: SerializeAs<$ty>,
+        {
+            fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                TAs::serialize_as(source, serializer)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! seq_len_impl {
+    ($ty:ident < T $(, $typaram:ident : $bound:ident)* >) => {
+        len_impl!($ty<T $(, $typaram)*>, T $(, $typaram)*);
+    };
+}
+#[cfg(feature = "alloc")]
+foreach_seq!(seq_len_impl);
+
+#[cfg(feature = "alloc")]
+macro_rules! map_len_impl {
+    ($ty:ident < K, V $(, $typaram:ident : $bound:ident)* >) => {
+        len_impl!($ty<K, V $(, $typaram)*>, K, V $(, $typaram)*);
+    };
+}
+#[cfg(feature = "alloc")]
+foreach_map!(map_len_impl);
+
+#[cfg(feature = "alloc")]
+len_impl!(String);
+#[cfg(feature = "alloc")]
+len_impl!(Box<str>);
+#[cfg(feature = "alloc")]
+len_impl!(Cow<'a, str>; 'a);
+#[cfg(feature = "alloc")]
+len_impl!(Cow<'a, [u8]>; 'a);
+
 impl<T> SerializeAs<Option<T>> for NoneAsEmptyString
 where
     T: Display,
@@ -723,6 +979,25 @@ use_signed_duration!(
     }
 );
 
+#[cfg(feature = "std")]
+macro_rules! use_timestamp_any {
+    ($ty:ty => $($format:ty),* $(,)?) => {
+        $(
+            impl SerializeAs<$ty> for TimestampAny<$format> {
+                fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    TimestampAny::<$format>::serialize_as(&DurationSigned::from(source), serializer)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "std")]
+use_timestamp_any!(SystemTime => i64, f64, String, formats::Rfc3339);
+
 impl<T, U> SerializeAs<T> for DefaultOnNull<U>
 where
     U: SerializeAs<T>,
@@ -735,6 +1010,18 @@ where
     }
 }
 
+impl<T, U, V> SerializeAs<T> for Validated<U, V>
+where
+    U: SerializeAs<T>,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        U::serialize_as(source, serializer)
+    }
+}
+
 impl SerializeAs<&[u8]> for Bytes {
     fn serialize_as<S>(bytes: &&[u8], serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -813,34 +1100,105 @@ impl<'a, const N: usize> SerializeAs<Cow<'a, [u8; N]>> for Bytes {
 }
 
 #[cfg(feature = "alloc")]
-impl<T, U> SerializeAs<Vec<T>> for OneOrMany<U, formats::PreferOne>
-where
-    U: SerializeAs<T>,
-{
-    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match source.len() {
-            1 => SerializeAsWrap::<T, U>::new(source.iter().next().expect("Cannot be empty"))
-                .serialize(serializer),
-            _ => SerializeAsWrap::<Vec<T>, Vec<U>>::new(source).serialize(serializer),
+macro_rules! one_or_many_impl {
+    ($ty:ident < T $(: $tbound1:ident $(+ $tbound2:ident)*)* $(, $typaram:ident : $bound:ident )* >) => {
+        impl<T, U $(, $typaram)*> SerializeAs<$ty<T $(, $typaram)*>> for OneOrMany<U, formats::PreferOne>
+        where
+            U: SerializeAs<T>,
+            $(T: ?Sized + $tbound1 $(+ $tbound2)*,)*
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$ty<T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match source.len() {
+                    1 => SerializeAsWrap::<T, U>::new(source.iter().next().expect("Cannot be empty"))
+                        .serialize(serializer),
+                    _ => serializer.collect_seq(source.iter().map(|item| SerializeAsWrap::<T, U>::new(item))),
+                }
+            }
+        }
+
+        impl<T, U $(, $typaram)*> SerializeAs<$ty<T $(, $typaram)*>> for OneOrMany<U, formats::PreferMany>
+        where
+            U: SerializeAs<T>,
+            $(T: ?Sized + $tbound1 $(+ $tbound2)*,)*
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$ty<T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.collect_seq(source.iter().map(|item| SerializeAsWrap::<T, U>::new(item)))
+            }
         }
     }
 }
+#[cfg(feature = "alloc")]
+foreach_seq!(one_or_many_impl);
+
+/// Serialize a single map entry as a map
+#[cfg(feature = "alloc")]
+struct SingleEntryMap<'a, K, KU, V, VU>(&'a K, &'a V, PhantomData<(KU, VU)>);
 
 #[cfg(feature = "alloc")]
-impl<T, U> SerializeAs<Vec<T>> for OneOrMany<U, formats::PreferMany>
+impl<'a, K, KU, V, VU> Serialize for SingleEntryMap<'a, K, KU, V, VU>
 where
-    U: SerializeAs<T>,
+    KU: SerializeAs<K>,
+    VU: SerializeAs<V>,
 {
-    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        SerializeAsWrap::<Vec<T>, Vec<U>>::new(source).serialize(serializer)
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(
+            &SerializeAsWrap::<K, KU>::new(self.0),
+            &SerializeAsWrap::<V, VU>::new(self.1),
+        )?;
+        map.end()
+    }
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! one_or_many_map_impl {
+    ($ty:ident < K, V $(, $typaram:ident : $bound:ident)* >) => {
+        impl<K, KU, V, VU $(, $typaram)*> SerializeAs<$ty<K, V $(, $typaram)*>> for OneOrManyMap<KU, VU, formats::PreferOne>
+        where
+            KU: SerializeAs<K>,
+            VU: SerializeAs<V>,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$ty<K, V $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                $ty::<KU, VU $(, $typaram)*>::serialize_as(source, serializer)
+            }
+        }
+
+        impl<K, KU, V, VU $(, $typaram)*> SerializeAs<$ty<K, V $(, $typaram)*>> for OneOrManyMap<KU, VU, formats::PreferMany>
+        where
+            KU: SerializeAs<K>,
+            VU: SerializeAs<V>,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$ty<K, V $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.collect_seq(
+                    source
+                        .iter()
+                        .map(|(k, v)| SingleEntryMap::<K, KU, V, VU>(k, v, PhantomData)),
+                )
+            }
+        }
     }
 }
+#[cfg(feature = "alloc")]
+foreach_map!(one_or_many_map_impl);
 
 #[cfg(feature = "alloc")]
 impl<T, TAs1> SerializeAs<T> for PickFirst<(TAs1,)>
@@ -0,0 +1,247 @@
+//! Serializer for [`StructAsSeq`] and [`StructAsFieldIndex`]
+//!
+//! The fields of the struct are forwarded directly to the sequence or map of the wrapped serializer.
+//! Skipped fields are tracked, such that the field indices stay aligned with the struct definition.
+
+use crate::prelude::*;
+
+/// Accept only structs and forward their fields as a sequence or as a map with the field indices as keys
+pub(super) struct StructAsSerializer<S> {
+    pub(super) delegate: S,
+    /// Use a map with the field indices as keys, otherwise a sequence
+    pub(super) field_index: bool,
+}
+
+/// Forward the struct fields to the sequence or map of the wrapped serializer
+pub(super) enum SerializeStructAs<S: Serializer> {
+    Seq(S::SerializeSeq),
+    Map { map: S::SerializeMap, index: u64 },
+}
+
+impl<S> Serializer for StructAsSerializer<S>
+where
+    S: Serializer,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    type SerializeSeq = Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = Impossible<Self::Ok, Self::Error>;
+    type SerializeStruct = SerializeStructAs<S>;
+    type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
+
+    fn is_human_readable(&self) -> bool {
+        self.delegate.is_human_readable()
+    }
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_i128(self, _v: i128) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_u128(self, _v: u128) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_char(self, _v: char) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_str(self, _v: &str) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _value: &T) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        // `len` is the number of fields which are not skipped
+        if self.field_index {
+            Ok(SerializeStructAs::Map {
+                map: self.delegate.serialize_map(Some(len))?,
+                index: 0,
+            })
+        } else {
+            Ok(SerializeStructAs::Seq(
+                self.delegate.serialize_seq(Some(len))?,
+            ))
+        }
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(SerError::custom("the value must serialize as a struct"))
+    }
+}
+
+impl<S> SerializeStruct for SerializeStructAs<S>
+where
+    S: Serializer,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        match self {
+            SerializeStructAs::Seq(seq) => seq.serialize_element(value),
+            SerializeStructAs::Map { map, index } => {
+                map.serialize_entry(index, value)?;
+                *index += 1;
+                Ok(())
+            }
+        }
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+        match self {
+            // A sequence has no way to mark the position of a missing field
+            SerializeStructAs::Seq(_) => Err(SerError::custom(format_args!(
+                "the field `{key}` cannot be skipped, since the fields are identified by their position"
+            ))),
+            SerializeStructAs::Map { index, .. } => {
+                *index += 1;
+                Ok(())
+            }
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        match self {
+            SerializeStructAs::Seq(seq) => seq.end(),
+            SerializeStructAs::Map { map, .. } => map.end(),
+        }
+    }
+}
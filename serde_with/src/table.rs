@@ -0,0 +1,255 @@
+use crate::{
+    columnar::collect_columns,
+    content::{
+        de::{Content as DeContent, ContentDeserializer},
+        ser::Content,
+    },
+    formats::TableLayout,
+    prelude::*,
+};
+
+/// Serialize a `Vec` of structs as a table of column names and rows of values
+///
+/// This is the format of many CSV-like APIs, where the field names are only sent once in a header.
+/// The layout is selected with `LAYOUT`:
+///
+/// * [`ColumnsAndRows`], the default, uses an object with separate `columns` and `rows` fields, like `{"columns": ["a", "b"], "rows": [[1, 2], [3, 4]]}`.
+/// * [`HeaderRow`] uses a list of rows, where the first row contains the column names, like `[["a", "b"], [1, 2], [3, 4]]`.
+///
+/// The elements must serialize as a struct or a map.
+/// The columns follow the order of the fields during serialization.
+/// If a field is missing from some elements, e.g., due to `#[serde(skip_serializing_if = "...")]`, `null` is used in its place.
+/// A field repeated within one element is an error.
+///
+/// During deserialization, the values are matched to the fields by the column name, so the column order does not matter.
+/// Columns without a matching field are ignored, unless the struct uses `#[serde(deny_unknown_fields)]`.
+/// Missing columns and `null` values are treated like missing fields, so `Option` fields become `None` and fields with `#[serde(default)]` use their default.
+/// Each row must have one value per column.
+///
+/// `T` is used to de/serialize the elements and defaults to [`Same`].
+///
+/// [`ColumnsAndRows`]: crate::formats::ColumnsAndRows
+/// [`HeaderRow`]: crate::formats::HeaderRow
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_with::{formats::HeaderRow, serde_as, Table};
+///
+/// # #[derive(Debug, Clone, PartialEq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     id: u32,
+///     name: String,
+///     email: Option<String>,
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, Clone, PartialEq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Users {
+///     #[serde_as(as = "Table")]
+///     users: Vec<User>,
+///     #[serde_as(as = "Table<_, HeaderRow>")]
+///     admins: Vec<User>,
+/// }
+///
+/// let user = |id, name: &str| User {
+///     id,
+///     name: name.into(),
+///     email: None,
+/// };
+/// let users = Users {
+///     users: vec![user(1, "alice"), user(2, "bob")],
+///     admins: vec![user(3, "carol")],
+/// };
+/// let json = json!({
+///     "users": {
+///         "columns": ["id", "name", "email"],
+///         "rows": [[1, "alice", null], [2, "bob", null]],
+///     },
+///     "admins": [
+///         ["id", "name", "email"],
+///         [3, "carol", null],
+///     ],
+/// });
+/// assert_eq!(json, serde_json::to_value(&users).unwrap());
+/// assert_eq!(users, serde_json::from_value(json).unwrap());
+///
+/// // Columns are matched by name, extra columns are ignored, and missing optional fields are `None`
+/// let json = json!({
+///     "users": {
+///         "columns": ["name", "age", "id"],
+///         "rows": [["alice", 30, 1], ["bob", 40, 2]],
+///     },
+///     "admins": [["name", "id"], ["carol", 3]],
+/// });
+/// assert_eq!(users, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+pub struct Table<T = Same, LAYOUT = formats::ColumnsAndRows>(PhantomData<(T, LAYOUT)>);
+
+impl<T, TAs, LAYOUT> SerializeAs<Vec<T>> for Table<TAs, LAYOUT>
+where
+    TAs: SerializeAs<T>,
+    LAYOUT: TableLayout,
+{
+    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let columns = collect_columns::<T, TAs, S::Error>(source, serializer.is_human_readable())?;
+        let (header, columns): (Vec<Content>, Vec<Vec<Content>>) = columns.into_iter().unzip();
+        let mut columns: Vec<_> = columns.into_iter().map(Vec::into_iter).collect();
+        let rows: Vec<Vec<Content>> = (0..source.len())
+            .map(|_| columns.iter_mut().filter_map(Iterator::next).collect())
+            .collect();
+
+        if LAYOUT::header_row() {
+            let mut seq = serializer.serialize_seq(Some(rows.len() + 1))?;
+            seq.serialize_element(&header)?;
+            for row in &rows {
+                seq.serialize_element(row)?;
+            }
+            seq.end()
+        } else {
+            let mut table = serializer.serialize_struct("Table", 2)?;
+            table.serialize_field("columns", &header)?;
+            table.serialize_field("rows", &rows)?;
+            table.end()
+        }
+    }
+}
+
+impl<'de, T, TAs, LAYOUT> DeserializeAs<'de, Vec<T>> for Table<TAs, LAYOUT>
+where
+    TAs: DeserializeAs<'de, T>,
+    LAYOUT: TableLayout,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct TableVisitor<T, TAs> {
+            is_human_readable: bool,
+            header_row: bool,
+            phantom: PhantomData<(T, TAs)>,
+        }
+
+        impl<'de, T, TAs> Visitor<'de> for TableVisitor<T, TAs>
+        where
+            TAs: DeserializeAs<'de, T>,
+        {
+            type Value = Vec<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if self.header_row {
+                    formatter.write_str("a list of rows, starting with the column names")
+                } else {
+                    formatter.write_str("a table with columns and rows")
+                }
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                if self.header_row {
+                    let header = seq
+                        .next_element::<Vec<DeContent<'de>>>()?
+                        .unwrap_or_default();
+                    let mut rows = Vec::with_capacity(utils::size_hint_cautious::<
+                        Vec<DeContent<'de>>,
+                    >(seq.size_hint()));
+                    while let Some(row) = seq.next_element()? {
+                        rows.push(row);
+                    }
+                    rows_into_values::<T, TAs, A::Error>(header, rows, self.is_human_readable)
+                } else {
+                    let header = seq
+                        .next_element()?
+                        .ok_or_else(|| DeError::invalid_length(0, &self))?;
+                    let rows = seq
+                        .next_element()?
+                        .ok_or_else(|| DeError::invalid_length(1, &self))?;
+                    rows_into_values::<T, TAs, A::Error>(header, rows, self.is_human_readable)
+                }
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut header = None;
+                let mut rows = None;
+                while let Some(key) = map.next_key::<DeContent<'de>>()? {
+                    match key.as_str() {
+                        Some("columns") => {
+                            if header.is_some() {
+                                return Err(DeError::duplicate_field("columns"));
+                            }
+                            header = Some(map.next_value()?);
+                        }
+                        Some("rows") => {
+                            if rows.is_some() {
+                                return Err(DeError::duplicate_field("rows"));
+                            }
+                            rows = Some(map.next_value()?);
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                let header = header.ok_or_else(|| DeError::missing_field("columns"))?;
+                let rows = rows.ok_or_else(|| DeError::missing_field("rows"))?;
+                rows_into_values::<T, TAs, A::Error>(header, rows, self.is_human_readable)
+            }
+        }
+
+        let visitor = TableVisitor::<T, TAs> {
+            is_human_readable: deserializer.is_human_readable(),
+            header_row: LAYOUT::header_row(),
+            phantom: PhantomData,
+        };
+        if LAYOUT::header_row() {
+            deserializer.deserialize_seq(visitor)
+        } else {
+            deserializer.deserialize_struct("Table", &["columns", "rows"], visitor)
+        }
+    }
+}
+
+/// Deserialize each row as a map from the column names to the row values
+fn rows_into_values<'de, T, TAs, E>(
+    header: Vec<DeContent<'de>>,
+    rows: Vec<Vec<DeContent<'de>>>,
+    is_human_readable: bool,
+) -> Result<Vec<T>, E>
+where
+    TAs: DeserializeAs<'de, T>,
+    E: DeError,
+{
+    rows.into_iter()
+        .enumerate()
+        .map(|(index, row)| {
+            if row.len() != header.len() {
+                return Err(E::custom(format_args!(
+                    "invalid length {} of row {index}, expected {} values like the columns",
+                    row.len(),
+                    header.len(),
+                )));
+            }
+            let fields = header
+                .iter()
+                .zip(row)
+                .filter_map(|(field, value)| match value {
+                    // `null` marks a field missing from the element
+                    DeContent::None | DeContent::Unit => None,
+                    value => Some((field.clone(), value)),
+                })
+                .collect();
+            TAs::deserialize_as(ContentDeserializer::new(
+                DeContent::Map(fields),
+                is_human_readable,
+            ))
+        })
+        .collect()
+}
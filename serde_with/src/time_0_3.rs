@@ -4,18 +4,26 @@
 //! No extra types are exposed. Instead it enables support for [`time_0_3::Duration`] together with [`DurationSeconds`] and its variants.
 //! The types [`time_0_3::PrimitiveDateTime`] and [`time_0_3::OffsetDateTime`] are supported by [`TimestampSeconds`] and its variants.
 //! The well-known format descriptions [`Rfc2822`], [`Rfc3339`] and [`Iso8601`] are supported for [`OffsetDateTime`].
+//! [`time_0_3::UtcOffset`] is supported by [`OffsetAsString`] and [`OffsetSeconds`].
+//! [`time_0_3::Month`] and [`time_0_3::Weekday`] are supported by [`MonthAsNumber`], [`WeekdayAsNumber`], and [`AsName`].
 //!
 //! [time]: https://docs.rs/time/0.3/
 
 use crate::{
-    formats::{Flexible, Format, Strict, Strictness},
+    formats::{self, Flexible, Format, Saturating, Strict, Strictness},
     prelude::*,
+    utils::{
+        calendar::{calendar_impls, MonthNumber, WeekdayNumber},
+        offset::UtcOffsetSeconds,
+    },
 };
 #[cfg(feature = "std")]
 use ::time_0_3::format_description::well_known::{
     iso8601::EncodedConfig, Iso8601, Rfc2822, Rfc3339,
 };
-use ::time_0_3::{Duration as Time03Duration, OffsetDateTime, PrimitiveDateTime};
+use ::time_0_3::{
+    Duration as Time03Duration, Month, OffsetDateTime, PrimitiveDateTime, UtcOffset, Weekday,
+};
 
 /// Create a [`PrimitiveDateTime`] for the Unix Epoch
 fn unix_epoch_primitive() -> PrimitiveDateTime {
@@ -62,6 +70,22 @@ where
     Ok(dur)
 }
 
+/// Convert a [`DurationSigned`] into a [`time_0_3::Duration`], if it is in the representable range
+fn checked_duration_from_duration_signed(sdur: DurationSigned) -> Option<Time03Duration> {
+    let dur = Time03Duration::try_from(sdur.duration).ok()?;
+    Some(if sdur.sign.is_negative() { -dur } else { dur })
+}
+
+/// Convert a [`DurationSigned`] into a [`time_0_3::Duration`], clamping it to the representable range
+fn duration_from_duration_signed_saturating<'de, D>(
+    sdur: DurationSigned,
+) -> Result<Time03Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(sdur.saturating_convert(checked_duration_from_duration_signed))
+}
+
 macro_rules! use_duration_signed_ser {
     (
         $main_trait:ident $internal_trait:ident =>
@@ -320,6 +344,28 @@ where
     Ok(unix_epoch_primitive() + duration_from_duration_signed::<D>(dur)?)
 }
 
+fn duration_to_offset_datetime_saturating<'de, D>(
+    dur: DurationSigned,
+) -> Result<OffsetDateTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(dur.saturating_convert(|dur| {
+        OffsetDateTime::UNIX_EPOCH.checked_add(checked_duration_from_duration_signed(dur)?)
+    }))
+}
+
+fn duration_to_primitive_datetime_saturating<'de, D>(
+    dur: DurationSigned,
+) -> Result<PrimitiveDateTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(dur.saturating_convert(|dur| {
+        unix_epoch_primitive().checked_add(checked_duration_from_duration_signed(dur)?)
+    }))
+}
+
 // No subsecond precision
 use_duration_signed_de!(
     DurationSeconds DurationSeconds,
@@ -519,6 +565,50 @@ use_duration_signed_de!(
     }
 );
 
+// Saturating conversions clamp out-of-range values
+use_duration_signed_de!(
+    DurationSeconds DurationSeconds,
+    DurationMilliSeconds DurationMilliSeconds,
+    DurationMicroSeconds DurationMicroSeconds,
+    DurationNanoSeconds DurationNanoSeconds,
+    DurationSecondsWithFrac DurationSecondsWithFrac,
+    DurationMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    DurationMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    DurationNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        Time03Duration; duration_from_duration_signed_saturating =>
+        {FORMAT, Saturating => FORMAT: Format}
+    }
+);
+use_duration_signed_de!(
+    TimestampSeconds DurationSeconds,
+    TimestampMilliSeconds DurationMilliSeconds,
+    TimestampMicroSeconds DurationMicroSeconds,
+    TimestampNanoSeconds DurationNanoSeconds,
+    TimestampSecondsWithFrac DurationSecondsWithFrac,
+    TimestampMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    TimestampMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    TimestampNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        OffsetDateTime; duration_to_offset_datetime_saturating =>
+        {FORMAT, Saturating => FORMAT: Format}
+    }
+);
+use_duration_signed_de!(
+    TimestampSeconds DurationSeconds,
+    TimestampMilliSeconds DurationMilliSeconds,
+    TimestampMicroSeconds DurationMicroSeconds,
+    TimestampNanoSeconds DurationNanoSeconds,
+    TimestampSecondsWithFrac DurationSecondsWithFrac,
+    TimestampMilliSecondsWithFrac DurationMilliSecondsWithFrac,
+    TimestampMicroSecondsWithFrac DurationMicroSecondsWithFrac,
+    TimestampNanoSecondsWithFrac DurationNanoSecondsWithFrac,
+    => {
+        PrimitiveDateTime; duration_to_primitive_datetime_saturating =>
+        {FORMAT, Saturating => FORMAT: Format}
+    }
+);
+
 #[cfg(feature = "std")]
 impl SerializeAs<OffsetDateTime> for Rfc2822 {
     fn serialize_as<S>(datetime: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
@@ -626,3 +716,205 @@ impl<'de, const CONFIG: EncodedConfig> DeserializeAs<'de, OffsetDateTime> for Is
         deserializer.deserialize_str(Helper::<CONFIG>)
     }
 }
+
+macro_rules! use_timestamp_any_ser {
+    (
+        $ty:ty; $converter:ident =>
+        $({
+            $format:ty =>
+            $($tbound:ident: $bound:ident)*
+        })*
+    ) => {
+        $(
+            impl<$($tbound,)*> SerializeAs<$ty> for TimestampAny<$format>
+            where
+                $($tbound: $bound,)*
+            {
+                fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let dur: DurationSigned = $converter(source);
+                    TimestampAny::<$format>::serialize_as(&dur, serializer)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! use_timestamp_any_de {
+    ($ty:ty; $converter:ident) => {
+        impl<'de, FORMAT> DeserializeAs<'de, $ty> for TimestampAny<FORMAT>
+        where
+            FORMAT: Format,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let dur: DurationSigned = TimestampAny::<FORMAT>::deserialize_as(deserializer)?;
+                $converter::<D>(dur)
+            }
+        }
+    };
+}
+
+use_timestamp_any_ser!(
+    OffsetDateTime; offset_datetime_to_duration =>
+    {i64 =>}
+    {f64 =>}
+    {formats::Rfc3339 =>}
+);
+#[cfg(feature = "alloc")]
+use_timestamp_any_ser!(
+    OffsetDateTime; offset_datetime_to_duration =>
+    {String =>}
+);
+use_timestamp_any_ser!(
+    PrimitiveDateTime; primitive_datetime_to_duration =>
+    {i64 =>}
+    {f64 =>}
+    {formats::Rfc3339 =>}
+);
+#[cfg(feature = "alloc")]
+use_timestamp_any_ser!(
+    PrimitiveDateTime; primitive_datetime_to_duration =>
+    {String =>}
+);
+use_timestamp_any_de!(OffsetDateTime; duration_to_offset_datetime);
+use_timestamp_any_de!(PrimitiveDateTime; duration_to_primitive_datetime);
+
+macro_rules! use_utc_offset_seconds_ser {
+    (
+        $ty:ty; $converter:ident =>
+        $({
+            $main:ty =>
+            $($tbound:ident: $bound:ident)*
+        })*
+    ) => {
+        $(
+            impl<$($tbound,)*> SerializeAs<$ty> for $main
+            where
+                $($tbound: $bound,)*
+            {
+                fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let offset: UtcOffsetSeconds = $converter(source);
+                    <$main as SerializeAs<UtcOffsetSeconds>>::serialize_as(&offset, serializer)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! use_utc_offset_seconds_de {
+    (
+        $ty:ty; $converter:ident =>
+        $({
+            $main:ty =>
+            $($tbound:ident: $bound:ident)*
+        })*
+    ) => {
+        $(
+            impl<'de, $($tbound,)*> DeserializeAs<'de, $ty> for $main
+            where
+                $($tbound: $bound,)*
+            {
+                fn deserialize_as<D>(deserializer: D) -> Result<$ty, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let offset: UtcOffsetSeconds =
+                        <$main as DeserializeAs<'de, UtcOffsetSeconds>>::deserialize_as(deserializer)?;
+                    $converter::<D>(offset)
+                }
+            }
+        )*
+    };
+}
+
+fn utc_offset_to_seconds(source: &UtcOffset) -> UtcOffsetSeconds {
+    UtcOffsetSeconds(source.whole_seconds())
+}
+
+fn seconds_to_utc_offset<'de, D>(offset: UtcOffsetSeconds) -> Result<UtcOffset, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    UtcOffset::from_whole_seconds(offset.0).map_err(|err| {
+        DeError::custom(format_args!(
+            "UTC offset is outside of the representable range: {err}"
+        ))
+    })
+}
+
+use_utc_offset_seconds_ser!(
+    UtcOffset; utc_offset_to_seconds =>
+    {OffsetAsString<STRICTNESS> => STRICTNESS: Strictness}
+    {OffsetSeconds<i64, STRICTNESS> => STRICTNESS: Strictness}
+);
+#[cfg(feature = "alloc")]
+use_utc_offset_seconds_ser!(
+    UtcOffset; utc_offset_to_seconds =>
+    {OffsetSeconds<String, STRICTNESS> => STRICTNESS: Strictness}
+);
+use_utc_offset_seconds_de!(
+    UtcOffset; seconds_to_utc_offset =>
+    {OffsetAsString<Strict> =>}
+    {OffsetAsString<Flexible> =>}
+    {OffsetSeconds<i64, Strict> =>}
+    {OffsetSeconds<FORMAT, Flexible> => FORMAT: Format}
+);
+#[cfg(feature = "alloc")]
+use_utc_offset_seconds_de!(
+    UtcOffset; seconds_to_utc_offset =>
+    {OffsetSeconds<String, Strict> =>}
+);
+
+const MONTHS: [Month; 12] = [
+    Month::January,
+    Month::February,
+    Month::March,
+    Month::April,
+    Month::May,
+    Month::June,
+    Month::July,
+    Month::August,
+    Month::September,
+    Month::October,
+    Month::November,
+    Month::December,
+];
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+    Weekday::Sunday,
+];
+
+fn month_to_number(source: &Month) -> MonthNumber {
+    MonthNumber(u8::from(*source))
+}
+
+fn number_to_month(month: MonthNumber) -> Month {
+    MONTHS[usize::from(month.0 - 1)]
+}
+
+fn weekday_to_number(source: &Weekday) -> WeekdayNumber {
+    WeekdayNumber(source.number_days_from_monday())
+}
+
+fn number_to_weekday(weekday: WeekdayNumber) -> Weekday {
+    WEEKDAYS[usize::from(weekday.0)]
+}
+
+calendar_impls!(
+    Month: month_to_number, number_to_month;
+    Weekday: weekday_to_number, number_to_weekday;
+);
@@ -1,4 +1,7 @@
+pub(crate) mod calendar;
 pub(crate) mod duration;
+pub(crate) mod offset;
+pub(crate) mod rfc3339;
 
 use crate::prelude::*;
 
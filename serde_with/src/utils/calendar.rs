@@ -0,0 +1,440 @@
+//! Internal Helper types for months and weekdays
+
+use crate::{
+    formats::{Abbreviated, FullName, Lowercase, Titlecase, Uppercase, WeekdayNumbering},
+    prelude::*,
+};
+use core::fmt::Write as _;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// A month, numbered from 1 for January to 12 for December
+///
+/// This is the common representation used by [`MonthAsNumber`] and [`AsName`].
+/// The time crate specific modules only need to convert from and to it.
+#[derive(Copy, Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub(crate) struct MonthNumber(pub(crate) u8);
+
+/// A day of the week, as number of days since Monday, i.e., from 0 for Monday to 6 for Sunday
+///
+/// This is the common representation used by [`WeekdayAsNumber`] and [`AsName`].
+/// The time crate specific modules only need to convert from and to it.
+#[derive(Copy, Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub(crate) struct WeekdayNumber(pub(crate) u8);
+
+impl WeekdayNumber {
+    /// Return `None` if the number does not fit into a `u8`
+    fn to_number<N: WeekdayNumbering>(self) -> Option<u8> {
+        ((self.0 + 7 - N::first_day() % 7) % 7).checked_add(N::first_number())
+    }
+
+    fn from_number<N: WeekdayNumbering>(number: u8) -> Option<Self> {
+        let offset = number.checked_sub(N::first_number())?;
+        (offset < 7).then(|| Self((offset + N::first_day() % 7) % 7))
+    }
+}
+
+/// Letter case used when displaying a [`Name`]
+#[derive(Copy, Clone)]
+enum Case {
+    Title,
+    Lower,
+    Upper,
+}
+
+/// Display a month or weekday name in the given case, optionally abbreviated to three letters
+struct Name {
+    name: &'static str,
+    case: Case,
+    abbreviated: bool,
+}
+
+impl Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = if self.abbreviated {
+            &self.name[..3]
+        } else {
+            self.name
+        };
+        for c in name.chars() {
+            let c = match self.case {
+                Case::Title => c,
+                Case::Lower => c.to_ascii_lowercase(),
+                Case::Upper => c.to_ascii_uppercase(),
+            };
+            f.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+/// Find the index of `value` in `names`, comparing case-insensitively against the full names and their abbreviations
+fn parse_name(names: &[&str], value: &str) -> Option<usize> {
+    names
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(value) || name[..3].eq_ignore_ascii_case(value))
+}
+
+/// Visitor for a number in an inclusive range
+struct NumberVisitor {
+    kind: &'static str,
+    min: u8,
+    max: u8,
+}
+
+impl<'de> Visitor<'de> for NumberVisitor {
+    type Value = u8;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "a {} number between {} and {}",
+            self.kind, self.min, self.max
+        )
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        u8::try_from(value)
+            .ok()
+            .filter(|value| (self.min..=self.max).contains(value))
+            .ok_or_else(|| DeError::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        u64::try_from(value)
+            .map_err(|_| DeError::invalid_value(Unexpected::Signed(value), &self))
+            .and_then(|value| self.visit_u64(value))
+    }
+}
+
+/// Visitor for a month or weekday name, either in full or abbreviated, in any case
+struct NameVisitor {
+    kind: &'static str,
+    names: &'static [&'static str],
+}
+
+impl<'de> Visitor<'de> for NameVisitor {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.names[0];
+        write!(
+            formatter,
+            "a {} name like \"{}\" or \"{}\"",
+            self.kind,
+            name,
+            &name[..3]
+        )
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        parse_name(self.names, value)
+            .ok_or_else(|| DeError::invalid_value(Unexpected::Str(value), &self))
+    }
+}
+
+impl SerializeAs<MonthNumber> for MonthAsNumber {
+    fn serialize_as<S>(source: &MonthNumber, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(source.0)
+    }
+}
+
+impl<'de> DeserializeAs<'de, MonthNumber> for MonthAsNumber {
+    fn deserialize_as<D>(deserializer: D) -> Result<MonthNumber, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_u8(NumberVisitor {
+                kind: "month",
+                min: 1,
+                max: 12,
+            })
+            .map(MonthNumber)
+    }
+}
+
+impl<NUMBERING> SerializeAs<WeekdayNumber> for WeekdayAsNumber<NUMBERING>
+where
+    NUMBERING: WeekdayNumbering,
+{
+    fn serialize_as<S>(source: &WeekdayNumber, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let number = source
+            .to_number::<NUMBERING>()
+            .ok_or_else(|| SerError::custom("weekday number must fit into a u8"))?;
+        serializer.serialize_u8(number)
+    }
+}
+
+impl<'de, NUMBERING> DeserializeAs<'de, WeekdayNumber> for WeekdayAsNumber<NUMBERING>
+where
+    NUMBERING: WeekdayNumbering,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<WeekdayNumber, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let first = NUMBERING::first_number();
+        let number = deserializer.deserialize_u8(NumberVisitor {
+            kind: "weekday",
+            min: first,
+            max: first.saturating_add(6),
+        })?;
+        Ok(WeekdayNumber::from_number::<NUMBERING>(number)
+            .expect("NumberVisitor only returns numbers in the valid range"))
+    }
+}
+
+macro_rules! as_name_impls {
+    ($($case:ty => $case_value:expr, $length:ty => $abbreviated:literal;)*) => {
+        $(
+            impl SerializeAs<MonthNumber> for AsName<$case, $length> {
+                fn serialize_as<S>(source: &MonthNumber, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let name = MONTH_NAMES
+                        .get(usize::from(source.0).wrapping_sub(1))
+                        .ok_or_else(|| SerError::custom("month number must be between 1 and 12"))?;
+                    serializer.collect_str(&Name {
+                        name,
+                        case: $case_value,
+                        abbreviated: $abbreviated,
+                    })
+                }
+            }
+
+            impl<'de> DeserializeAs<'de, MonthNumber> for AsName<$case, $length> {
+                fn deserialize_as<D>(deserializer: D) -> Result<MonthNumber, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let idx = deserializer.deserialize_str(NameVisitor {
+                        kind: "month",
+                        names: &MONTH_NAMES,
+                    })?;
+                    Ok(MonthNumber(idx as u8 + 1))
+                }
+            }
+
+            impl SerializeAs<WeekdayNumber> for AsName<$case, $length> {
+                fn serialize_as<S>(source: &WeekdayNumber, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let name = WEEKDAY_NAMES
+                        .get(usize::from(source.0))
+                        .ok_or_else(|| SerError::custom("weekday number must be between 0 and 6"))?;
+                    serializer.collect_str(&Name {
+                        name,
+                        case: $case_value,
+                        abbreviated: $abbreviated,
+                    })
+                }
+            }
+
+            impl<'de> DeserializeAs<'de, WeekdayNumber> for AsName<$case, $length> {
+                fn deserialize_as<D>(deserializer: D) -> Result<WeekdayNumber, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let idx = deserializer.deserialize_str(NameVisitor {
+                        kind: "weekday",
+                        names: &WEEKDAY_NAMES,
+                    })?;
+                    Ok(WeekdayNumber(idx as u8))
+                }
+            }
+        )*
+    };
+}
+as_name_impls!(
+    Titlecase => Case::Title, FullName => false;
+    Titlecase => Case::Title, Abbreviated => true;
+    Lowercase => Case::Lower, FullName => false;
+    Lowercase => Case::Lower, Abbreviated => true;
+    Uppercase => Case::Upper, FullName => false;
+    Uppercase => Case::Upper, Abbreviated => true;
+);
+
+/// Implement [`MonthAsNumber`], [`WeekdayAsNumber`], and [`AsName`] for the month and weekday types of a time crate
+///
+/// The types are converted from and to [`MonthNumber`] and [`WeekdayNumber`] with the given functions.
+#[cfg(any(feature = "chrono_0_4", feature = "time_0_3"))]
+macro_rules! calendar_impls {
+    (
+        $month:ty: $month_to_number:ident, $number_to_month:ident;
+        $weekday:ty: $weekday_to_number:ident, $number_to_weekday:ident;
+    ) => {
+        $crate::utils::calendar::calendar_impls!(
+            @impl $month as $crate::utils::calendar::MonthNumber;
+            $month_to_number, $number_to_month =>
+            {$crate::MonthAsNumber =>}
+        );
+        $crate::utils::calendar::calendar_impls!(
+            @impl $weekday as $crate::utils::calendar::WeekdayNumber;
+            $weekday_to_number, $number_to_weekday =>
+            {$crate::WeekdayAsNumber<NUMBERING> => NUMBERING: $crate::formats::WeekdayNumbering}
+        );
+        $crate::utils::calendar::calendar_impls!(@names $month as $crate::utils::calendar::MonthNumber; $month_to_number, $number_to_month);
+        $crate::utils::calendar::calendar_impls!(@names $weekday as $crate::utils::calendar::WeekdayNumber; $weekday_to_number, $number_to_weekday);
+    };
+    (@names $ty:ty as $internal:ty; $to_number:ident, $from_number:ident) => {
+        $crate::utils::calendar::calendar_impls!(
+            @impl $ty as $internal; $to_number, $from_number =>
+            {$crate::AsName<$crate::formats::Titlecase, $crate::formats::FullName> =>}
+            {$crate::AsName<$crate::formats::Titlecase, $crate::formats::Abbreviated> =>}
+            {$crate::AsName<$crate::formats::Lowercase, $crate::formats::FullName> =>}
+            {$crate::AsName<$crate::formats::Lowercase, $crate::formats::Abbreviated> =>}
+            {$crate::AsName<$crate::formats::Uppercase, $crate::formats::FullName> =>}
+            {$crate::AsName<$crate::formats::Uppercase, $crate::formats::Abbreviated> =>}
+        );
+    };
+    (
+        @impl $ty:ty as $internal:ty; $to_number:ident, $from_number:ident =>
+        $({
+            $main:ty =>
+            $($tbound:ident: $bound:path)*
+        })*
+    ) => {
+        $(
+            impl<$($tbound,)*> $crate::SerializeAs<$ty> for $main
+            where
+                $($tbound: $bound,)*
+            {
+                fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: $crate::serde::Serializer,
+                {
+                    let value: $internal = $to_number(source);
+                    <$main as $crate::SerializeAs<$internal>>::serialize_as(&value, serializer)
+                }
+            }
+
+            impl<'de, $($tbound,)*> $crate::DeserializeAs<'de, $ty> for $main
+            where
+                $($tbound: $bound,)*
+            {
+                fn deserialize_as<D>(deserializer: D) -> Result<$ty, D::Error>
+                where
+                    D: $crate::serde::Deserializer<'de>,
+                {
+                    let value: $internal =
+                        <$main as $crate::DeserializeAs<'de, $internal>>::deserialize_as(deserializer)?;
+                    Ok($from_number(value))
+                }
+            }
+        )*
+    };
+}
+#[cfg(any(feature = "chrono_0_4", feature = "time_0_3"))]
+pub(crate) use calendar_impls;
+
+#[test]
+fn test_weekday_numbering() {
+    use crate::formats::{MondayOne, MondayZero, SundayZero};
+
+    let monday = WeekdayNumber(0);
+    let sunday = WeekdayNumber(6);
+    assert_eq!(Some(0), monday.to_number::<MondayZero>());
+    assert_eq!(Some(6), sunday.to_number::<MondayZero>());
+    assert_eq!(Some(1), monday.to_number::<SundayZero>());
+    assert_eq!(Some(0), sunday.to_number::<SundayZero>());
+    assert_eq!(Some(1), monday.to_number::<MondayOne>());
+    assert_eq!(Some(7), sunday.to_number::<MondayOne>());
+
+    for day in 0..7 {
+        let weekday = WeekdayNumber(day);
+        assert_eq!(
+            Some(weekday),
+            weekday
+                .to_number::<MondayZero>()
+                .and_then(WeekdayNumber::from_number::<MondayZero>)
+        );
+        assert_eq!(
+            Some(weekday),
+            weekday
+                .to_number::<SundayZero>()
+                .and_then(WeekdayNumber::from_number::<SundayZero>)
+        );
+        assert_eq!(
+            Some(weekday),
+            weekday
+                .to_number::<MondayOne>()
+                .and_then(WeekdayNumber::from_number::<MondayOne>)
+        );
+    }
+    assert_eq!(None, WeekdayNumber::from_number::<MondayZero>(7));
+    assert_eq!(None, WeekdayNumber::from_number::<MondayOne>(0));
+
+    // Large numbers must not overflow
+    struct Large;
+    impl WeekdayNumbering for Large {
+        fn first_day() -> u8 {
+            255
+        }
+        fn first_number() -> u8 {
+            250
+        }
+    }
+    // The first day is `255 % 7`, i.e., Thursday
+    assert_eq!(Some(250), WeekdayNumber(3).to_number::<Large>());
+    assert_eq!(Some(255), WeekdayNumber(1).to_number::<Large>());
+    assert_eq!(None, WeekdayNumber(2).to_number::<Large>());
+    assert_eq!(
+        Some(WeekdayNumber(1)),
+        WeekdayNumber::from_number::<Large>(255)
+    );
+}
+
+#[test]
+fn test_parse_name() {
+    assert_eq!(Some(0), parse_name(&MONTH_NAMES, "January"));
+    assert_eq!(Some(0), parse_name(&MONTH_NAMES, "jan"));
+    assert_eq!(Some(8), parse_name(&MONTH_NAMES, "SEPTEMBER"));
+    assert_eq!(Some(4), parse_name(&MONTH_NAMES, "May"));
+    assert_eq!(Some(6), parse_name(&WEEKDAY_NAMES, "sun"));
+    assert_eq!(None, parse_name(&MONTH_NAMES, "Janu"));
+    assert_eq!(None, parse_name(&WEEKDAY_NAMES, "Mo"));
+    assert_eq!(None, parse_name(&WEEKDAY_NAMES, ""));
+}
@@ -1,8 +1,9 @@
 //! Internal Helper types
 
 use crate::{
-    formats::{Flexible, Format, Strict, Strictness},
+    formats::{self, Flexible, Format, Saturating, Strict, Strictness},
     prelude::*,
+    utils::rfc3339::{parse_rfc3339, UtcDateTime},
 };
 
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -13,12 +14,10 @@ pub(crate) enum Sign {
 }
 
 impl Sign {
-    #[allow(dead_code)]
     pub(crate) fn is_positive(&self) -> bool {
         *self == Sign::Positive
     }
 
-    #[allow(dead_code)]
     pub(crate) fn is_negative(&self) -> bool {
         *self == Sign::Negative
     }
@@ -48,21 +47,75 @@ impl DurationSigned {
         }
     }
 
-    #[cfg(any(feature = "chrono_0_4", feature = "time_0_3"))]
+    #[cfg(any(feature = "chrono_0_4", feature = "jiff_0_2", feature = "time_0_3"))]
     pub(crate) fn with_duration(sign: Sign, duration: Duration) -> Self {
         Self { sign, duration }
     }
 
+    /// The largest duration with the given sign
+    pub(crate) fn saturated(sign: Sign) -> Self {
+        Self::new(sign, u64::MAX, utils::NANOS_PER_SEC - 1)
+    }
+
+    /// Convert the value using `convert`, but clamp it to the closest value `convert` accepts.
+    ///
+    /// `convert` must accept a zero duration and all durations between zero and any other accepted duration of the same sign.
+    /// The closest accepted duration is found using a binary search over the nanoseconds.
+    #[cfg(any(
+        feature = "std",
+        feature = "chrono_0_4",
+        feature = "jiff_0_2",
+        feature = "time_0_3"
+    ))]
+    pub(crate) fn saturating_convert<T>(self, convert: impl Fn(Self) -> Option<T>) -> T {
+        if let Some(value) = convert(self) {
+            return value;
+        }
+
+        let from_nanos = |nanos: u128| {
+            Self::new(
+                self.sign,
+                (nanos / u128::from(utils::NANOS_PER_SEC)) as u64,
+                (nanos % u128::from(utils::NANOS_PER_SEC)) as u32,
+            )
+        };
+        // Invariant: `low` is accepted while `high` is not
+        let (mut low, mut high) = (0, self.duration.as_nanos());
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if convert(from_nanos(mid)).is_some() {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        convert(from_nanos(low)).expect("A zero duration must always be accepted")
+    }
+
     #[cfg(feature = "std")]
-    pub(crate) fn to_system_time<'de, D>(self) -> Result<SystemTime, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
+    fn checked_system_time(self) -> Option<SystemTime> {
         match self.sign {
             Sign::Positive => SystemTime::UNIX_EPOCH.checked_add(self.duration),
             Sign::Negative => SystemTime::UNIX_EPOCH.checked_sub(self.duration),
         }
-        .ok_or_else(|| DeError::custom("timestamp is outside the range for std::time::SystemTime"))
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn to_system_time<'de, D>(self) -> Result<SystemTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.checked_system_time().ok_or_else(|| {
+            DeError::custom("timestamp is outside the range for std::time::SystemTime")
+        })
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn to_system_time_saturating<'de, D>(self) -> Result<SystemTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(self.saturating_convert(Self::checked_system_time))
     }
 
     #[cfg(feature = "std")]
@@ -75,6 +128,17 @@ impl DurationSigned {
             Sign::Negative => Err(DeError::custom("std::time::Duration cannot be negative")),
         }
     }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn to_std_duration_saturating<'de, D>(self) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match self.sign {
+            Sign::Positive => Ok(self.duration),
+            Sign::Negative => Ok(Duration::ZERO),
+        }
+    }
 }
 
 impl From<&Duration> for DurationSigned {
@@ -276,6 +340,10 @@ macro_rules! duration_impls {
                 D: Deserializer<'de>,
             {
                 let dur = $inner::<FORMAT, STRICTNESS>::deserialize_as(deserializer)?;
+                // A saturated value stands for any larger value, so it must stay saturated
+                if dur.duration == Duration::MAX {
+                    return Ok(dur);
+                }
                 Ok(dur / $factor)
             }
         }
@@ -465,6 +533,195 @@ where
     }
 }
 
+/// Like [`DurationVisitorFlexible`], but clamps values which do not fit into a [`DurationSigned`]
+struct DurationVisitorSaturating;
+impl<'de> Visitor<'de> for DurationVisitorSaturating {
+    type Value = DurationSigned;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        DurationVisitorFlexible.expecting(formatter)
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        DurationVisitorFlexible.visit_i64(value)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        DurationVisitorFlexible.visit_u64(value)
+    }
+
+    fn visit_f64<E>(self, secs: f64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        // `u64::MAX as f64` rounds up to 2^64, which is the first value without a representation
+        if !secs.is_nan() && secs.abs() >= u64::MAX as f64 {
+            let sign = if secs < 0. {
+                Sign::Negative
+            } else {
+                Sign::Positive
+            };
+            return Ok(DurationSigned::saturated(sign));
+        }
+        DurationVisitorFlexible.visit_f64(secs)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        match parse_float_into_time_parts(value) {
+            Ok((sign, seconds, subseconds)) => Ok(DurationSigned::new(sign, seconds, subseconds)),
+            Err(ParseFloatError::InvalidValue) => {
+                // Only the number of seconds can be too large, the subseconds are already limited to 9 digits
+                let (sign, digits) = match value.as_bytes().first() {
+                    Some(b'-') => (Sign::Negative, &value[1..]),
+                    Some(b'+') => (Sign::Positive, &value[1..]),
+                    _ => (Sign::Positive, value),
+                };
+                let seconds = digits
+                    .split_once('.')
+                    .map_or(digits, |(seconds, _)| seconds);
+                if !seconds.is_empty() && seconds.bytes().all(|b| b.is_ascii_digit()) {
+                    Ok(DurationSigned::saturated(sign))
+                } else {
+                    Err(DeError::invalid_value(Unexpected::Str(value), &self))
+                }
+            }
+            Err(ParseFloatError::Custom(msg)) => Err(DeError::custom(msg)),
+        }
+    }
+}
+
+impl<'de, FORMAT> DeserializeAs<'de, DurationSigned> for DurationSeconds<FORMAT, Saturating>
+where
+    FORMAT: Format,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<DurationSigned, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DurationVisitorSaturating)
+    }
+}
+
+impl<'de, FORMAT> DeserializeAs<'de, DurationSigned> for DurationSecondsWithFrac<FORMAT, Saturating>
+where
+    FORMAT: Format,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<DurationSigned, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DurationVisitorSaturating)
+    }
+}
+
+impl SerializeAs<DurationSigned> for TimestampAny<i64> {
+    fn serialize_as<S>(source: &DurationSigned, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        DurationSeconds::<i64, Strict>::serialize_as(source, serializer)
+    }
+}
+
+impl SerializeAs<DurationSigned> for TimestampAny<f64> {
+    fn serialize_as<S>(source: &DurationSigned, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        DurationSecondsWithFrac::<f64, Strict>::serialize_as(source, serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SerializeAs<DurationSigned> for TimestampAny<String> {
+    fn serialize_as<S>(source: &DurationSigned, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        DurationSecondsWithFrac::<String, Strict>::serialize_as(source, serializer)
+    }
+}
+
+impl SerializeAs<DurationSigned> for TimestampAny<formats::Rfc3339> {
+    fn serialize_as<S>(source: &DurationSigned, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match UtcDateTime::from_duration_signed(source) {
+            Some(datetime) => serializer.collect_str(&datetime),
+            None => Err(SerError::custom(
+                "timestamp is outside the range representable in RFC 3339",
+            )),
+        }
+    }
+}
+
+/// Accepts all formats supported by [`TimestampAny`]
+struct TimestampVisitorAny;
+impl<'de> Visitor<'de> for TimestampVisitorAny {
+    type Value = DurationSigned;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(
+            "an integer, a float, a string containing a number, or an RFC 3339 timestamp",
+        )
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        DurationVisitorFlexible.visit_i64(value)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        DurationVisitorFlexible.visit_u64(value)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        DurationVisitorFlexible.visit_f64(value)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        match parse_float_into_time_parts(value) {
+            Ok((sign, seconds, subseconds)) => Ok(DurationSigned::new(sign, seconds, subseconds)),
+            Err(ParseFloatError::InvalidValue) => parse_rfc3339(value)
+                .ok_or_else(|| DeError::invalid_value(Unexpected::Str(value), &self)),
+            Err(ParseFloatError::Custom(msg)) => Err(DeError::custom(msg)),
+        }
+    }
+}
+
+impl<'de, FORMAT> DeserializeAs<'de, DurationSigned> for TimestampAny<FORMAT>
+where
+    FORMAT: Format,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<DurationSigned, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(TimestampVisitorAny)
+    }
+}
+
 #[cfg_attr(test, derive(Debug, PartialEq))]
 pub(crate) enum ParseFloatError {
     InvalidValue,
@@ -561,3 +818,38 @@ fn test_parse_float_into_time_parts() {
         parse_float_into_time_parts("0.123456789")
     );
 }
+
+#[cfg(any(
+    feature = "std",
+    feature = "chrono_0_4",
+    feature = "jiff_0_2",
+    feature = "time_0_3"
+))]
+#[test]
+fn test_saturating_convert() {
+    // Accept everything in the range of -5s to 10.5s
+    let convert = |dur: DurationSigned| {
+        let limit = match dur.sign {
+            Sign::Positive => Duration::new(10, 500_000_000),
+            Sign::Negative => Duration::new(5, 0),
+        };
+        (dur.duration <= limit).then_some((dur.sign, dur.duration))
+    };
+
+    assert_eq!(
+        (Sign::Positive, Duration::new(3, 0)),
+        DurationSigned::new(Sign::Positive, 3, 0).saturating_convert(convert)
+    );
+    assert_eq!(
+        (Sign::Positive, Duration::new(10, 500_000_000)),
+        DurationSigned::new(Sign::Positive, 11, 0).saturating_convert(convert)
+    );
+    assert_eq!(
+        (Sign::Positive, Duration::new(10, 500_000_000)),
+        DurationSigned::saturated(Sign::Positive).saturating_convert(convert)
+    );
+    assert_eq!(
+        (Sign::Negative, Duration::new(5, 0)),
+        DurationSigned::new(Sign::Negative, 5, 1).saturating_convert(convert)
+    );
+}
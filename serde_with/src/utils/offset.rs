@@ -0,0 +1,288 @@
+//! Internal Helper types for UTC offsets
+
+use crate::{
+    formats::{Flexible, Format, Strict, Strictness},
+    prelude::*,
+};
+
+/// A UTC offset expressed as the number of seconds east of UTC
+///
+/// This is the common representation used by [`OffsetAsString`] and [`OffsetSeconds`].
+/// The time crate specific modules only need to convert from and to it.
+#[derive(Copy, Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub(crate) struct UtcOffsetSeconds(pub(crate) i32);
+
+impl Display for UtcOffsetSeconds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 == 0 {
+            return f.write_str("Z");
+        }
+
+        let sign = if self.0.is_negative() { '-' } else { '+' };
+        let secs = self.0.unsigned_abs();
+        let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+        write!(f, "{sign}{hours:02}:{minutes:02}")?;
+        if seconds != 0 {
+            write!(f, ":{seconds:02}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a UTC offset string into the number of seconds east of UTC
+///
+/// The strict syntax accepts `Z`, `±HH:MM`, and `±HH:MM:SS`.
+/// The flexible syntax additionally accepts a lowercase `z` and the forms without separators, i.e., `±HH`, `±HHMM`, and `±HHMMSS`.
+pub(crate) fn parse_utc_offset(value: &str, flexible: bool) -> Option<i32> {
+    if value == "Z" || (flexible && value == "z") {
+        return Some(0);
+    }
+
+    let (sign, rest) = match value.as_bytes().first()? {
+        b'+' => (Sign::Positive, &value[1..]),
+        b'-' => (Sign::Negative, &value[1..]),
+        _ => return None,
+    };
+    let bytes = rest.as_bytes();
+    let two_digits = |idx: usize| -> Option<i32> {
+        match bytes.get(idx..idx + 2)? {
+            [tens @ b'0'..=b'9', ones @ b'0'..=b'9'] => {
+                Some(i32::from(tens - b'0') * 10 + i32::from(ones - b'0'))
+            }
+            _ => None,
+        }
+    };
+
+    let (hours, minutes, seconds) = match bytes.len() {
+        5 if bytes[2] == b':' => (two_digits(0)?, two_digits(3)?, 0),
+        8 if bytes[2] == b':' && bytes[5] == b':' => {
+            (two_digits(0)?, two_digits(3)?, two_digits(6)?)
+        }
+        2 if flexible => (two_digits(0)?, 0, 0),
+        4 if flexible => (two_digits(0)?, two_digits(2)?, 0),
+        6 if flexible => (two_digits(0)?, two_digits(2)?, two_digits(4)?),
+        _ => return None,
+    };
+    if minutes >= 60 || seconds >= 60 {
+        return None;
+    }
+    Some(sign.apply(hours * 3600 + minutes * 60 + seconds))
+}
+
+struct OffsetStringVisitor {
+    flexible: bool,
+}
+
+impl<'de> Visitor<'de> for OffsetStringVisitor {
+    type Value = UtcOffsetSeconds;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.flexible {
+            formatter.write_str("a UTC offset like \"Z\", \"+05:30\", \"+0530\", or \"+05\"")
+        } else {
+            formatter.write_str("a UTC offset like \"Z\" or \"+05:30\"")
+        }
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        parse_utc_offset(value, self.flexible)
+            .map(UtcOffsetSeconds)
+            .ok_or_else(|| DeError::invalid_value(Unexpected::Str(value), &self))
+    }
+}
+
+impl<STRICTNESS> SerializeAs<UtcOffsetSeconds> for OffsetAsString<STRICTNESS>
+where
+    STRICTNESS: Strictness,
+{
+    fn serialize_as<S>(source: &UtcOffsetSeconds, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(source)
+    }
+}
+
+impl<'de> DeserializeAs<'de, UtcOffsetSeconds> for OffsetAsString<Strict> {
+    fn deserialize_as<D>(deserializer: D) -> Result<UtcOffsetSeconds, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(OffsetStringVisitor { flexible: false })
+    }
+}
+
+impl<'de> DeserializeAs<'de, UtcOffsetSeconds> for OffsetAsString<Flexible> {
+    fn deserialize_as<D>(deserializer: D) -> Result<UtcOffsetSeconds, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(OffsetStringVisitor { flexible: true })
+    }
+}
+
+impl<STRICTNESS> SerializeAs<UtcOffsetSeconds> for OffsetSeconds<i64, STRICTNESS>
+where
+    STRICTNESS: Strictness,
+{
+    fn serialize_as<S>(source: &UtcOffsetSeconds, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(i64::from(source.0))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<STRICTNESS> SerializeAs<UtcOffsetSeconds> for OffsetSeconds<String, STRICTNESS>
+where
+    STRICTNESS: Strictness,
+{
+    fn serialize_as<S>(source: &UtcOffsetSeconds, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&source.0)
+    }
+}
+
+/// Visitor for the number of seconds, either as integer or as string containing an integer
+struct OffsetSecondsVisitor {
+    allow_int: bool,
+    allow_str: bool,
+}
+
+impl<'de> Visitor<'de> for OffsetSecondsVisitor {
+    type Value = UtcOffsetSeconds;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.allow_int, self.allow_str) {
+            (true, false) => formatter.write_str("an integer number of seconds"),
+            (false, true) => {
+                formatter.write_str("a string containing an integer number of seconds")
+            }
+            _ => formatter
+                .write_str("an integer or a string containing an integer number of seconds"),
+        }
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        if !self.allow_int {
+            return Err(DeError::invalid_type(Unexpected::Signed(value), &self));
+        }
+        i32::try_from(value)
+            .map(UtcOffsetSeconds)
+            .map_err(|_| DeError::invalid_value(Unexpected::Signed(value), &self))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        if !self.allow_int {
+            return Err(DeError::invalid_type(Unexpected::Unsigned(value), &self));
+        }
+        i32::try_from(value)
+            .map(UtcOffsetSeconds)
+            .map_err(|_| DeError::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        if !self.allow_str {
+            return Err(DeError::invalid_type(Unexpected::Str(value), &self));
+        }
+        value
+            .parse()
+            .map(UtcOffsetSeconds)
+            .map_err(|_| DeError::invalid_value(Unexpected::Str(value), &self))
+    }
+}
+
+impl<'de> DeserializeAs<'de, UtcOffsetSeconds> for OffsetSeconds<i64, Strict> {
+    fn deserialize_as<D>(deserializer: D) -> Result<UtcOffsetSeconds, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_i64(OffsetSecondsVisitor {
+            allow_int: true,
+            allow_str: false,
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> DeserializeAs<'de, UtcOffsetSeconds> for OffsetSeconds<String, Strict> {
+    fn deserialize_as<D>(deserializer: D) -> Result<UtcOffsetSeconds, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(OffsetSecondsVisitor {
+            allow_int: false,
+            allow_str: true,
+        })
+    }
+}
+
+impl<'de, FORMAT> DeserializeAs<'de, UtcOffsetSeconds> for OffsetSeconds<FORMAT, Flexible>
+where
+    FORMAT: Format,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<UtcOffsetSeconds, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(OffsetSecondsVisitor {
+            allow_int: true,
+            allow_str: true,
+        })
+    }
+}
+
+#[test]
+fn test_parse_utc_offset() {
+    // Strict syntax
+    assert_eq!(Some(0), parse_utc_offset("Z", false));
+    assert_eq!(Some(0), parse_utc_offset("+00:00", false));
+    assert_eq!(Some(0), parse_utc_offset("-00:00", false));
+    assert_eq!(Some(19800), parse_utc_offset("+05:30", false));
+    assert_eq!(Some(-34200), parse_utc_offset("-09:30", false));
+    assert_eq!(Some(19815), parse_utc_offset("+05:30:15", false));
+    assert_eq!(None, parse_utc_offset("z", false));
+    assert_eq!(None, parse_utc_offset("+0530", false));
+    assert_eq!(None, parse_utc_offset("+05", false));
+    assert_eq!(None, parse_utc_offset("05:30", false));
+    assert_eq!(None, parse_utc_offset("+05:60", false));
+    assert_eq!(None, parse_utc_offset("+5:30", false));
+    assert_eq!(None, parse_utc_offset("", false));
+
+    // Flexible syntax
+    assert_eq!(Some(0), parse_utc_offset("z", true));
+    assert_eq!(Some(19800), parse_utc_offset("+05:30", true));
+    assert_eq!(Some(19800), parse_utc_offset("+0530", true));
+    assert_eq!(Some(18000), parse_utc_offset("+05", true));
+    assert_eq!(Some(-18015), parse_utc_offset("-050015", true));
+    assert_eq!(None, parse_utc_offset("+5", true));
+    assert_eq!(None, parse_utc_offset("+053", true));
+    assert_eq!(None, parse_utc_offset("+05:3a", true));
+    assert_eq!(None, parse_utc_offset("+０５", true));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_display_utc_offset() {
+    assert_eq!("Z", UtcOffsetSeconds(0).to_string());
+    assert_eq!("+05:30", UtcOffsetSeconds(19800).to_string());
+    assert_eq!("-09:30", UtcOffsetSeconds(-34200).to_string());
+    assert_eq!("+05:30:15", UtcOffsetSeconds(19815).to_string());
+    assert_eq!("-00:00:01", UtcOffsetSeconds(-1).to_string());
+}
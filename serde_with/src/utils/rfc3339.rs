@@ -0,0 +1,277 @@
+//! Internal helpers to convert between [`DurationSigned`] timestamps and RFC 3339 strings
+//!
+//! The conversions only depend on `core` such that they are available for [`SystemTime`](std::time::SystemTime) and all time crates alike.
+//! The calendar calculations follow the algorithms from <https://howardhinnant.github.io/date_algorithms.html>.
+
+use crate::{prelude::*, utils::offset::parse_utc_offset};
+
+const SECS_PER_DAY: i128 = 86_400;
+
+/// Broken down UTC date and time of a timestamp, which is displayed in the RFC 3339 format
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub(crate) struct UtcDateTime {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanosecond: u32,
+}
+
+impl UtcDateTime {
+    /// Break down the timestamp, which is relative to the UNIX epoch.
+    ///
+    /// Returns `None` if the year is not in the range `0..=9999`, which is all RFC 3339 can represent.
+    pub(crate) fn from_duration_signed(dur: &DurationSigned) -> Option<Self> {
+        let mut secs = i128::from(dur.duration.as_secs());
+        let mut nanosecond = dur.duration.subsec_nanos();
+        if dur.sign.is_negative() {
+            secs = -secs;
+            if nanosecond != 0 {
+                secs -= 1;
+                nanosecond = utils::NANOS_PER_SEC - nanosecond;
+            }
+        }
+
+        let days = i64::try_from(secs.div_euclid(SECS_PER_DAY)).ok()?;
+        let secs_of_day = secs.rem_euclid(SECS_PER_DAY) as u32;
+        let (year, month, day) = civil_from_days(days);
+        if !(0..=9999).contains(&year) {
+            return None;
+        }
+
+        Some(Self {
+            year,
+            month,
+            day,
+            hour: secs_of_day / 3600,
+            minute: secs_of_day / 60 % 60,
+            second: secs_of_day % 60,
+            nanosecond,
+        })
+    }
+}
+
+impl Display for UtcDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        // Use the shortest of milli-, micro-, or nanosecond precision which represents the value exactly
+        let nanos = self.nanosecond;
+        if nanos != 0 {
+            if nanos % 1_000_000 == 0 {
+                write!(f, ".{:03}", nanos / 1_000_000)?;
+            } else if nanos % 1_000 == 0 {
+                write!(f, ".{:06}", nanos / 1_000)?;
+            } else {
+                write!(f, ".{nanos:09}")?;
+            }
+        }
+        f.write_str("Z")
+    }
+}
+
+/// Parse an RFC 3339 timestamp into the [`DurationSigned`] since the UNIX epoch.
+///
+/// Besides the strict RFC 3339 syntax, some common ISO 8601 variations are accepted.
+/// The date and time can be separated by a space, the fractional seconds can have any length (only the first 9 digits are used), and the offset can omit the colon or the minutes (`+0530`, `+05`).
+pub(crate) fn parse_rfc3339(value: &str) -> Option<DurationSigned> {
+    let bytes = value.as_bytes();
+    if bytes.len() < 20 {
+        return None;
+    }
+
+    let number = |range: core::ops::Range<usize>| -> Option<u32> {
+        bytes[range].iter().try_fold(0, |acc, &b| {
+            b.is_ascii_digit().then(|| acc * 10 + u32::from(b - b'0'))
+        })
+    };
+    let year = number(0..4)?;
+    let month = number(5..7)?;
+    let day = number(8..10)?;
+    let hour = number(11..13)?;
+    let minute = number(14..16)?;
+    let second = number(17..19)?;
+    if bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || !matches!(bytes[10], b'T' | b't' | b' ')
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return None;
+    }
+    if !(1..=12).contains(&month)
+        || day == 0
+        || day > days_in_month(year, month)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return None;
+    }
+
+    let mut rest = &value[19..];
+    let mut nanosecond = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return None;
+        }
+        nanosecond = fraction.as_bytes()[..digits]
+            .iter()
+            .chain(core::iter::repeat(&b'0'))
+            .take(9)
+            .fold(0, |acc, &b| acc * 10 + u32::from(b - b'0'));
+        rest = &fraction[digits..];
+    }
+    let offset = parse_utc_offset(rest, true)?;
+
+    let secs = i128::from(days_from_civil(i64::from(year), month, day)) * SECS_PER_DAY
+        + i128::from(hour * 3600 + minute * 60 + second)
+        - i128::from(offset);
+    Some(if secs >= 0 {
+        DurationSigned::new(Sign::Positive, secs as u64, nanosecond)
+    } else if nanosecond == 0 {
+        DurationSigned::new(Sign::Negative, (-secs) as u64, 0)
+    } else {
+        DurationSigned::new(
+            Sign::Negative,
+            (-secs - 1) as u64,
+            utils::NANOS_PER_SEC - nanosecond,
+        )
+    })
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Number of days since 1970-01-01 for the given date of the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_index = i64::from((month + 9) % 12);
+    let day_of_year = (153 * month_index + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date of the proleptic Gregorian calendar for the given number of days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[test]
+fn test_civil_days_roundtrip() {
+    assert_eq!(0, days_from_civil(1970, 1, 1));
+    assert_eq!((1970, 1, 1), civil_from_days(0));
+    assert_eq!(11_016, days_from_civil(2000, 2, 29));
+    assert_eq!((2000, 2, 29), civil_from_days(11_016));
+    assert_eq!((1969, 12, 31), civil_from_days(-1));
+    assert_eq!(-719_528, days_from_civil(0, 1, 1));
+    assert_eq!((9999, 12, 31), civil_from_days(2_932_896));
+    for days in (-800_000..3_000_000).step_by(7) {
+        let (year, month, day) = civil_from_days(days);
+        assert_eq!(days, days_from_civil(year, month, day));
+    }
+}
+
+#[test]
+fn test_parse_rfc3339() {
+    let parse = |value| parse_rfc3339(value).map(|dur| (dur.sign, dur.duration));
+
+    assert_eq!(
+        Some((Sign::Positive, Duration::new(0, 0))),
+        parse("1970-01-01T00:00:00Z")
+    );
+    assert_eq!(
+        Some((Sign::Positive, Duration::new(1_478_563_200, 123_000_000))),
+        parse("2016-11-08T00:00:00.123Z")
+    );
+    assert_eq!(
+        Some((Sign::Positive, Duration::new(1_478_563_200, 123_000_000))),
+        parse("2016-11-08 05:30:00.123+05:30")
+    );
+    assert_eq!(
+        Some((Sign::Positive, Duration::new(1_478_563_200, 0))),
+        parse("2016-11-07t19:00:00-0500")
+    );
+    assert_eq!(
+        Some((Sign::Negative, Duration::new(0, 500_000_000))),
+        parse("1969-12-31T23:59:59.5Z")
+    );
+    assert_eq!(
+        Some((Sign::Negative, Duration::new(62_167_219_200, 0))),
+        parse("0000-01-01T00:00:00Z")
+    );
+    assert_eq!(
+        Some((Sign::Positive, Duration::new(0, 123_456_789))),
+        parse("1970-01-01T00:00:00.1234567891Z")
+    );
+
+    // Invalid values
+    assert_eq!(None, parse("2016-11-08T00:00:00"));
+    assert_eq!(None, parse("2016-11-08T00:00:00.Z"));
+    assert_eq!(None, parse("2016-02-30T00:00:00Z"));
+    assert_eq!(None, parse("2015-02-29T00:00:00Z"));
+    assert_eq!(None, parse("2016-11-08T24:00:00Z"));
+    assert_eq!(None, parse("2016-11-08X00:00:00Z"));
+    assert_eq!(None, parse("2016-11-08T00:00:00ZZ"));
+    assert_eq!(None, parse("1478563200"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_display_rfc3339() {
+    let display = |sign, secs, nanos| {
+        UtcDateTime::from_duration_signed(&DurationSigned::new(sign, secs, nanos))
+            .map(|datetime| datetime.to_string())
+    };
+
+    assert_eq!(
+        Some("1970-01-01T00:00:00Z".to_string()),
+        display(Sign::Positive, 0, 0)
+    );
+    assert_eq!(
+        Some("2016-11-08T00:00:00.123Z".to_string()),
+        display(Sign::Positive, 1_478_563_200, 123_000_000)
+    );
+    assert_eq!(
+        Some("2016-11-08T00:00:00.000123Z".to_string()),
+        display(Sign::Positive, 1_478_563_200, 123_000)
+    );
+    assert_eq!(
+        Some("2016-11-08T00:00:00.000000123Z".to_string()),
+        display(Sign::Positive, 1_478_563_200, 123)
+    );
+    assert_eq!(
+        Some("1969-12-31T23:59:59.500Z".to_string()),
+        display(Sign::Negative, 0, 500_000_000)
+    );
+    assert_eq!(None, display(Sign::Positive, u64::MAX, 0));
+    assert_eq!(None, display(Sign::Negative, 62_167_219_201, 0));
+}
@@ -0,0 +1,393 @@
+//! Validators for the [`Validated`] adapter
+//!
+//! A [`Validator`] checks a constraint on an already deserialized value.
+//! This module contains the built-in validators, like [`NonEmpty`], [`InRange`], or [`Finite`], and the combinators [`And`], [`Or`], and [`Not`] to build more complex constraints.
+//! [`MatchesRegex`] is available with the `regex_1` feature.
+//!
+//! [`Validated`]: crate::Validated
+
+#[cfg(feature = "regex_1")]
+use crate::prelude::RwLock;
+use crate::prelude::{fmt, DeError, Expected, PhantomData, Unexpected};
+#[cfg(feature = "alloc")]
+use crate::prelude::{BTreeMap, BTreeSet, BinaryHeap, Box, Cow, LinkedList, String, Vec, VecDeque};
+#[cfg(feature = "std")]
+use crate::prelude::{HashMap, HashSet};
+#[cfg(feature = "hashbrown_0_14")]
+use hashbrown_0_14::{HashMap as HashbrownMap014, HashSet as HashbrownSet014};
+#[cfg(feature = "indexmap_1")]
+use indexmap_1::{IndexMap, IndexSet};
+#[cfg(feature = "indexmap_2")]
+use indexmap_2::{IndexMap as IndexMap2, IndexSet as IndexSet2};
+
+/// Check a constraint on a value
+///
+/// Validators are used by the [`Validated`](crate::Validated) adapter and check the value after it has been deserialized.
+/// If the value is invalid, deserialization fails with an [`invalid_value`](serde::de::Error::invalid_value) error, which combines [`Validator::unexpected`] and [`Validator::expecting`].
+///
+/// # Example
+///
+/// ```rust
+/// # use serde_with::validate::Validator;
+/// # use serde::de::Unexpected;
+/// # use std::fmt;
+/// # #[allow(dead_code)]
+/// struct Even;
+///
+/// impl Validator<u32> for Even {
+///     fn is_valid(value: &u32) -> bool {
+///         value % 2 == 0
+///     }
+///
+///     fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         formatter.write_str("an even number")
+///     }
+///
+///     fn unexpected(value: &u32) -> Unexpected<'_> {
+///         Unexpected::Unsigned(u64::from(*value))
+///     }
+/// }
+/// ```
+pub trait Validator<T: ?Sized> {
+    /// Check whether `value` satisfies the constraint.
+    fn is_valid(value: &T) -> bool;
+
+    /// Describe the constraint, such that it completes the sentence "expected ...".
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Describe an invalid value in the error message.
+    ///
+    /// The default implementation does not describe the value itself.
+    fn unexpected(value: &T) -> Unexpected<'_> {
+        let _ = value;
+        Unexpected::Other("value")
+    }
+}
+
+/// Check `value` against the validator `V` and turn a violation into an error
+pub(crate) fn validate<T, V, E>(value: T) -> Result<T, E>
+where
+    V: Validator<T>,
+    E: DeError,
+{
+    if V::is_valid(&value) {
+        Ok(value)
+    } else {
+        Err(E::invalid_value(
+            V::unexpected(&value),
+            &Expecting::<T, V>(PhantomData, PhantomData),
+        ))
+    }
+}
+
+/// Use [`Validator::expecting`] as [`Expected`]
+struct Expecting<T: ?Sized, V>(PhantomData<V>, PhantomData<T>);
+
+impl<T: ?Sized, V> Expected for Expecting<T, V>
+where
+    V: Validator<T>,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        V::expecting(formatter)
+    }
+}
+
+/// Both validators must accept the value
+pub struct And<A, B>(PhantomData<(A, B)>);
+
+impl<T: ?Sized, A, B> Validator<T> for And<A, B>
+where
+    A: Validator<T>,
+    B: Validator<T>,
+{
+    fn is_valid(value: &T) -> bool {
+        A::is_valid(value) && B::is_valid(value)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        A::expecting(formatter)?;
+        formatter.write_str(" and ")?;
+        B::expecting(formatter)
+    }
+
+    fn unexpected(value: &T) -> Unexpected<'_> {
+        if A::is_valid(value) {
+            B::unexpected(value)
+        } else {
+            A::unexpected(value)
+        }
+    }
+}
+
+/// At least one of the validators must accept the value
+pub struct Or<A, B>(PhantomData<(A, B)>);
+
+impl<T: ?Sized, A, B> Validator<T> for Or<A, B>
+where
+    A: Validator<T>,
+    B: Validator<T>,
+{
+    fn is_valid(value: &T) -> bool {
+        A::is_valid(value) || B::is_valid(value)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        A::expecting(formatter)?;
+        formatter.write_str(" or ")?;
+        B::expecting(formatter)
+    }
+
+    fn unexpected(value: &T) -> Unexpected<'_> {
+        A::unexpected(value)
+    }
+}
+
+/// The validator must reject the value
+pub struct Not<V>(PhantomData<V>);
+
+impl<T: ?Sized, V> Validator<T> for Not<V>
+where
+    V: Validator<T>,
+{
+    fn is_valid(value: &T) -> bool {
+        !V::is_valid(value)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("anything except ")?;
+        V::expecting(formatter)
+    }
+
+    fn unexpected(value: &T) -> Unexpected<'_> {
+        V::unexpected(value)
+    }
+}
+
+/// Strings and collections must not be empty
+///
+/// The validator supports strings, slices, and all sequence, set, and map types.
+pub struct NonEmpty;
+
+macro_rules! non_empty_impl {
+    ($ty:ty $(, $typaram:ident)* $(; $lt:lifetime)? => str) => {
+        non_empty_impl!(@impl $ty $(, $typaram)* $(; $lt)? => |value| Unexpected::Str(value));
+    };
+    ($ty:ty $(, $typaram:ident)* $(; $lt:lifetime)? => $unexpected:literal) => {
+        non_empty_impl!(@impl $ty $(, $typaram)* $(; $lt)? => |_value| Unexpected::Other($unexpected));
+    };
+    (@impl $ty:ty $(, $typaram:ident)* $(; $lt:lifetime)? => |$value:ident| $unexpected:expr) => {
+        impl<$($lt,)? $($typaram),*> Validator<$ty> for NonEmpty {
+            fn is_valid(value: &$ty) -> bool {
+                !value.is_empty()
+            }
+
+            fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a non-empty value")
+            }
+
+            fn unexpected<'v>($value: &'v $ty) -> Unexpected<'v> {
+                $unexpected
+            }
+        }
+    };
+}
+
+non_empty_impl!(str => str);
+non_empty_impl!([T], T => "an empty sequence");
+#[cfg(feature = "alloc")]
+non_empty_impl!(String => str);
+#[cfg(feature = "alloc")]
+non_empty_impl!(Box<str> => str);
+#[cfg(feature = "alloc")]
+non_empty_impl!(Cow<'a, str>; 'a => str);
+#[cfg(feature = "alloc")]
+non_empty_impl!(Box<[T]>, T => "an empty sequence");
+#[cfg(feature = "alloc")]
+non_empty_impl!(Vec<T>, T => "an empty sequence");
+#[cfg(feature = "alloc")]
+non_empty_impl!(VecDeque<T>, T => "an empty sequence");
+#[cfg(feature = "alloc")]
+non_empty_impl!(LinkedList<T>, T => "an empty sequence");
+#[cfg(feature = "alloc")]
+non_empty_impl!(BinaryHeap<T>, T => "an empty sequence");
+#[cfg(feature = "alloc")]
+non_empty_impl!(BTreeSet<T>, T => "an empty set");
+#[cfg(feature = "std")]
+non_empty_impl!(HashSet<T, S>, T, S => "an empty set");
+#[cfg(feature = "hashbrown_0_14")]
+non_empty_impl!(HashbrownSet014<T, S>, T, S => "an empty set");
+#[cfg(feature = "indexmap_1")]
+non_empty_impl!(IndexSet<T, S>, T, S => "an empty set");
+#[cfg(feature = "indexmap_2")]
+non_empty_impl!(IndexSet2<T, S>, T, S => "an empty set");
+#[cfg(feature = "alloc")]
+non_empty_impl!(BTreeMap<K, V>, K, V => "an empty map");
+#[cfg(feature = "std")]
+non_empty_impl!(HashMap<K, V, S>, K, V, S => "an empty map");
+#[cfg(feature = "hashbrown_0_14")]
+non_empty_impl!(HashbrownMap014<K, V, S>, K, V, S => "an empty map");
+#[cfg(feature = "indexmap_1")]
+non_empty_impl!(IndexMap<K, V, S>, K, V, S => "an empty map");
+#[cfg(feature = "indexmap_2")]
+non_empty_impl!(IndexMap2<K, V, S>, K, V, S => "an empty map");
+
+/// Integers must lie within the inclusive range `MIN..=MAX`
+///
+/// The validator supports all primitive integer types.
+/// Negative bounds need to be wrapped in braces, like `InRange<{-10}, 10>`.
+pub struct InRange<const MIN: i128, const MAX: i128>;
+
+macro_rules! in_range_impl {
+    ($($ty:ty => $unexpected:ident($as:ty);)*) => {
+        $(
+            impl<const MIN: i128, const MAX: i128> Validator<$ty> for InRange<MIN, MAX> {
+                fn is_valid(value: &$ty) -> bool {
+                    i128::try_from(*value).map_or(false, |value| MIN <= value && value <= MAX)
+                }
+
+                fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(formatter, "an integer between {MIN} and {MAX}")
+                }
+
+                fn unexpected(value: &$ty) -> Unexpected<'_> {
+                    <$as>::try_from(*value)
+                        .map_or(Unexpected::Other("integer"), Unexpected::$unexpected)
+                }
+            }
+        )*
+    };
+}
+
+in_range_impl! {
+    i8 => Signed(i64);
+    i16 => Signed(i64);
+    i32 => Signed(i64);
+    i64 => Signed(i64);
+    i128 => Signed(i64);
+    isize => Signed(i64);
+    u8 => Unsigned(u64);
+    u16 => Unsigned(u64);
+    u32 => Unsigned(u64);
+    u64 => Unsigned(u64);
+    u128 => Unsigned(u64);
+    usize => Unsigned(u64);
+}
+
+/// Floats must be finite, i.e., neither infinite nor NaN
+pub struct Finite;
+
+macro_rules! finite_impl {
+    ($($ty:ty)*) => {
+        $(
+            impl Validator<$ty> for Finite {
+                fn is_valid(value: &$ty) -> bool {
+                    value.is_finite()
+                }
+
+                fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("a finite number")
+                }
+
+                fn unexpected(value: &$ty) -> Unexpected<'_> {
+                    Unexpected::Float(f64::from(*value))
+                }
+            }
+        )*
+    };
+}
+
+finite_impl!(f32 f64);
+
+/// A regular expression for the [`MatchesRegex`] validator
+///
+/// The pattern uses the syntax of the [`regex`](regex_1) crate.
+/// Like the `pattern` keyword of JSON schema, the pattern is not anchored.
+/// Use `^` and `$` to match the whole string.
+///
+/// ```rust
+/// # use serde_with::validate::RegexPattern;
+/// # #[allow(dead_code)]
+/// struct Slug;
+///
+/// impl RegexPattern for Slug {
+///     const PATTERN: &'static str = "^[a-z0-9]+(-[a-z0-9]+)*$";
+/// }
+/// ```
+#[cfg(feature = "regex_1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex_1")))]
+pub trait RegexPattern {
+    /// The regular expression
+    const PATTERN: &'static str;
+}
+
+/// Strings must match the regular expression of the [`RegexPattern`] `P`
+///
+/// The regular expression is compiled once and then cached for the lifetime of the program.
+/// An invalid pattern is a programming error and causes a panic during the first validation.
+#[cfg(feature = "regex_1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex_1")))]
+pub struct MatchesRegex<P>(PhantomData<P>);
+
+/// Get the compiled regular expression for `pattern`
+///
+/// Each pattern is compiled once and leaked, such that all validations share the regex and its search cache.
+/// Lookups only take a shared read lock.
+#[cfg(feature = "regex_1")]
+fn regex(pattern: &'static str) -> &'static regex_1::Regex {
+    use std::sync::PoisonError;
+
+    type Cache = Vec<(&'static str, &'static regex_1::Regex)>;
+    static CACHE: RwLock<Cache> = RwLock::new(Vec::new());
+
+    let find = |cache: &Cache| {
+        cache
+            .iter()
+            .find(|(cached, _)| *cached == pattern)
+            .map(|&(_, regex)| regex)
+    };
+
+    if let Some(regex) = find(&CACHE.read().unwrap_or_else(PoisonError::into_inner)) {
+        return regex;
+    }
+    let mut cache = CACHE.write().unwrap_or_else(PoisonError::into_inner);
+    // Another thread might have compiled the pattern in the meantime
+    if let Some(regex) = find(&cache) {
+        return regex;
+    }
+    let regex = regex_1::Regex::new(pattern)
+        .unwrap_or_else(|err| panic!("invalid regex pattern `{pattern}`: {err}"));
+    let regex: &'static regex_1::Regex = Box::leak(Box::new(regex));
+    cache.push((pattern, regex));
+    regex
+}
+
+#[cfg(feature = "regex_1")]
+macro_rules! matches_regex_impl {
+    ($ty:ty $(; $lt:lifetime)?) => {
+        impl<$($lt,)? P> Validator<$ty> for MatchesRegex<P>
+        where
+            P: RegexPattern,
+        {
+            fn is_valid(value: &$ty) -> bool {
+                regex(P::PATTERN).is_match(value)
+            }
+
+            fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a string matching the pattern `{}`", P::PATTERN)
+            }
+
+            fn unexpected<'v>(value: &'v $ty) -> Unexpected<'v> {
+                Unexpected::Str(value)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "regex_1")]
+matches_regex_impl!(str);
+#[cfg(feature = "regex_1")]
+matches_regex_impl!(String);
+#[cfg(feature = "regex_1")]
+matches_regex_impl!(Box<str>);
+#[cfg(feature = "regex_1")]
+matches_regex_impl!(Cow<'a, str>; 'a);
@@ -11,19 +11,24 @@ extern crate alloc;
 mod utils;
 
 use crate::utils::{
-    check_deserialization, check_error_deserialization, check_serialization, is_equal,
+    check_deserialization, check_error_deserialization, check_error_serialization,
+    check_serialization, is_equal,
 };
 use alloc::collections::BTreeMap;
-use chrono_0_4::{DateTime, Duration, Local, NaiveDateTime, TimeZone, Utc};
+use chrono_0_4::{
+    DateTime, Duration, FixedOffset, Local, Month, NaiveDateTime, TimeZone, Utc, Weekday,
+};
 use core::{iter::FromIterator, str::FromStr};
 use expect_test::expect;
 use serde::{Deserialize, Serialize};
 use serde_with::{
-    formats::Flexible, serde_as, DurationMicroSeconds, DurationMicroSecondsWithFrac,
-    DurationMilliSeconds, DurationMilliSecondsWithFrac, DurationNanoSeconds,
-    DurationNanoSecondsWithFrac, DurationSeconds, DurationSecondsWithFrac, TimestampMicroSeconds,
-    TimestampMicroSecondsWithFrac, TimestampMilliSeconds, TimestampMilliSecondsWithFrac,
-    TimestampNanoSeconds, TimestampNanoSecondsWithFrac, TimestampSeconds, TimestampSecondsWithFrac,
+    formats::{self, Flexible, Saturating},
+    serde_as, AsName, DurationMicroSeconds, DurationMicroSecondsWithFrac, DurationMilliSeconds,
+    DurationMilliSecondsWithFrac, DurationNanoSeconds, DurationNanoSecondsWithFrac,
+    DurationSeconds, DurationSecondsWithFrac, MonthAsNumber, OffsetAsString, OffsetSeconds,
+    TimestampAny, TimestampMicroSeconds, TimestampMicroSecondsWithFrac, TimestampMilliSeconds,
+    TimestampMilliSecondsWithFrac, TimestampNanoSeconds, TimestampNanoSecondsWithFrac,
+    TimestampSeconds, TimestampSecondsWithFrac, WeekdayAsNumber,
 };
 
 fn new_datetime(secs: i64, nsecs: u32) -> DateTime<Utc> {
@@ -80,6 +85,65 @@ fn json_datetime_from_any_to_string_deserialization() {
     );
 }
 
+#[test]
+fn test_chrono_timestamp_any() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S(#[serde_as(as = "TimestampAny")] DateTime<Utc>);
+
+    is_equal(
+        S(new_datetime(1_478_563_200, 0)),
+        expect![[r#"1478563200"#]],
+    );
+    check_deserialization(
+        vec![
+            S(new_datetime(1_478_563_200, 0)),
+            S(new_datetime(1_478_563_200, 123_000_000)),
+            S(new_datetime(1_478_563_200, 123_000_000)),
+            S(new_datetime(1_478_563_200, 123_000_000)),
+            S(new_datetime(-86000, 999_000_000)),
+        ],
+        r#"[
+            1478563200,
+            "1478563200.123",
+            "2016-11-08T00:00:00.123Z",
+            "2016-11-08T05:30:00.123+05:30",
+            "1969-12-31T00:06:40.999Z"
+        ]"#,
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Rfc3339(#[serde_as(as = "TimestampAny<formats::Rfc3339>")] DateTime<Utc>);
+
+    is_equal(
+        Rfc3339(new_datetime(1_478_563_200, 123_000_000)),
+        expect![[r#""2016-11-08T00:00:00.123Z""#]],
+    );
+    is_equal(
+        Rfc3339(new_datetime(-86000, 999_999_999)),
+        expect![[r#""1969-12-31T00:06:40.999999999Z""#]],
+    );
+    check_deserialization(Rfc3339(new_datetime(1_478_563_200, 0)), r#"1478563200"#);
+    check_error_serialization(
+        Rfc3339(DateTime::<Utc>::MAX_UTC),
+        expect![[r#"timestamp is outside the range representable in RFC 3339"#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Naive(#[serde_as(as = "TimestampAny<String>")] NaiveDateTime);
+
+    is_equal(
+        Naive(new_datetime(1_478_563_200, 500_000_000).naive_utc()),
+        expect![[r#""1478563200.5""#]],
+    );
+    check_deserialization(
+        Naive(new_datetime(1_478_563_200, 0).naive_utc()),
+        r#""2016-11-08T00:00:00Z""#,
+    );
+}
+
 #[test]
 fn test_chrono_naive_date_time() {
     #[serde_as]
@@ -466,7 +530,7 @@ fn test_chrono_timestamp_seconds() {
     check_error_deserialization::<StructStringStrict>(
         r#"0.0"#,
         expect![[
-            r#"invalid type: floating point `0`, expected a string containing a number at line 1 column 3"#
+            r#"invalid type: floating point `0.0`, expected a string containing a number at line 1 column 3"#
         ]],
     );
 
@@ -556,7 +620,7 @@ fn test_chrono_timestamp_seconds_with_frac() {
     );
     check_error_deserialization::<StructStringStrict>(
         r#"0.0"#,
-        expect![[r#"invalid type: floating point `0`, expected a string at line 1 column 3"#]],
+        expect![[r#"invalid type: floating point `0.0`, expected a string at line 1 column 3"#]],
     );
 
     #[serde_as]
@@ -596,6 +660,40 @@ macro_rules! smoketest {
     };
 }
 
+#[test]
+fn test_chrono_saturating() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Dur(#[serde_as(as = "DurationSeconds<i64, Saturating>")] Duration);
+
+    is_equal(Dur(Duration::seconds(-1)), expect![[r#"-1"#]]);
+    check_deserialization(Dur(Duration::seconds(1)), r#""1""#);
+    check_deserialization(Dur(Duration::max_value()), r#"1e30"#);
+    check_deserialization(Dur(-Duration::max_value()), r#""-100000000000000000000""#);
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct DtUtc(#[serde_as(as = "TimestampSeconds<i64, Saturating>")] DateTime<Utc>);
+
+    is_equal(
+        DtUtc(new_datetime(1_478_563_200, 0)),
+        expect![[r#"1478563200"#]],
+    );
+    check_deserialization(DtUtc(DateTime::<Utc>::MAX_UTC), r#"1e30"#);
+    check_deserialization(DtUtc(DateTime::<Utc>::MIN_UTC), r#"-9223372036854775807"#);
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Naive(#[serde_as(as = "TimestampMilliSecondsWithFrac<f64, Saturating>")] NaiveDateTime);
+
+    is_equal(
+        Naive(NaiveDateTime::from_timestamp_opt(1, 500_000_000).unwrap()),
+        expect![[r#"1500.0"#]],
+    );
+    check_deserialization(Naive(NaiveDateTime::MAX), r#""100000000000000000000000.5""#);
+    check_deserialization(Naive(NaiveDateTime::MIN), r#"-1e30"#);
+}
+
 #[test]
 fn test_duration_smoketest() {
     let zero = Duration::seconds(0);
@@ -739,3 +837,234 @@ fn test_naive_datetime_smoketest() {
         NaiveDateTime, "TimestampSecondsWithFrac", zero - Duration::seconds(1), {expect![[r#"-1.0"#]]};
     };
 }
+
+#[test]
+fn test_fixed_offset_as_string() {
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let india = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+    let newfoundland = FixedOffset::west_opt(3 * 3600 + 30 * 60).unwrap();
+    let with_seconds = FixedOffset::east_opt(9 * 60 + 21).unwrap();
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Strict(#[serde_as(as = "OffsetAsString")] FixedOffset);
+
+    is_equal(Strict(utc), expect![[r#""Z""#]]);
+    is_equal(Strict(india), expect![[r#""+05:30""#]]);
+    is_equal(Strict(newfoundland), expect![[r#""-03:30""#]]);
+    is_equal(Strict(with_seconds), expect![[r#""+00:09:21""#]]);
+    check_deserialization(Strict(utc), r#""+00:00""#);
+    check_deserialization(Strict(utc), r#""-00:00""#);
+    check_error_deserialization::<Strict>(
+        r#""+0530""#,
+        expect![[
+            r#"invalid value: string "+0530", expected a UTC offset like "Z" or "+05:30" at line 1 column 7"#
+        ]],
+    );
+    check_error_deserialization::<Strict>(
+        r#"19800"#,
+        expect![[
+            r#"invalid type: integer `19800`, expected a UTC offset like "Z" or "+05:30" at line 1 column 5"#
+        ]],
+    );
+    check_error_deserialization::<Strict>(
+        r#""+24:00""#,
+        expect![[r#"UTC offset is outside of the representable range: 86400 seconds"#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Flexible(#[serde_as(as = "OffsetAsString<formats::Flexible>")] FixedOffset);
+
+    is_equal(Flexible(india), expect![[r#""+05:30""#]]);
+    check_deserialization(Flexible(utc), r#""z""#);
+    check_deserialization(Flexible(india), r#""+0530""#);
+    check_deserialization(Flexible(newfoundland), r#""-0330""#);
+    check_deserialization(
+        Flexible(FixedOffset::east_opt(5 * 3600).unwrap()),
+        r#""+05""#,
+    );
+    check_deserialization(Flexible(with_seconds), r#""+000921""#);
+    check_error_deserialization::<Flexible>(
+        r#""+5""#,
+        expect![[
+            r#"invalid value: string "+5", expected a UTC offset like "Z", "+05:30", "+0530", or "+05" at line 1 column 4"#
+        ]],
+    );
+}
+
+#[test]
+fn test_fixed_offset_seconds() {
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let india = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+    let newfoundland = FixedOffset::west_opt(3 * 3600 + 30 * 60).unwrap();
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct StructIntStrict(#[serde_as(as = "OffsetSeconds")] FixedOffset);
+
+    is_equal(StructIntStrict(utc), expect![[r#"0"#]]);
+    is_equal(StructIntStrict(india), expect![[r#"19800"#]]);
+    is_equal(StructIntStrict(newfoundland), expect![[r#"-12600"#]]);
+    check_error_deserialization::<StructIntStrict>(
+        r#""19800""#,
+        expect![[
+            r#"invalid type: string "19800", expected an integer number of seconds at line 1 column 7"#
+        ]],
+    );
+    check_error_deserialization::<StructIntStrict>(
+        r#"86400"#,
+        expect![[r#"UTC offset is outside of the representable range: 86400 seconds"#]],
+    );
+    check_error_deserialization::<StructIntStrict>(
+        r#"4294967296"#,
+        expect![[
+            r#"invalid value: integer `4294967296`, expected an integer number of seconds at line 1 column 10"#
+        ]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct StructStringStrict(#[serde_as(as = "OffsetSeconds<String>")] FixedOffset);
+
+    is_equal(StructStringStrict(india), expect![[r#""19800""#]]);
+    is_equal(StructStringStrict(newfoundland), expect![[r#""-12600""#]]);
+    check_error_deserialization::<StructStringStrict>(
+        r#"19800"#,
+        expect![[
+            r#"invalid type: integer `19800`, expected a string containing an integer number of seconds at line 1 column 5"#
+        ]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct StructIntFlexible(#[serde_as(as = "OffsetSeconds<i64, Flexible>")] FixedOffset);
+
+    is_equal(StructIntFlexible(india), expect![[r#"19800"#]]);
+    check_deserialization(StructIntFlexible(india), r#""19800""#);
+    check_deserialization(StructIntFlexible(newfoundland), r#""-12600""#);
+    check_error_deserialization::<StructIntFlexible>(
+        r#""+05:30""#,
+        expect![[
+            r#"invalid value: string "+05:30", expected an integer or a string containing an integer number of seconds at line 1 column 8"#
+        ]],
+    );
+}
+
+#[test]
+fn test_month_and_weekday_as_number() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S {
+        #[serde_as(as = "MonthAsNumber")]
+        month: Month,
+        #[serde_as(as = "WeekdayAsNumber")]
+        monday_zero: Weekday,
+        #[serde_as(as = "WeekdayAsNumber<formats::SundayZero>")]
+        sunday_zero: Weekday,
+        #[serde_as(as = "WeekdayAsNumber<formats::MondayOne>")]
+        iso: Weekday,
+    }
+
+    is_equal(
+        S {
+            month: Month::January,
+            monday_zero: Weekday::Mon,
+            sunday_zero: Weekday::Mon,
+            iso: Weekday::Mon,
+        },
+        expect![[r#"
+            {
+              "month": 1,
+              "monday_zero": 0,
+              "sunday_zero": 1,
+              "iso": 1
+            }"#]],
+    );
+    is_equal(
+        S {
+            month: Month::December,
+            monday_zero: Weekday::Sun,
+            sunday_zero: Weekday::Sun,
+            iso: Weekday::Sun,
+        },
+        expect![[r#"
+            {
+              "month": 12,
+              "monday_zero": 6,
+              "sunday_zero": 0,
+              "iso": 7
+            }"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"month": 0, "monday_zero": 0, "sunday_zero": 0, "iso": 1}"#,
+        expect![[
+            r#"invalid value: integer `0`, expected a month number between 1 and 12 at line 1 column 11"#
+        ]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"month": 1, "monday_zero": 0, "sunday_zero": 0, "iso": 0}"#,
+        expect![[
+            r#"invalid value: integer `0`, expected a weekday number between 1 and 7 at line 1 column 57"#
+        ]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"month": 1, "monday_zero": -1, "sunday_zero": 0, "iso": 1}"#,
+        expect![[
+            r#"invalid value: integer `-1`, expected a weekday number between 0 and 6 at line 1 column 30"#
+        ]],
+    );
+}
+
+#[test]
+fn test_month_and_weekday_as_name() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S {
+        #[serde_as(as = "AsName")]
+        month: Month,
+        #[serde_as(as = "AsName<formats::Uppercase, formats::Abbreviated>")]
+        month_short: Month,
+        #[serde_as(as = "AsName<formats::Lowercase>")]
+        weekday: Weekday,
+        #[serde_as(as = "AsName<formats::Titlecase, formats::Abbreviated>")]
+        weekday_short: Weekday,
+    }
+
+    is_equal(
+        S {
+            month: Month::May,
+            month_short: Month::September,
+            weekday: Weekday::Thu,
+            weekday_short: Weekday::Sat,
+        },
+        expect![[r#"
+            {
+              "month": "May",
+              "month_short": "SEP",
+              "weekday": "thursday",
+              "weekday_short": "Sat"
+            }"#]],
+    );
+    check_deserialization(
+        S {
+            month: Month::February,
+            month_short: Month::October,
+            weekday: Weekday::Mon,
+            weekday_short: Weekday::Sun,
+        },
+        r#"{"month": "feb", "month_short": "October", "weekday": "MON", "weekday_short": "sunday"}"#,
+    );
+    check_error_deserialization::<S>(
+        r#"{"month": "Janu", "month_short": "Jan", "weekday": "Mon", "weekday_short": "Mon"}"#,
+        expect![[
+            r#"invalid value: string "Janu", expected a month name like "January" or "Jan" at line 1 column 16"#
+        ]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"month": "Jan", "month_short": "Jan", "weekday": 1, "weekday_short": "Mon"}"#,
+        expect![[
+            r#"invalid type: integer `1`, expected a weekday name like "Monday" or "Mon" at line 1 column 51"#
+        ]],
+    );
+}
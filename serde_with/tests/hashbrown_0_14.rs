@@ -96,6 +96,39 @@ fn test_hashset() {
     is_equal(SStd(HashSet::default()), expect![[r#"[]"#]]);
 }
 
+#[test]
+fn test_sorted_map_and_set() {
+    use serde_with::{SortedMap, SortedSet};
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S(
+        #[serde_as(as = "SortedMap<DisplayFromStr, _>")] HashMap<u8, u32>,
+        #[serde_as(as = "SortedSet<_>")] HashSet<u32>,
+    );
+
+    is_equal(
+        S(
+            [(111, 1), (3, 3), (1, 111)].iter().copied().collect(),
+            [4, 1, 3, 2].iter().copied().collect(),
+        ),
+        expect![[r#"
+            [
+              {
+                "1": 111,
+                "3": 3,
+                "111": 1
+              },
+              [
+                1,
+                2,
+                3,
+                4
+              ]
+            ]"#]],
+    );
+}
+
 #[test]
 fn test_map_as_tuple_list() {
     let ip = "1.2.3.4".parse().unwrap();
@@ -215,7 +248,7 @@ fn prohibit_duplicate_key_hashmap() {
     // Duplicate keys are an error
     check_error_deserialization::<S>(
         r#"{"1": 1, "2": 2, "1": 3}"#,
-        expect![[r#"invalid entry: found duplicate key at line 1 column 24"#]],
+        expect![[r#"invalid entry: found duplicate key 1 at index 2 at line 1 column 24"#]],
     );
 }
 
@@ -297,6 +330,29 @@ fn prohibit_duplicate_value_hashset() {
     );
     check_error_deserialization::<S>(
         r#"[1, 2, 3, 4, 1]"#,
-        expect![[r#"invalid entry: found duplicate value at line 1 column 15"#]],
+        expect![[r#"invalid entry: found duplicate value 1 at index 4 at line 1 column 15"#]],
+    );
+}
+
+#[test]
+fn merge_duplicate_keys_hashmap() {
+    use serde_with::{
+        formats::{Concat, DeepMerge},
+        MapMergeDuplicates,
+    };
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S(
+        #[serde_as(as = "MapMergeDuplicates<_, _, DeepMerge<Concat>>")]
+        HashMap<String, HashMap<String, Vec<u8>>>,
+    );
+
+    check_deserialization(
+        S(HashMap::from_iter(vec![(
+            "a".into(),
+            HashMap::from_iter(vec![("x".into(), vec![1, 2]), ("y".into(), vec![3])]),
+        )])),
+        r#"{"a": {"x": [1]}, "a": {"x": [2], "y": [3]}}"#,
     );
 }
@@ -175,7 +175,7 @@ fn prohibit_duplicate_key_indexmap() {
     // Duplicate keys are an error
     check_error_deserialization::<S>(
         r#"{"1": 1, "2": 2, "1": 3}"#,
-        expect![[r#"invalid entry: found duplicate key at line 1 column 24"#]],
+        expect![[r#"invalid entry: found duplicate key 1 at index 2 at line 1 column 24"#]],
     );
 }
 
@@ -257,6 +257,29 @@ fn prohibit_duplicate_value_indexset() {
     );
     check_error_deserialization::<S>(
         r#"[1, 2, 3, 4, 1]"#,
-        expect![[r#"invalid entry: found duplicate value at line 1 column 15"#]],
+        expect![[r#"invalid entry: found duplicate value 1 at index 4 at line 1 column 15"#]],
+    );
+}
+
+#[test]
+fn merge_duplicate_keys_indexmap() {
+    use serde_with::{
+        formats::{Concat, DeepMerge},
+        MapMergeDuplicates,
+    };
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S(
+        #[serde_as(as = "MapMergeDuplicates<_, _, DeepMerge<Concat>>")]
+        IndexMap<String, IndexMap<String, Vec<u8>>>,
+    );
+
+    check_deserialization(
+        S(IndexMap::from_iter(vec![(
+            "a".into(),
+            IndexMap::from_iter(vec![("x".into(), vec![1, 2]), ("y".into(), vec![3])]),
+        )])),
+        r#"{"a": {"x": [1]}, "a": {"x": [2], "y": [3]}}"#,
     );
 }
@@ -0,0 +1,273 @@
+#![allow(
+    // clippy is broken and shows wrong warnings
+    // clippy on stable does not know yet about the lint name
+    unknown_lints,
+    // https://github.com/rust-lang/rust-clippy/issues/8867
+    clippy::derive_partial_eq_without_eq,
+)]
+
+mod utils;
+
+use crate::utils::{
+    check_deserialization, check_error_deserialization, check_error_serialization, is_equal,
+};
+use expect_test::expect;
+use jiff_0_2::{
+    civil::{date, DateTime},
+    tz::{self, TimeZone},
+    SignedDuration, Span, Timestamp, ToSpan, Zoned,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::{
+    formats::{Flexible, Saturating},
+    jiff_0_2::{Iso8601, Rfc3339},
+    serde_as, DurationMicroSeconds, DurationMicroSecondsWithFrac, DurationMilliSeconds,
+    DurationMilliSecondsWithFrac, DurationNanoSeconds, DurationNanoSecondsWithFrac,
+    DurationSeconds, DurationSecondsWithFrac, TimestampAny, TimestampMicroSeconds,
+    TimestampMicroSecondsWithFrac, TimestampMilliSeconds, TimestampMilliSecondsWithFrac,
+    TimestampNanoSeconds, TimestampNanoSecondsWithFrac, TimestampSeconds, TimestampSecondsWithFrac,
+};
+
+macro_rules! smoketest {
+    ($($valuety:ty, $adapter:literal, $value:expr, $expect:tt;)*) => {
+        $({
+            #[serde_as]
+            #[derive(Debug, Serialize, Deserialize, PartialEq)]
+            struct S(#[serde_as(as = $adapter)] $valuety);
+            #[allow(unused_braces)]
+            is_equal(S($value), $expect);
+        })*
+    };
+}
+
+#[test]
+fn test_signed_duration_smoketest() {
+    let zero = SignedDuration::ZERO;
+    let one_second = SignedDuration::from_secs(1);
+
+    smoketest! {
+        SignedDuration, "DurationSeconds<i64>", one_second, {expect![[r#"1"#]]};
+        SignedDuration, "DurationSeconds<f64>", one_second, {expect![[r#"1.0"#]]};
+        SignedDuration, "DurationMilliSeconds<i64>", one_second, {expect![[r#"1000"#]]};
+        SignedDuration, "DurationMilliSeconds<f64>", one_second, {expect![[r#"1000.0"#]]};
+        SignedDuration, "DurationMicroSeconds<i64>", one_second, {expect![[r#"1000000"#]]};
+        SignedDuration, "DurationMicroSeconds<f64>", one_second, {expect![[r#"1000000.0"#]]};
+        SignedDuration, "DurationNanoSeconds<i64>", one_second, {expect![[r#"1000000000"#]]};
+        SignedDuration, "DurationNanoSeconds<f64>", one_second, {expect![[r#"1000000000.0"#]]};
+    };
+
+    smoketest! {
+        SignedDuration, "DurationSecondsWithFrac", one_second, {expect![[r#"1.0"#]]};
+        SignedDuration, "DurationSecondsWithFrac<String>", one_second, {expect![[r#""1""#]]};
+        SignedDuration, "DurationMilliSecondsWithFrac", one_second, {expect![[r#"1000.0"#]]};
+        SignedDuration, "DurationMilliSecondsWithFrac<String>", one_second, {expect![[r#""1000""#]]};
+        SignedDuration, "DurationMicroSecondsWithFrac", one_second, {expect![[r#"1000000.0"#]]};
+        SignedDuration, "DurationMicroSecondsWithFrac<String>", one_second, {expect![[r#""1000000""#]]};
+        SignedDuration, "DurationNanoSecondsWithFrac", one_second, {expect![[r#"1000000000.0"#]]};
+        SignedDuration, "DurationNanoSecondsWithFrac<String>", one_second, {expect![[r#""1000000000""#]]};
+    };
+
+    smoketest! {
+        SignedDuration, "DurationSecondsWithFrac", zero, {expect![[r#"0.0"#]]};
+        SignedDuration, "DurationSecondsWithFrac", SignedDuration::from_millis(500), {expect![[r#"0.5"#]]};
+        SignedDuration, "DurationSecondsWithFrac", SignedDuration::from_millis(-500), {expect![[r#"-0.5"#]]};
+        SignedDuration, "DurationSecondsWithFrac", -one_second, {expect![[r#"-1.0"#]]};
+        SignedDuration, "DurationSeconds<String, Flexible>", -one_second, {expect![[r#""-1""#]]};
+    };
+}
+
+#[test]
+fn test_signed_duration_range() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S(#[serde_as(as = "DurationSecondsWithFrac<String>")] SignedDuration);
+
+    // The negative range is larger than the positive one
+    check_deserialization(
+        S(SignedDuration::MIN),
+        r#""-9223372036854775808.999999999""#,
+    );
+    check_deserialization(S(SignedDuration::MAX), r#""9223372036854775807.999999999""#);
+    check_error_deserialization::<S>(
+        r#""9223372036854775808""#,
+        expect![[r#"Duration is outside of the representable range"#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Saturated(#[serde_as(as = "DurationSeconds<i64, Saturating>")] SignedDuration);
+
+    check_deserialization(Saturated(SignedDuration::MAX), r#"1e30"#);
+    check_deserialization(Saturated(SignedDuration::MIN), r#"-1e30"#);
+}
+
+#[test]
+fn test_span() {
+    #[serde_as]
+    #[derive(Debug, Deserialize, Serialize)]
+    struct S(#[serde_as(as = "DurationMilliSeconds<i64>")] Span);
+
+    let span = 1.hour().minutes(2).milliseconds(500);
+    expect![[r#"3720500"#]].assert_eq(&serde_json::to_string(&S(span)).unwrap());
+    let S(deserialized) = serde_json::from_str("3720500").unwrap();
+    // Spans are converted to seconds and smaller units
+    assert_eq!(
+        3720.seconds().milliseconds(500).fieldwise(),
+        deserialized.fieldwise()
+    );
+
+    check_error_serialization(
+        S(1.day()),
+        expect![[
+            r#"failed to convert span to duration without relative datetime (must use `jiff::Span::to_duration` instead): using unit 'day' in a span or configuration requires that either a relative reference time be given or `jiff::SpanRelativeTo::days_are_24_hours()` is used to indicate invariant 24-hour days, but neither were provided"#
+        ]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Saturated(#[serde_as(as = "DurationSeconds<f64, Saturating>")] Span);
+
+    let Saturated(deserialized) = serde_json::from_str("1e30").unwrap();
+    assert_eq!(
+        631_107_417_600_i64
+            .seconds()
+            .milliseconds(999)
+            .microseconds(999)
+            .nanoseconds(999)
+            .fieldwise(),
+        deserialized.fieldwise()
+    );
+}
+
+#[test]
+fn test_timestamp_smoketest() {
+    let zero = Timestamp::UNIX_EPOCH;
+    let one_second = Timestamp::from_second(1).unwrap();
+
+    smoketest! {
+        Timestamp, "TimestampSeconds<i64>", one_second, {expect![[r#"1"#]]};
+        Timestamp, "TimestampSeconds<f64>", one_second, {expect![[r#"1.0"#]]};
+        Timestamp, "TimestampMilliSeconds<i64>", one_second, {expect![[r#"1000"#]]};
+        Timestamp, "TimestampMilliSeconds<f64>", one_second, {expect![[r#"1000.0"#]]};
+        Timestamp, "TimestampMicroSeconds<i64>", one_second, {expect![[r#"1000000"#]]};
+        Timestamp, "TimestampMicroSeconds<f64>", one_second, {expect![[r#"1000000.0"#]]};
+        Timestamp, "TimestampNanoSeconds<i64>", one_second, {expect![[r#"1000000000"#]]};
+        Timestamp, "TimestampNanoSeconds<f64>", one_second, {expect![[r#"1000000000.0"#]]};
+    };
+
+    smoketest! {
+        Timestamp, "TimestampSecondsWithFrac", one_second, {expect![[r#"1.0"#]]};
+        Timestamp, "TimestampSecondsWithFrac<String>", one_second, {expect![[r#""1""#]]};
+        Timestamp, "TimestampMilliSecondsWithFrac", one_second, {expect![[r#"1000.0"#]]};
+        Timestamp, "TimestampMilliSecondsWithFrac<String>", one_second, {expect![[r#""1000""#]]};
+        Timestamp, "TimestampMicroSecondsWithFrac", one_second, {expect![[r#"1000000.0"#]]};
+        Timestamp, "TimestampMicroSecondsWithFrac<String>", one_second, {expect![[r#""1000000""#]]};
+        Timestamp, "TimestampNanoSecondsWithFrac", one_second, {expect![[r#"1000000000.0"#]]};
+        Timestamp, "TimestampNanoSecondsWithFrac<String>", one_second, {expect![[r#""1000000000""#]]};
+    };
+
+    smoketest! {
+        Timestamp, "TimestampSecondsWithFrac", zero, {expect![[r#"0.0"#]]};
+        Timestamp, "TimestampSecondsWithFrac", Timestamp::from_millisecond(500).unwrap(), {expect![[r#"0.5"#]]};
+        Timestamp, "TimestampSecondsWithFrac", Timestamp::from_millisecond(-500).unwrap(), {expect![[r#"-0.5"#]]};
+        Timestamp, "TimestampSecondsWithFrac", Timestamp::from_second(-1).unwrap(), {expect![[r#"-1.0"#]]};
+    };
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S(#[serde_as(as = "TimestampSeconds")] Timestamp);
+
+    check_error_deserialization::<S>(
+        r#"1000000000000"#,
+        expect![[
+            r#"parameter 'Unix timestamp seconds' is not in the required range of -377705023201..=253402207200"#
+        ]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Saturated(#[serde_as(as = "TimestampSeconds<i64, Saturating>")] Timestamp);
+
+    check_deserialization(Saturated(Timestamp::MAX), r#"1e30"#);
+    check_deserialization(Saturated(Timestamp::MIN), r#"-1e30"#);
+}
+
+#[test]
+fn test_timestamp_any() {
+    let with_millis = Timestamp::from_millisecond(1_478_563_200_123).unwrap();
+
+    smoketest! {
+        Timestamp, "TimestampAny", Timestamp::from_second(1_478_563_200).unwrap(), {expect![[r#"1478563200"#]]};
+        Timestamp, "TimestampAny<f64>", Timestamp::from_millisecond(500).unwrap(), {expect![[r#"0.5"#]]};
+        Timestamp, "TimestampAny<String>", Timestamp::from_millisecond(-500).unwrap(), {expect![[r#""-0.5""#]]};
+        Timestamp, "TimestampAny<serde_with::formats::Rfc3339>", with_millis, {expect![[r#""2016-11-08T00:00:00.123Z""#]]};
+    };
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S(#[serde_as(as = "TimestampAny")] Timestamp);
+
+    check_deserialization(S(with_millis), r#""1478563200.123""#);
+    check_deserialization(S(with_millis), r#""2016-11-08T05:30:00.123+05:30""#);
+}
+
+#[test]
+fn test_rfc3339() {
+    let civil = date(2024, 6, 19).at(15, 22, 45, 500_000_000);
+    let zoned = civil.to_zoned(TimeZone::fixed(tz::offset(-4))).unwrap();
+
+    smoketest! {
+        Timestamp, "Rfc3339", zoned.timestamp(), {expect![[r#""2024-06-19T19:22:45.5Z""#]]};
+        Zoned, "Rfc3339", zoned.clone(), {expect![[r#""2024-06-19T15:22:45.5-04:00""#]]};
+        DateTime, "Rfc3339", civil, {expect![[r#""2024-06-19T15:22:45.5Z""#]]};
+    };
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Z(#[serde_as(as = "Rfc3339")] Zoned);
+
+    check_deserialization(
+        Z(civil.to_zoned(TimeZone::UTC).unwrap()),
+        r#""2024-06-19T15:22:45.5Z""#,
+    );
+    check_error_deserialization::<Z>(
+        r#""2024-06-19T15:22:45""#,
+        expect![[r#"RFC3339 timestamp requires a UTC offset at line 1 column 21"#]],
+    );
+    check_error_deserialization::<Z>(
+        r#"1"#,
+        expect![[
+            r#"invalid type: integer `1`, expected a RFC3339-formatted `Zoned` at line 1 column 1"#
+        ]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Civil(#[serde_as(as = "Rfc3339")] DateTime);
+
+    // The offset is applied before dropping it
+    check_deserialization(Civil(civil), r#""2024-06-19T19:22:45.5+04:00""#);
+}
+
+#[test]
+fn test_iso8601() {
+    let civil = date(2024, 6, 19).at(15, 22, 45, 0);
+    let zoned = civil.to_zoned(TimeZone::fixed(tz::offset(2))).unwrap();
+
+    smoketest! {
+        Timestamp, "Iso8601", zoned.timestamp(), {expect![[r#""2024-06-19T13:22:45Z""#]]};
+        Zoned, "Iso8601", zoned.clone(), {expect![[r#""2024-06-19T15:22:45+02:00[+02:00]""#]]};
+        DateTime, "Iso8601", civil, {expect![[r#""2024-06-19T15:22:45""#]]};
+    };
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Civil(#[serde_as(as = "Iso8601")] DateTime);
+
+    check_error_deserialization::<Civil>(
+        r#""2024-06-19 25:00""#,
+        expect![[
+            r#"failed to parse two digit integer as hour: parameter 'hour' is not in the required range of 0..=23 at line 1 column 18"#
+        ]],
+    );
+}
@@ -8,11 +8,15 @@
 
 mod utils;
 
-use crate::utils::is_equal;
+use crate::utils::{check_deserialization, check_error_deserialization, is_equal};
 use expect_test::expect;
 use serde::{Deserialize, Serialize};
-use serde_with::{json::JsonString, serde_as, DisplayFromStr};
-use std::collections::BTreeMap;
+use serde_with::{
+    formats::UnquotedStrings,
+    json::{JsonKey, JsonString},
+    serde_as, DisplayFromStr, Map, Same,
+};
+use std::collections::{BTreeMap, HashMap};
 
 #[test]
 fn test_jsonstring() {
@@ -60,3 +64,131 @@ fn test_jsonstring_nested() {
             }"#]],
     );
 }
+
+#[test]
+fn test_jsonkey() {
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+    enum Kind {
+        Read,
+        Write,
+    }
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Struct {
+        #[serde_as(as = "BTreeMap<JsonKey, _>")]
+        tuples: BTreeMap<(u32, String), u32>,
+        #[serde_as(as = "BTreeMap<JsonKey, _>")]
+        numbers: BTreeMap<i64, u32>,
+        #[serde_as(as = "BTreeMap<JsonKey, _>")]
+        enums: BTreeMap<Kind, u32>,
+        #[serde_as(as = "Map<JsonKey<(_, DisplayFromStr)>, _>")]
+        pairs: Vec<((u8, u8), u32)>,
+    }
+
+    is_equal(
+        Struct {
+            tuples: BTreeMap::from([((1, "a".into()), 1), ((2, "b".into()), 2)]),
+            numbers: BTreeMap::from([(-1, 1), (100, 2)]),
+            enums: BTreeMap::from([(Kind::Read, 1), (Kind::Write, 2)]),
+            pairs: vec![((1, 2), 3)],
+        },
+        expect![[r#"
+            {
+              "tuples": {
+                "[1,\"a\"]": 1,
+                "[2,\"b\"]": 2
+              },
+              "numbers": {
+                "-1": 1,
+                "100": 2
+              },
+              "enums": {
+                "\"Read\"": 1,
+                "\"Write\"": 2
+              },
+              "pairs": {
+                "[1,\"2\"]": 3
+              }
+            }"#]],
+    );
+
+    check_error_deserialization::<Struct>(
+        r#"{"tuples": {"[1]": 1}, "numbers": {}, "enums": {}, "pairs": {}}"#,
+        expect![[r#"invalid length 1, expected a tuple of size 2 at line 1 column 3"#]],
+    );
+    check_error_deserialization::<Struct>(
+        r#"{"tuples": {}, "numbers": {"one": 1}, "enums": {}, "pairs": {}}"#,
+        expect![[r#"expected value at line 1 column 1"#]],
+    );
+}
+
+#[test]
+fn test_jsonkey_strings() {
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Struct(
+        #[serde_as(as = "HashMap<JsonKey<Same, UnquotedStrings>, _>")] HashMap<String, u32>,
+    );
+
+    // Strings are only quoted if they would be valid JSON otherwise
+    is_equal(
+        Struct(HashMap::from([("1".into(), 1)])),
+        expect![[r#"
+            {
+              "\"1\"": 1
+            }"#]],
+    );
+    is_equal(
+        Struct(HashMap::from([("one".into(), 1)])),
+        expect![[r#"
+            {
+              "one": 1
+            }"#]],
+    );
+    is_equal(
+        Struct(HashMap::from([("\"one\"".into(), 1)])),
+        expect![[r#"
+            {
+              "\"\\\"one\\\"\"": 1
+            }"#]],
+    );
+    is_equal(
+        Struct(HashMap::from([(String::new(), 1)])),
+        expect![[r#"
+            {
+              "": 1
+            }"#]],
+    );
+
+    // Starts like a number, but is not valid JSON
+    is_equal(
+        Struct(HashMap::from([("1 x".into(), 1)])),
+        expect![[r#"
+            {
+              "1 x": 1
+            }"#]],
+    );
+
+    check_deserialization(
+        Struct(HashMap::from([("true".into(), 1), ("null".into(), 2)])),
+        r#"{"\"true\"": 1, "\"null\"": 2}"#,
+    );
+
+    // By default, the quotes are kept for all strings
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Quoted(#[serde_as(as = "HashMap<JsonKey, _>")] HashMap<String, u32>);
+
+    is_equal(
+        Quoted(HashMap::from([("one".into(), 1)])),
+        expect![[r#"
+            {
+              "\"one\"": 1
+            }"#]],
+    );
+    check_error_deserialization::<Quoted>(
+        r#"{"one": 1}"#,
+        expect![[r#"expected value at line 1 column 1"#]],
+    );
+}
@@ -35,7 +35,7 @@ fn prohibit_duplicate_value_hashset() {
     );
     check_error_deserialization::<S>(
         r#"[1, 2, 3, 4, 1]"#,
-        expect![[r#"invalid entry: found duplicate value at line 1 column 15"#]],
+        expect![[r#"invalid entry: found duplicate value 1 at index 4 at line 1 column 15"#]],
     );
 }
 
@@ -56,7 +56,7 @@ fn prohibit_duplicate_value_btreeset() {
     );
     check_error_deserialization::<S>(
         r#"[1, 2, 3, 4, 1]"#,
-        expect![[r#"invalid entry: found duplicate value at line 1 column 15"#]],
+        expect![[r#"invalid entry: found duplicate value 1 at index 4 at line 1 column 15"#]],
     );
 }
 
@@ -92,7 +92,7 @@ fn prohibit_duplicate_key_hashmap() {
     // Duplicate keys are an error
     check_error_deserialization::<S>(
         r#"{"1": 1, "2": 2, "1": 3}"#,
-        expect![[r#"invalid entry: found duplicate key at line 1 column 24"#]],
+        expect![[r#"invalid entry: found duplicate key 1 at index 2 at line 1 column 24"#]],
     );
 }
 
@@ -128,7 +128,7 @@ fn prohibit_duplicate_key_btreemap() {
     // Duplicate keys are an error
     check_error_deserialization::<S>(
         r#"{"1": 1, "2": 2, "1": 3}"#,
-        expect![[r#"invalid entry: found duplicate key at line 1 column 24"#]],
+        expect![[r#"invalid entry: found duplicate key 1 at index 2 at line 1 column 24"#]],
     );
 }
 
@@ -671,6 +671,23 @@ fn test_map() {
     });
 }
 
+#[test]
+fn test_vec_as_index_map() {
+    #[serde_as]
+    #[derive(Serialize, JsonSchema)]
+    struct Test {
+        #[serde_as(as = "VecAsIndexMap<_>")]
+        dense: Vec<u32>,
+        #[serde_as(as = "VecAsIndexMap<_, formats::Flexible>")]
+        sparse: Vec<Option<u32>>,
+    }
+
+    check_valid_json_schema(&Test {
+        dense: vec![1, 2],
+        sparse: vec![None, Some(1)],
+    });
+}
+
 #[test]
 fn test_set_last_value_wins_with_duplicates() {
     #[serde_as]
@@ -699,3 +716,166 @@ fn test_set_prevent_duplicates_with_duplicates() {
         "set": [ 1, 1 ]
     }));
 }
+
+mod len {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[serde_as]
+    #[derive(JsonSchema, Serialize)]
+    struct Bounded {
+        #[serde_as(as = "MaxLen<2>")]
+        seq: Vec<u32>,
+        #[serde_as(as = "LenBetween<1, 2>")]
+        map: BTreeMap<String, u32>,
+        #[serde_as(as = "LenBetween<1, 3>")]
+        string: String,
+    }
+
+    #[test]
+    fn test_serialized_is_valid() {
+        check_valid_json_schema(&Bounded {
+            seq: vec![1, 2],
+            map: BTreeMap::from([("a".into(), 1)]),
+            string: "abc".into(),
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_seq_too_long() {
+        check_matches_schema::<Bounded>(&json!({
+            "seq": [1, 2, 3],
+            "map": {"a": 1},
+            "string": "a",
+        }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_map_too_short() {
+        check_matches_schema::<Bounded>(&json!({
+            "seq": [],
+            "map": {},
+            "string": "a",
+        }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_string_too_long() {
+        check_matches_schema::<Bounded>(&json!({
+            "seq": [],
+            "map": {"a": 1},
+            "string": "abcd",
+        }));
+    }
+}
+
+mod validated {
+    use super::*;
+    use serde_with::validate::{InRange, NonEmpty, Not, Or};
+
+    #[serde_as]
+    #[derive(JsonSchema, Serialize)]
+    struct Constrained {
+        #[serde_as(as = "Validated<_, NonEmpty>")]
+        seq: Vec<u32>,
+        #[serde_as(as = "Validated<_, Or<InRange<1, 3>, InRange<10, 10>>>")]
+        level: u8,
+        #[serde_as(as = "Validated<_, Not<InRange<0, 0>>>")]
+        offset: i32,
+    }
+
+    #[test]
+    fn test_serialized_is_valid() {
+        check_valid_json_schema(&Constrained {
+            seq: vec![1],
+            level: 10,
+            offset: -1,
+        });
+    }
+
+    #[test]
+    fn test_range_bounds_are_not_widened() {
+        #[serde_as]
+        #[derive(JsonSchema, Serialize)]
+        struct Large {
+            #[serde_as(
+                as = "Validated<_, InRange<9007199254740993, 170141183460469231731687303715884105727>>"
+            )]
+            positive: i128,
+            #[serde_as(as = "Validated<_, InRange<{ i128::MIN }, { -9007199254740993 }>>")]
+            negative: i128,
+        }
+
+        let schema = serde_json::to_value(schemars::schema_for!(Large)).unwrap();
+        let properties = &schema["properties"];
+        assert_eq!(
+            json!(9_007_199_254_740_994.0),
+            properties["positive"]["minimum"]
+        );
+        assert_eq!(
+            json!(2f64.powi(127) - 2f64.powi(74)),
+            properties["positive"]["maximum"]
+        );
+        assert_eq!(json!(-(2f64.powi(127))), properties["negative"]["minimum"]);
+        assert_eq!(
+            json!(-9_007_199_254_740_994.0),
+            properties["negative"]["maximum"]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty_seq() {
+        check_matches_schema::<Constrained>(&json!({
+            "seq": [],
+            "level": 1,
+            "offset": 1,
+        }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_range() {
+        check_matches_schema::<Constrained>(&json!({
+            "seq": [1],
+            "level": 5,
+            "offset": 1,
+        }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_not_in_range() {
+        check_matches_schema::<Constrained>(&json!({
+            "seq": [1],
+            "level": 1,
+            "offset": 0,
+        }));
+    }
+}
+
+#[cfg(feature = "regex_1")]
+#[test]
+#[should_panic]
+fn test_validated_regex_mismatch() {
+    use serde_with::validate::{MatchesRegex, RegexPattern};
+
+    struct Word;
+    impl RegexPattern for Word {
+        const PATTERN: &'static str = "^[a-z]+$";
+    }
+
+    #[serde_as]
+    #[derive(JsonSchema, Serialize)]
+    struct Test {
+        #[serde_as(as = "Validated<_, MatchesRegex<Word>>")]
+        word: String,
+    }
+
+    check_matches_schema::<Test>(&json!({
+        "word": "A",
+    }));
+}
@@ -42,3 +42,180 @@ fn test_fnv_hashmap() {
     );
     is_equal(S(FnvHashMap::default()), expect![[r#"{}"#]]);
 }
+
+#[test]
+fn test_sorted_map_and_set() {
+    use serde_with::{SortedMap, SortedSet};
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S {
+        #[serde_as(as = "SortedMap<DisplayFromStr, _>")]
+        map: FnvHashMap<u8, u32>,
+        #[serde_as(as = "SortedSet<DisplayFromStr>")]
+        set: FnvHashSet<u32>,
+        #[serde_as(as = "Vec<SortedMap<_, Map<DisplayFromStr, _>>>")]
+        nested: Vec<HashMap<String, Vec<(u8, bool)>>>,
+    }
+
+    is_equal(
+        S {
+            map: [(111, 1), (3, 3), (1, 111), (20, 0)]
+                .iter()
+                .copied()
+                .collect(),
+            set: [5, 10, 1, 3, 2].iter().copied().collect(),
+            nested: vec![HashMap::from([
+                ("b".into(), vec![(1, true)]),
+                ("a".into(), vec![(2, false), (1, true)]),
+                ("c".into(), vec![]),
+            ])],
+        },
+        expect![[r#"
+            {
+              "map": {
+                "1": 111,
+                "3": 3,
+                "20": 0,
+                "111": 1
+              },
+              "set": [
+                "1",
+                "2",
+                "3",
+                "5",
+                "10"
+              ],
+              "nested": [
+                {
+                  "a": {
+                    "2": false,
+                    "1": true
+                  },
+                  "b": {
+                    "1": true
+                  },
+                  "c": {}
+                }
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn test_max_len() {
+    use serde_with::{Bytes, MaxLen};
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S {
+        #[serde_as(as = "MaxLen<2, Vec<DisplayFromStr>>")]
+        seq: Vec<u32>,
+        #[serde_as(as = "MaxLen<1>")]
+        map: BTreeMap<String, u32>,
+        #[serde_as(as = "MaxLen<3>")]
+        string: String,
+        #[serde_as(as = "MaxLen<3, Bytes>")]
+        bytes: Vec<u8>,
+    }
+
+    is_equal(
+        S {
+            seq: vec![1, 2],
+            map: BTreeMap::from([("a".into(), 1)]),
+            string: "abc".into(),
+            bytes: b"abc".to_vec(),
+        },
+        expect![[r#"
+            {
+              "seq": [
+                "1",
+                "2"
+              ],
+              "map": {
+                "a": 1
+              },
+              "string": "abc",
+              "bytes": [
+                97,
+                98,
+                99
+              ]
+            }"#]],
+    );
+    check_deserialization(
+        S {
+            seq: vec![],
+            map: BTreeMap::new(),
+            string: String::new(),
+            bytes: vec![],
+        },
+        r#"{"seq": [], "map": {}, "string": "", "bytes": ""}"#,
+    );
+
+    // The element after the limit is not deserialized
+    check_error_deserialization::<S>(
+        r#"{"seq": ["1", "2", 3]}"#,
+        expect![[r#"invalid length 3, expected at most 2 elements at line 1 column 21"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"seq": [], "map": {"a": 1, "b": 2}}"#,
+        expect![[r#"invalid length 2, expected at most 1 entry at line 1 column 31"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"seq": [], "map": {}, "string": "abcd"}"#,
+        expect![[r#"invalid length 4, expected at most 3 bytes at line 1 column 39"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"seq": [], "map": {}, "string": "", "bytes": "abcd"}"#,
+        expect![[r#"invalid length 4, expected at most 3 bytes at line 1 column 52"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"seq": [], "map": {}, "string": "", "bytes": [1, 2, 3, 4]}"#,
+        expect![[r#"invalid length 4, expected at most 3 elements at line 1 column 58"#]],
+    );
+
+    // Inputs with a known length are rejected before deserializing any element
+    let value = serde_json::json!({"seq": [1, 2, 3], "map": {}, "string": "", "bytes": []});
+    expect![[r#"invalid length 3, expected at most 2 elements"#]]
+        .assert_eq(&serde_json::from_value::<S>(value).unwrap_err().to_string());
+}
+
+#[test]
+fn test_len_between() {
+    use serde_with::LenBetween;
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S(#[serde_as(as = "LenBetween<1, 2>")] BTreeSet<u32>);
+
+    is_equal(
+        S(BTreeSet::from([1])),
+        expect![[r#"
+            [
+              1
+            ]"#]],
+    );
+    check_deserialization(S(BTreeSet::from([1, 2])), r#"[1, 2]"#);
+    check_error_deserialization::<S>(
+        r#"[]"#,
+        expect![[r#"invalid length 0, expected between 1 and 2 elements at line 1 column 2"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"[1, 2, 3]"#,
+        expect![[r#"invalid length 3, expected between 1 and 2 elements at line 1 column 9"#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Exact(#[serde_as(as = "LenBetween<2, 2>")] HashMap<String, u32>);
+
+    check_deserialization(
+        Exact(HashMap::from([("a".into(), 1), ("b".into(), 2)])),
+        r#"{"a": 1, "b": 2}"#,
+    );
+    check_error_deserialization::<Exact>(
+        r#"{"a": 1}"#,
+        expect![[r#"invalid length 1, expected exactly 2 entries at line 1 column 8"#]],
+    );
+}
@@ -0,0 +1,260 @@
+use super::*;
+use serde_with::Columnar;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Sample {
+    ts: u64,
+    value: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+#[test]
+fn test_columnar() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Columnar")] Vec<Sample>);
+
+    is_equal(
+        S(vec![
+            Sample {
+                ts: 1,
+                value: 0.5,
+                note: Some("start".into()),
+            },
+            Sample {
+                ts: 2,
+                value: 1.5,
+                note: None,
+            },
+            Sample {
+                ts: 3,
+                value: 1.0,
+                note: Some("end".into()),
+            },
+        ]),
+        expect![[r#"
+            {
+              "ts": [
+                1,
+                2,
+                3
+              ],
+              "value": [
+                0.5,
+                1.5,
+                1.0
+              ],
+              "note": [
+                "start",
+                null,
+                "end"
+              ]
+            }"#]],
+    );
+    // Missing fields are filled with null, even if missing from the first element
+    is_equal(
+        S(vec![
+            Sample {
+                ts: 1,
+                value: 0.5,
+                note: None,
+            },
+            Sample {
+                ts: 2,
+                value: 1.5,
+                note: Some("peak".into()),
+            },
+        ]),
+        expect![[r#"
+            {
+              "ts": [
+                1,
+                2
+              ],
+              "value": [
+                0.5,
+                1.5
+              ],
+              "note": [
+                null,
+                "peak"
+              ]
+            }"#]],
+    );
+    is_equal(S(vec![]), expect![[r#"{}"#]]);
+
+    // The column order does not matter
+    check_deserialization(
+        S(vec![
+            Sample {
+                ts: 1,
+                value: 0.5,
+                note: None,
+            },
+            Sample {
+                ts: 2,
+                value: 1.5,
+                note: None,
+            },
+        ]),
+        r#"{"value": [0.5, 1.5], "ts": [1, 2]}"#,
+    );
+
+    check_error_deserialization::<S>(
+        r#"{"ts": [1, 2], "value": [0.5]}"#,
+        expect![[
+            r#"invalid length 1 of column "value", expected 2 values like the previous columns at line 1 column 30"#
+        ]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"ts": [1, 2], "value": [0.5, "x"]}"#,
+        expect![[r#"invalid type: string "x", expected f64 at line 1 column 35"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"[{"ts": 1, "value": 0.5}]"#,
+        expect![[r#"invalid type: sequence, expected a map of columns at line 1 column 0"#]],
+    );
+}
+
+#[test]
+fn test_columnar_inner_adapter() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Columnar<Map<_, DisplayFromStr>>")] Vec<Vec<(String, u32)>>);
+
+    is_equal(
+        S(vec![
+            vec![("a".into(), 1), ("b".into(), 2)],
+            vec![("a".into(), 3), ("b".into(), 4)],
+        ]),
+        expect![[r#"
+            {
+              "a": [
+                "1",
+                "3"
+              ],
+              "b": [
+                "2",
+                "4"
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn test_columnar_skipped_default_field() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        id: u32,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Columnar")] Vec<Item>);
+
+    // The `null` of the skipped field is read as a missing field
+    is_equal(
+        S(vec![
+            Item {
+                id: 1,
+                tags: vec!["a".into()],
+            },
+            Item {
+                id: 2,
+                tags: vec![],
+            },
+        ]),
+        expect![[r#"
+            {
+              "id": [
+                1,
+                2
+              ],
+              "tags": [
+                [
+                  "a"
+                ],
+                null
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn test_columnar_not_a_struct() {
+    #[serde_as]
+    #[derive(Debug, Serialize)]
+    struct S(#[serde_as(as = "Columnar")] Vec<u32>);
+
+    check_error_serialization(
+        S(vec![1, 2]),
+        expect![[r#"the elements must serialize as a struct or a map"#]],
+    );
+}
+
+#[test]
+fn test_columnar_repeated_field() {
+    #[serde_as]
+    #[derive(Debug, Serialize)]
+    struct S(#[serde_as(as = "Columnar<Map<_, _>>")] Vec<Vec<(String, u32)>>);
+
+    check_error_serialization(
+        S(vec![
+            vec![("a".into(), 1), ("b".into(), 2)],
+            vec![("a".into(), 3), ("a".into(), 4)],
+        ]),
+        expect![[r#"the field `a` is repeated in the element at index 1"#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize)]
+    struct Ints(#[serde_as(as = "Columnar<Map<_, _>>")] Vec<Vec<(u32, u32)>>);
+
+    check_error_serialization(
+        Ints(vec![vec![(1, 1), (1, 2)]]),
+        expect![[r#"a field is repeated in the element at index 0"#]],
+    );
+}
+
+#[test]
+fn test_columnar_yaml() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "Columnar")]
+        samples: Vec<Sample>,
+    }
+
+    let value = S {
+        samples: vec![
+            Sample {
+                ts: 1,
+                value: 0.5,
+                note: None,
+            },
+            Sample {
+                ts: 2,
+                value: 1.5,
+                note: Some("peak".into()),
+            },
+        ],
+    };
+    let yaml = serde_yaml::to_string(&value).unwrap();
+    expect![[r#"
+        samples:
+          ts:
+          - 1
+          - 2
+          value:
+          - 0.5
+          - 1.5
+          note:
+          - null
+          - peak
+    "#]]
+    .assert_eq(&yaml);
+    assert_eq!(value, serde_yaml::from_str(&yaml).unwrap());
+}
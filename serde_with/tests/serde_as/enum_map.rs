@@ -1,8 +1,8 @@
 use super::*;
 use core::{fmt::Write as _, str::FromStr};
 use serde_test::Configure;
-use serde_with::EnumMap;
-use std::net::IpAddr;
+use serde_with::{EnumMap, TaggedEnumMap};
+use std::{collections::HashSet, net::IpAddr};
 
 fn bytes_debug_readable(bytes: &[u8]) -> String {
     let mut result = String::with_capacity(bytes.len() * 2);
@@ -456,3 +456,396 @@ fn yaml_round_trip() {
     let deser_values: VecEnumValues = serde_yaml::from_str(&yaml).unwrap();
     assert_eq!(values, deser_values);
 }
+
+struct Type;
+impl serde_with::formats::FieldName for Type {
+    fn name() -> &'static str {
+        "type"
+    }
+}
+
+struct Value;
+impl serde_with::formats::FieldName for Value {
+    fn name() -> &'static str {
+        "value"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Inner {
+    id: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum InternalValue {
+    Unit,
+    Newtype(Inner),
+    Struct { a: i32, b: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+enum AdjacentValue {
+    Unit,
+    Int(i32),
+    Tuple(i32, String),
+    Struct { a: i32, b: String },
+}
+
+#[test]
+fn internally_tagged_json_round_trip() {
+    use serde_with::formats::InternallyTagged;
+
+    #[serde_as]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "TaggedEnumMap<InternallyTagged<Type>>")]
+        vec: Vec<InternalValue>,
+    }
+
+    let values = S {
+        vec: vec![
+            InternalValue::Unit,
+            InternalValue::Newtype(Inner { id: 1 }),
+            InternalValue::Struct {
+                a: 2,
+                b: "B".into(),
+            },
+            InternalValue::Newtype(Inner { id: 3 }),
+        ],
+    };
+
+    let json = serde_json::to_string_pretty(&values).unwrap();
+    expect_test::expect![[r#"
+        {
+          "vec": {
+            "Unit": {},
+            "Newtype": {
+              "id": 1
+            },
+            "Struct": {
+              "a": 2,
+              "b": "B"
+            },
+            "Newtype": {
+              "id": 3
+            }
+          }
+        }"#]]
+    .assert_eq(&json);
+    let deser_values: S = serde_json::from_str(&json).unwrap();
+    assert_eq!(values, deser_values);
+
+    // A unit value is accepted for variants without fields
+    let deser_values: S = serde_json::from_str(r#"{"vec": {"Unit": null}}"#).unwrap();
+    assert_eq!(
+        S {
+            vec: vec![InternalValue::Unit]
+        },
+        deser_values
+    );
+
+    check_error_deserialization::<S>(
+        r#"{"vec": {"Struct": [1, "B"]}}"#,
+        expect![[
+            r#"invalid type: sequence, expected a map of the variant fields at line 1 column 28"#
+        ]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"vec": {"Other": {}}}"#,
+        expect![[
+            r#"unknown variant `Other`, expected one of `Unit`, `Newtype`, `Struct` at line 1 column 21"#
+        ]],
+    );
+}
+
+#[test]
+fn adjacently_tagged_json_round_trip() {
+    use serde_with::formats::AdjacentlyTagged;
+
+    #[serde_as]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "TaggedEnumMap<AdjacentlyTagged<Type, Value>>")]
+        vec: Vec<AdjacentValue>,
+    }
+
+    let values = S {
+        vec: vec![
+            AdjacentValue::Int(1),
+            AdjacentValue::Unit,
+            AdjacentValue::Tuple(2, "Middle".into()),
+            AdjacentValue::Struct {
+                a: 3,
+                b: "B".into(),
+            },
+            AdjacentValue::Int(4),
+        ],
+    };
+
+    let json = serde_json::to_string_pretty(&values).unwrap();
+    expect_test::expect![[r#"
+        {
+          "vec": {
+            "Int": 1,
+            "Unit": null,
+            "Tuple": [
+              2,
+              "Middle"
+            ],
+            "Struct": {
+              "a": 3,
+              "b": "B"
+            },
+            "Int": 4
+          }
+        }"#]]
+    .assert_eq(&json);
+    let deser_values: S = serde_json::from_str(&json).unwrap();
+    assert_eq!(values, deser_values);
+
+    check_error_deserialization::<S>(
+        r#"{"vec": {"Int": "1"}}"#,
+        expect![[r#"invalid type: string "1", expected i32 at line 1 column 20"#]],
+    );
+}
+
+#[test]
+fn tagged_yaml_round_trip() {
+    use serde_with::formats::{AdjacentlyTagged, InternallyTagged};
+
+    #[serde_as]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "TaggedEnumMap<InternallyTagged<Type>>")]
+        internal: Vec<InternalValue>,
+        #[serde_as(as = "TaggedEnumMap<AdjacentlyTagged<Type, Value>>")]
+        adjacent: Vec<AdjacentValue>,
+    }
+
+    let values = S {
+        internal: vec![InternalValue::Unit, InternalValue::Newtype(Inner { id: 1 })],
+        adjacent: vec![
+            AdjacentValue::Unit,
+            AdjacentValue::Tuple(2, "Middle".into()),
+        ],
+    };
+
+    let yaml = serde_yaml::to_string(&values).unwrap();
+    expect_test::expect![[r#"
+        internal:
+          Unit: {}
+          Newtype:
+            id: 1
+        adjacent:
+          Unit: null
+          Tuple:
+          - 2
+          - Middle
+    "#]]
+    .assert_eq(&yaml);
+    let deser_values: S = serde_yaml::from_str(&yaml).unwrap();
+    assert_eq!(values, deser_values);
+}
+
+#[test]
+fn tagged_missing_tag() {
+    use serde_with::formats::InternallyTagged;
+
+    #[serde_as]
+    #[derive(Debug, Serialize)]
+    struct S {
+        #[serde_as(as = "TaggedEnumMap<InternallyTagged<Type>>")]
+        vec: Vec<EnumValue>,
+    }
+
+    let err = serde_json::to_string(&S {
+        vec: vec![EnumValue::Int(1)],
+    })
+    .unwrap_err();
+    expect_test::expect!["missing tag field `type`"].assert_eq(&err.to_string());
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+enum Setting {
+    Verbose(bool),
+    Threads(u32),
+    Name(String),
+    Debug,
+}
+
+#[test]
+fn into_sets() {
+    #[serde_as]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "EnumMap")]
+        btree: BTreeSet<Setting>,
+        #[serde_as(as = "EnumMap")]
+        hash: HashSet<Setting>,
+    }
+
+    let values = S {
+        btree: BTreeSet::from([Setting::Verbose(true), Setting::Threads(4), Setting::Debug]),
+        hash: HashSet::from([Setting::Name("foo".into())]),
+    };
+
+    let json = serde_json::to_string_pretty(&values).unwrap();
+    expect_test::expect![[r#"
+        {
+          "btree": {
+            "Verbose": true,
+            "Threads": 4,
+            "Debug": null
+          },
+          "hash": {
+            "Name": "foo"
+          }
+        }"#]]
+    .assert_eq(&json);
+    let deser_values: S = serde_json::from_str(&json).unwrap();
+    assert_eq!(values, deser_values);
+
+    // Repeated variants are kept, if the values differ
+    let deser_values: S = serde_json::from_str(
+        r#"{"btree": {"Threads": 4, "Threads": 8, "Threads": 4}, "hash": {}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        BTreeSet::from([Setting::Threads(4), Setting::Threads(8)]),
+        deser_values.btree
+    );
+}
+
+#[test]
+fn into_maps() {
+    #[serde_as]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "EnumMap")]
+        btree: BTreeMap<String, Setting>,
+        #[serde_as(as = "EnumMap")]
+        hash: HashMap<String, Setting>,
+    }
+
+    let values = S {
+        btree: BTreeMap::from([
+            ("Debug".into(), Setting::Debug),
+            ("Threads".into(), Setting::Threads(4)),
+        ]),
+        hash: HashMap::from([("Name".into(), Setting::Name("foo".into()))]),
+    };
+
+    let json = serde_json::to_string_pretty(&values).unwrap();
+    expect_test::expect![[r#"
+        {
+          "btree": {
+            "Debug": null,
+            "Threads": 4
+          },
+          "hash": {
+            "Name": "foo"
+          }
+        }"#]]
+    .assert_eq(&json);
+    let deser_values: S = serde_json::from_str(&json).unwrap();
+    assert_eq!(values, deser_values);
+
+    // The last value of a repeated variant wins
+    let deser_values: S =
+        serde_json::from_str(r#"{"btree": {"Threads": 4, "Threads": 8}, "hash": {}}"#).unwrap();
+    assert_eq!(
+        BTreeMap::from([("Threads".into(), Setting::Threads(8))]),
+        deser_values.btree
+    );
+
+    check_error_deserialization::<S>(
+        r#"{"btree": {"Other": 1}, "hash": {}}"#,
+        expect![[
+            r#"unknown variant `Other`, expected one of `Verbose`, `Threads`, `Name`, `Debug` at line 1 column 18"#
+        ]],
+    );
+}
+
+#[test]
+fn enum_map_unique() {
+    use serde_with::EnumMapUnique;
+
+    #[serde_as]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "EnumMapUnique")]
+        vec: Vec<Setting>,
+        #[serde_as(as = "EnumMapUnique")]
+        set: BTreeSet<Setting>,
+        #[serde_as(as = "EnumMapUnique")]
+        map: BTreeMap<String, Setting>,
+    }
+
+    let values = S {
+        vec: vec![Setting::Threads(4), Setting::Verbose(false)],
+        set: BTreeSet::from([Setting::Debug]),
+        map: BTreeMap::from([("Name".into(), Setting::Name("foo".into()))]),
+    };
+
+    let json = serde_json::to_string_pretty(&values).unwrap();
+    expect_test::expect![[r#"
+        {
+          "vec": {
+            "Threads": 4,
+            "Verbose": false
+          },
+          "set": {
+            "Debug": null
+          },
+          "map": {
+            "Name": "foo"
+          }
+        }"#]]
+    .assert_eq(&json);
+    let deser_values: S = serde_json::from_str(&json).unwrap();
+    assert_eq!(values, deser_values);
+
+    check_error_deserialization::<S>(
+        r#"{"vec": {"Threads": 4, "Verbose": true, "Threads": 8}, "set": {}, "map": {}}"#,
+        expect![[r#"invalid entry: found duplicate key "Threads" at index 2 at line 1 column 53"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"vec": {}, "set": {"Debug": null, "Debug": null}, "map": {}}"#,
+        expect![[r#"invalid entry: found duplicate key "Debug" at index 1 at line 1 column 49"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"vec": {}, "set": {}, "map": {"Name": "a", "Name": "b"}}"#,
+        expect![[r#"invalid entry: found duplicate key "Name" at index 1 at line 1 column 56"#]],
+    );
+}
+
+#[test]
+fn enum_map_unique_yaml() {
+    use serde_with::EnumMapUnique;
+
+    #[serde_as]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "EnumMapUnique")]
+        map: BTreeMap<String, Setting>,
+    }
+
+    let values = S {
+        map: BTreeMap::from([
+            ("Debug".into(), Setting::Debug),
+            ("Verbose".into(), Setting::Verbose(true)),
+        ]),
+    };
+
+    let yaml = serde_yaml::to_string(&values).unwrap();
+    expect_test::expect![[r#"
+        map:
+          Debug: null
+          Verbose: true
+    "#]]
+    .assert_eq(&yaml);
+    let deser_values: S = serde_yaml::from_str(&yaml).unwrap();
+    assert_eq!(values, deser_values);
+}
@@ -0,0 +1,298 @@
+use super::*;
+use serde_with::{
+    formats::{DotSeparator, IndexArrays, Separator},
+    FlattenKeys,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Pool {
+    max_size: u32,
+    timeout: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Database {
+    url: String,
+    pool: Pool,
+    labels: BTreeMap<String, String>,
+    replicas: Vec<Replica>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Replica {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn test_flatten_keys() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "FlattenKeys")] Database);
+
+    is_equal(
+        S(Database {
+            url: "postgres://".into(),
+            pool: Pool {
+                max_size: 10,
+                timeout: Some(30),
+            },
+            labels: BTreeMap::from([("env".into(), "prod".into())]),
+            replicas: vec![
+                Replica {
+                    host: "a".into(),
+                    port: 5432,
+                },
+                Replica {
+                    host: "b".into(),
+                    port: 5433,
+                },
+            ],
+        }),
+        expect![[r#"
+            {
+              "url": "postgres://",
+              "pool.max_size": 10,
+              "pool.timeout": 30,
+              "labels.env": "prod",
+              "replicas": [
+                {
+                  "host": "a",
+                  "port": 5432
+                },
+                {
+                  "host": "b",
+                  "port": 5433
+                }
+              ]
+            }"#]],
+    );
+
+    // The order of the keys does not matter and missing optional fields are `None`
+    check_deserialization(
+        S(Database {
+            url: "postgres://".into(),
+            pool: Pool {
+                max_size: 10,
+                timeout: None,
+            },
+            labels: BTreeMap::new(),
+            replicas: Vec::new(),
+        }),
+        r#"{"replicas": [], "pool.max_size": 10, "labels": {}, "url": "postgres://"}"#,
+    );
+
+    check_error_deserialization::<S>(
+        r#"{"url": "postgres://", "pool": 1, "pool.max_size": 10}"#,
+        expect![[r#"the key "pool.max_size" conflicts with a previous key at line 1 column 54"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"url": "postgres://", "pool.max_size": 10, "pool.max_size": 20}"#,
+        expect![[r#"the key "pool.max_size" conflicts with a previous key at line 1 column 64"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"url": "postgres://"}"#,
+        expect![[r#"missing field `pool`"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"[]"#,
+        expect![[
+            r#"invalid type: sequence, expected a map with flattened keys at line 1 column 0"#
+        ]],
+    );
+}
+
+#[test]
+fn test_flatten_keys_index_arrays() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "FlattenKeys<DotSeparator, IndexArrays>")] Database);
+
+    is_equal(
+        S(Database {
+            url: "postgres://".into(),
+            pool: Pool {
+                max_size: 10,
+                timeout: Some(30),
+            },
+            labels: BTreeMap::from([("env".into(), "prod".into())]),
+            replicas: vec![
+                Replica {
+                    host: "a".into(),
+                    port: 5432,
+                },
+                Replica {
+                    host: "b".into(),
+                    port: 5433,
+                },
+            ],
+        }),
+        expect![[r#"
+            {
+              "url": "postgres://",
+              "pool.max_size": 10,
+              "pool.timeout": 30,
+              "labels.env": "prod",
+              "replicas.0.host": "a",
+              "replicas.0.port": 5432,
+              "replicas.1.host": "b",
+              "replicas.1.port": 5433
+            }"#]],
+    );
+
+    // The indices can be in any order
+    check_deserialization(
+        S(Database {
+            url: "postgres://".into(),
+            pool: Pool {
+                max_size: 10,
+                timeout: Some(30),
+            },
+            labels: BTreeMap::from([("env".into(), "prod".into())]),
+            replicas: vec![
+                Replica {
+                    host: "a".into(),
+                    port: 5432,
+                },
+                Replica {
+                    host: "b".into(),
+                    port: 5433,
+                },
+            ],
+        }),
+        r#"{
+            "replicas.1.port": 5433,
+            "replicas.1.host": "b",
+            "replicas.0.host": "a",
+            "replicas.0.port": 5432,
+            "url": "postgres://",
+            "pool.max_size": 10,
+            "pool.timeout": 30,
+            "labels.env": "prod"
+        }"#,
+    );
+
+    // Missing indices prevent reading the elements as a sequence
+    check_error_deserialization::<S>(
+        r#"{
+            "url": "postgres://",
+            "pool.max_size": 10,
+            "labels": {},
+            "replicas.1.host": "b",
+            "replicas.1.port": 5433
+        }"#,
+        expect![[r#"invalid type: map, expected a sequence"#]],
+    );
+}
+
+#[test]
+fn test_flatten_keys_custom_separator() {
+    struct DoubleUnderscore;
+
+    impl Separator for DoubleUnderscore {
+        fn separator() -> &'static str {
+            "__"
+        }
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "FlattenKeys<DoubleUnderscore>")] Pool);
+
+    is_equal(
+        S(Pool {
+            max_size: 10,
+            timeout: None,
+        }),
+        expect![[r#"
+            {
+              "max_size": 10,
+              "timeout": null
+            }"#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Env(#[serde_as(as = "FlattenKeys<DoubleUnderscore>")] BTreeMap<String, Pool>);
+
+    is_equal(
+        Env(BTreeMap::from([(
+            "primary".into(),
+            Pool {
+                max_size: 10,
+                timeout: Some(30),
+            },
+        )])),
+        expect![[r#"
+            {
+              "primary__max_size": 10,
+              "primary__timeout": 30
+            }"#]],
+    );
+}
+
+#[test]
+fn test_flatten_keys_enums() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Storage {
+        Memory,
+        Disk { path: String, size: u64 },
+        Remote(String),
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "FlattenKeys")] BTreeMap<String, Storage>);
+
+    is_equal(
+        S(BTreeMap::from([
+            (
+                "cache".into(),
+                Storage::Disk {
+                    path: "/tmp".into(),
+                    size: 1024,
+                },
+            ),
+            ("logs".into(), Storage::Remote("s3://".into())),
+            ("temp".into(), Storage::Memory),
+        ])),
+        expect![[r#"
+            {
+              "cache.disk.path": "/tmp",
+              "cache.disk.size": 1024,
+              "logs.remote": "s3://",
+              "temp": "memory"
+            }"#]],
+    );
+}
+
+#[test]
+fn test_flatten_keys_errors() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct DottedKeys(#[serde_as(as = "FlattenKeys")] BTreeMap<String, u32>);
+
+    check_error_serialization(
+        DottedKeys(BTreeMap::from([("a.b".into(), 1)])),
+        expect![[r#"the key "a.b" contains the separator ".""#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct IntKeys(#[serde_as(as = "FlattenKeys")] BTreeMap<u32, u32>);
+
+    check_error_serialization(
+        IntKeys(BTreeMap::from([(1, 1)])),
+        expect![[r#"the map keys must be strings"#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct NotAMap(#[serde_as(as = "FlattenKeys")] Vec<u32>);
+
+    check_error_serialization(
+        NotAMap(vec![1]),
+        expect![[r#"the value must serialize as a struct or a map"#]],
+    );
+}
@@ -0,0 +1,333 @@
+use super::*;
+use serde_with::{formats::FieldName, GroupedBy};
+
+struct Region;
+impl FieldName for Region {
+    fn name() -> &'static str {
+        "region"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Server {
+    name: String,
+    region: String,
+    cores: u32,
+}
+
+#[test]
+fn test_grouped_by_vec() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "GroupedBy<Region, _>")] Vec<Server>);
+
+    // The groups keep the order of their first appearance
+    check_serialization(
+        S(vec![
+            Server {
+                name: "a".into(),
+                region: "us-east".into(),
+                cores: 4,
+            },
+            Server {
+                name: "b".into(),
+                region: "eu-west".into(),
+                cores: 2,
+            },
+            Server {
+                name: "c".into(),
+                region: "us-east".into(),
+                cores: 8,
+            },
+        ]),
+        expect![[r#"
+            {
+              "us-east": [
+                {
+                  "name": "a",
+                  "cores": 4
+                },
+                {
+                  "name": "c",
+                  "cores": 8
+                }
+              ],
+              "eu-west": [
+                {
+                  "name": "b",
+                  "cores": 2
+                }
+              ]
+            }"#]],
+    );
+    is_equal(S(vec![]), expect![[r#"{}"#]]);
+
+    // Repeated groups are flattened in order
+    check_deserialization(
+        S(vec![
+            Server {
+                name: "a".into(),
+                region: "us-east".into(),
+                cores: 4,
+            },
+            Server {
+                name: "b".into(),
+                region: "eu-west".into(),
+                cores: 2,
+            },
+            Server {
+                name: "c".into(),
+                region: "us-east".into(),
+                cores: 8,
+            },
+        ]),
+        r#"{
+            "us-east": [{"name": "a", "cores": 4}],
+            "eu-west": [{"name": "b", "cores": 2}],
+            "us-east": [{"name": "c", "cores": 8}]
+        }"#,
+    );
+
+    check_error_deserialization::<S>(
+        r#"{"us-east": [["a", 4]]}"#,
+        expect![[
+            r#"invalid type: sequence, expected a map of the record fields at line 1 column 23"#
+        ]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"us-east": [{"name": "a"}]}"#,
+        expect![[r#"missing field `cores` at line 1 column 28"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"us-east": {"name": "a"}}"#,
+        expect![[r#"invalid type: map, expected a sequence at line 1 column 12"#]],
+    );
+}
+
+#[test]
+fn test_grouped_by_missing_field() {
+    #[derive(Debug, Serialize)]
+    struct Other {
+        name: String,
+    }
+
+    #[serde_as]
+    #[derive(Debug, Serialize)]
+    struct S(#[serde_as(as = "GroupedBy<Region, _>")] Vec<Other>);
+
+    let err = serde_json::to_string(&S(vec![Other { name: "a".into() }])).unwrap_err();
+    expect![[r#"missing value for `region` field"#]].assert_eq(&err.to_string());
+}
+
+#[test]
+fn test_grouped_by_inner_adapter() {
+    struct Id;
+    impl FieldName for Id {
+        fn name() -> &'static str {
+            "id"
+        }
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(
+        #[serde_as(as = "GroupedBy<Id, Map<DisplayFromStr, DisplayFromStr>>")]
+        Vec<Vec<(String, u32)>>,
+    );
+
+    check_serialization(
+        S(vec![
+            vec![("id".into(), 1), ("a".into(), 2)],
+            vec![("b".into(), 3), ("id".into(), 1)],
+        ]),
+        expect![[r#"
+            {
+              "1": [
+                {
+                  "a": "2"
+                },
+                {
+                  "b": "3"
+                }
+              ]
+            }"#]],
+    );
+    // The key field is reinserted as the first entry
+    check_deserialization(
+        S(vec![
+            vec![("id".into(), 1), ("a".into(), 2)],
+            vec![("id".into(), 1), ("b".into(), 3)],
+        ]),
+        r#"{"1": [{"a": "2"}, {"b": "3"}]}"#,
+    );
+}
+
+#[test]
+fn test_grouped_by_into_map() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "GroupedBy<Region, _>")] BTreeMap<String, Vec<Server>>);
+
+    is_equal(
+        S(BTreeMap::from([
+            (
+                "eu-west".into(),
+                vec![Server {
+                    name: "b".into(),
+                    region: "eu-west".into(),
+                    cores: 2,
+                }],
+            ),
+            (
+                "us-east".into(),
+                vec![
+                    Server {
+                        name: "a".into(),
+                        region: "us-east".into(),
+                        cores: 4,
+                    },
+                    Server {
+                        name: "c".into(),
+                        region: "us-east".into(),
+                        cores: 8,
+                    },
+                ],
+            ),
+        ])),
+        expect![[r#"
+            {
+              "eu-west": [
+                {
+                  "name": "b",
+                  "cores": 2
+                }
+              ],
+              "us-east": [
+                {
+                  "name": "a",
+                  "cores": 4
+                },
+                {
+                  "name": "c",
+                  "cores": 8
+                }
+              ]
+            }"#]],
+    );
+
+    // Repeated keys are merged instead of overwritten
+    check_deserialization(
+        S(BTreeMap::from([
+            (
+                "eu-west".into(),
+                vec![Server {
+                    name: "b".into(),
+                    region: "eu-west".into(),
+                    cores: 2,
+                }],
+            ),
+            (
+                "us-east".into(),
+                vec![
+                    Server {
+                        name: "a".into(),
+                        region: "us-east".into(),
+                        cores: 4,
+                    },
+                    Server {
+                        name: "c".into(),
+                        region: "us-east".into(),
+                        cores: 8,
+                    },
+                ],
+            ),
+        ])),
+        r#"{
+            "us-east": [{"name": "a", "cores": 4}],
+            "eu-west": [{"name": "b", "cores": 2}],
+            "us-east": [{"name": "c", "cores": 8}]
+        }"#,
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SHash(#[serde_as(as = "GroupedBy<Region, _>")] HashMap<String, Vec<Server>>);
+
+    is_equal(
+        SHash(HashMap::from([(
+            "eu-west".into(),
+            vec![Server {
+                name: "b".into(),
+                region: "eu-west".into(),
+                cores: 2,
+            }],
+        )])),
+        expect![[r#"
+            {
+              "eu-west": [
+                {
+                  "name": "b",
+                  "cores": 2
+                }
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn test_grouped_by_non_string_keys() {
+    struct Rack;
+    impl FieldName for Rack {
+        fn name() -> &'static str {
+            "rack"
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Host {
+        rack: u32,
+        name: String,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "GroupedBy<Rack, _>")]
+        hosts: Vec<Host>,
+        #[serde_as(as = "GroupedBy<Rack, _>")]
+        racks: BTreeMap<u32, Vec<Host>>,
+    }
+
+    let value = S {
+        hosts: vec![
+            Host {
+                rack: 1,
+                name: "a".into(),
+            },
+            Host {
+                rack: 2,
+                name: "b".into(),
+            },
+        ],
+        racks: BTreeMap::from([(
+            3,
+            vec![Host {
+                rack: 3,
+                name: "c".into(),
+            }],
+        )]),
+    };
+    let yaml = serde_yaml::to_string(&value).unwrap();
+    expect![[r#"
+        hosts:
+          1:
+          - name: a
+          2:
+          - name: b
+        racks:
+          3:
+          - name: c
+    "#]]
+    .assert_eq(&yaml);
+    assert_eq!(value, serde_yaml::from_str(&yaml).unwrap());
+}
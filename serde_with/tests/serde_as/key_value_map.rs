@@ -1,7 +1,10 @@
 use super::*;
 use core::str::FromStr;
 use serde_test::Configure;
-use serde_with::{serde_as, KeyValueMap};
+use serde_with::{
+    formats::{FieldName, FirstValueWins, KeepKey, PreventDuplicates},
+    serde_as, KeyValueMap, KeyValueMapBy,
+};
 use std::net::IpAddr;
 
 #[serde_as]
@@ -348,3 +351,371 @@ fn test_kvmap_complex_key_yaml() {
     let deser_values = serde_yaml::from_str(&yaml).unwrap();
     assert_eq!(kvmap, deser_values);
 }
+
+struct Id;
+
+impl FieldName for Id {
+    fn name() -> &'static str {
+        "id"
+    }
+}
+
+#[test]
+fn test_kvmap_by_struct_json() {
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Struct {
+        id: String,
+        bar: String,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Removed(#[serde_as(as = "KeyValueMapBy<Id, _>")] Vec<Struct>);
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Kept(#[serde_as(as = "KeyValueMapBy<Id, _, KeepKey>")] Vec<Struct>);
+
+    let values = vec![
+        Struct {
+            id: "a".to_string(),
+            bar: "b".to_string(),
+        },
+        Struct {
+            id: "c".to_string(),
+            bar: "d".to_string(),
+        },
+    ];
+
+    is_equal(
+        Removed(values),
+        expect![[r#"
+            {
+              "a": {
+                "bar": "b"
+              },
+              "c": {
+                "bar": "d"
+              }
+            }"#]],
+    );
+
+    let values = vec![Struct {
+        id: "a".to_string(),
+        bar: "b".to_string(),
+    }];
+    is_equal(
+        Kept(values),
+        expect![[r#"
+            {
+              "a": {
+                "id": "a",
+                "bar": "b"
+              }
+            }"#]],
+    );
+    // The map key takes precedence over the copy in the value
+    check_deserialization(
+        Kept(vec![Struct {
+            id: "a".to_string(),
+            bar: "b".to_string(),
+        }]),
+        r#"{"a": {"bar": "b", "id": "other"}}"#,
+    );
+    // and the copy is optional
+    check_deserialization(
+        Kept(vec![Struct {
+            id: "a".to_string(),
+            bar: "b".to_string(),
+        }]),
+        r#"{"a": {"bar": "b"}}"#,
+    );
+    // Without `KeepKey` the value cannot repeat the key field
+    check_error_deserialization::<Removed>(
+        r#"{"a": {"bar": "b", "id": "a"}}"#,
+        expect![[r#"duplicate field `id` at line 1 column 23"#]],
+    );
+
+    is_equal(Removed(vec![]), expect![[r#"{}"#]]);
+}
+
+#[test]
+fn test_kvmap_by_missing_key() {
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Struct {
+        key: String,
+        bar: String,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Missing(#[serde_as(as = "KeyValueMapBy<Id, _>")] Vec<Struct>);
+
+    check_error_serialization(
+        Missing(vec![Struct {
+            key: "a".to_string(),
+            bar: "b".to_string(),
+        }]),
+        expect![[r#"missing value for `id` field"#]],
+    );
+}
+
+#[test]
+fn test_kvmap_by_map_and_seq_json() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Maps(#[serde_as(as = "KeyValueMapBy<Id, _, KeepKey>")] Vec<BTreeMap<String, String>>);
+
+    is_equal(
+        Maps(vec![BTreeMap::from([
+            ("bar".to_string(), "b".to_string()),
+            ("id".to_string(), "a".to_string()),
+        ])]),
+        expect![[r#"
+            {
+              "a": {
+                "bar": "b",
+                "id": "a"
+              }
+            }"#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Seqs(#[serde_as(as = "KeyValueMapBy<Id, _, KeepKey>")] Vec<(String, u32)>);
+
+    is_equal(
+        Seqs(vec![("a".to_string(), 1), ("b".to_string(), 2)]),
+        expect![[r#"
+            {
+              "a": [
+                "a",
+                1
+              ],
+              "b": [
+                "b",
+                2
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn test_kvmap_into_map() {
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Struct {
+        #[serde(rename = "$key$")]
+        key: String,
+        bar: u32,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct LastWins(#[serde_as(as = "KeyValueMap<_>")] BTreeMap<String, Struct>);
+
+    is_equal(
+        LastWins(BTreeMap::from([
+            (
+                "a".to_string(),
+                Struct {
+                    key: "a".to_string(),
+                    bar: 1,
+                },
+            ),
+            (
+                "b".to_string(),
+                Struct {
+                    key: "b".to_string(),
+                    bar: 2,
+                },
+            ),
+        ])),
+        expect![[r#"
+            {
+              "a": {
+                "bar": 1
+              },
+              "b": {
+                "bar": 2
+              }
+            }"#]],
+    );
+    check_deserialization(
+        LastWins(BTreeMap::from([(
+            "a".to_string(),
+            Struct {
+                key: "a".to_string(),
+                bar: 2,
+            },
+        )])),
+        r#"{"a": {"bar": 1}, "a": {"bar": 2}}"#,
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Deserialize)]
+    struct FirstWins(#[serde_as(as = "KeyValueMap<_, FirstValueWins>")] HashMap<String, Struct>);
+
+    check_deserialization(
+        FirstWins(HashMap::from([(
+            "a".to_string(),
+            Struct {
+                key: "a".to_string(),
+                bar: 1,
+            },
+        )])),
+        r#"{"a": {"bar": 1}, "a": {"bar": 2}}"#,
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Deserialize)]
+    struct Prevent(#[serde_as(as = "KeyValueMap<_, PreventDuplicates>")] HashMap<String, Struct>);
+
+    check_error_deserialization::<Prevent>(
+        r#"{"a": {"bar": 1}, "b": {"bar": 2}, "a": {"bar": 3}}"#,
+        expect![[r#"invalid entry: found duplicate key "a" at index 2 at line 1 column 51"#]],
+    );
+
+    // Map keys are not restricted to strings
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Tuples(#[serde_as(as = "KeyValueMap<_, PreventDuplicates>")] BTreeMap<u32, (u32, bool)>);
+
+    let tuples = Tuples(BTreeMap::from([(1, (1, true)), (2, (2, false))]));
+    let yaml = serde_yaml::to_string(&tuples).unwrap();
+    expect_test::expect![[r#"
+        1:
+        - true
+        2:
+        - false
+    "#]]
+    .assert_eq(&yaml);
+    assert_eq!(tuples, serde_yaml::from_str(&yaml).unwrap());
+    let error = serde_yaml::from_str::<Tuples>("{1: [true], 1: [false]}").unwrap_err();
+    expect_test::expect![[r#"invalid entry: found duplicate key 1 at index 1"#]]
+        .assert_eq(&error.to_string());
+}
+
+#[test]
+fn test_kvmap_into_vec_policy() {
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Struct {
+        #[serde(rename = "$key$")]
+        key: String,
+        bar: u32,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct FirstWins(#[serde_as(as = "KeyValueMap<_, FirstValueWins>")] Vec<Struct>);
+
+    is_equal(
+        FirstWins(vec![
+            Struct {
+                key: "a".to_string(),
+                bar: 1,
+            },
+            Struct {
+                key: "b".to_string(),
+                bar: 2,
+            },
+        ]),
+        expect![[r#"
+            {
+              "a": {
+                "bar": 1
+              },
+              "b": {
+                "bar": 2
+              }
+            }"#]],
+    );
+    check_deserialization(
+        FirstWins(vec![
+            Struct {
+                key: "a".to_string(),
+                bar: 1,
+            },
+            Struct {
+                key: "b".to_string(),
+                bar: 2,
+            },
+        ]),
+        r#"{"a": {"bar": 1}, "b": {"bar": 2}, "a": {"bar": 3}}"#,
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Prevent(#[serde_as(as = "KeyValueMap<_, PreventDuplicates>")] Vec<Struct>);
+
+    check_error_deserialization::<Prevent>(
+        r#"{"a": {"bar": 1}, "b": {"bar": 2}, "a": {"bar": 3}}"#,
+        expect![[r#"invalid entry: found duplicate key "a" at index 2 at line 1 column 51"#]],
+    );
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct ById {
+        id: String,
+        bar: u32,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct PreventBy(
+        #[serde_as(as = "KeyValueMapBy<Id, _, KeepKey, PreventDuplicates>")] Vec<ById>,
+    );
+
+    check_deserialization(
+        PreventBy(vec![
+            ById {
+                id: "2".to_string(),
+                bar: 1,
+            },
+            ById {
+                id: "1".to_string(),
+                bar: 2,
+            },
+        ]),
+        r#"{"2": {"id": "2", "bar": 1}, "1": {"id": "1", "bar": 2}}"#,
+    );
+    check_error_deserialization::<PreventBy>(
+        r#"{"2": {"id": "2", "bar": 1}, "2": {"id": "2", "bar": 2}}"#,
+        expect![[r#"invalid entry: found duplicate key "2" at index 1 at line 1 column 56"#]],
+    );
+}
+
+#[test]
+fn test_kvmap_by_into_map() {
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Struct {
+        id: String,
+        bar: u32,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Kept(
+        #[serde_as(as = "KeyValueMapBy<Id, _, KeepKey, PreventDuplicates>")]
+        BTreeMap<String, Struct>,
+    );
+
+    is_equal(
+        Kept(BTreeMap::from([(
+            "a".to_string(),
+            Struct {
+                id: "a".to_string(),
+                bar: 1,
+            },
+        )])),
+        expect![[r#"
+            {
+              "a": {
+                "id": "a",
+                "bar": 1
+              }
+            }"#]],
+    );
+    check_error_deserialization::<Kept>(
+        r#"{"a": {"id": "a", "bar": 1}, "a": {"id": "a", "bar": 2}}"#,
+        expect![[r#"invalid entry: found duplicate key "a" at index 1 at line 1 column 56"#]],
+    );
+}
@@ -9,15 +9,20 @@
 extern crate alloc;
 
 mod collections;
+mod columnar;
 mod default_on;
 mod enum_map;
+mod flatten_keys;
 mod frominto;
 mod fromintoref;
+mod grouped_by;
 mod key_value_map;
 mod map_tuple_list;
 mod pickfirst;
 mod serde_as_macro;
 mod serde_conv;
+mod struct_as;
+mod table;
 mod time;
 #[path = "../utils.rs"]
 mod utils;
@@ -37,7 +42,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::{
     formats::{CommaSeparator, Flexible, Strict},
     serde_as, BoolFromInt, BytesOrString, DisplayFromStr, IfIsHumanReadable, Map,
-    NoneAsEmptyString, OneOrMany, Same, Seq, StringWithSeparator,
+    NoneAsEmptyString, OneOrMany, Same, Seq, StringWithSeparator, VecAsIndexMap,
 };
 use std::{
     collections::HashMap,
@@ -570,6 +575,477 @@ fn test_vec_skip_error() {
     );
 }
 
+#[test]
+fn test_seq_skip_error() {
+    use serde_with::SeqSkipError;
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S {
+        #[serde_as(as = "SeqSkipError<_>")]
+        deque: VecDeque<u8>,
+        #[serde_as(as = "SeqSkipError<_>")]
+        set: BTreeSet<u8>,
+        #[serde_as(as = "SeqSkipError<DisplayFromStr>")]
+        boxed: Box<[u32]>,
+    }
+
+    check_deserialization(
+        S {
+            deque: VecDeque::from([0, 1]),
+            set: BTreeSet::from([1, 2]),
+            boxed: vec![12, 34].into(),
+        },
+        r#"{
+            "deque": [0, "str", 1, [10, 11], -2, {}, 300],
+            "set": [2, null, 1, 2, 1000],
+            "boxed": ["12", 56, "x", "34", "-1"]
+        }"#,
+    );
+    is_equal(
+        S {
+            deque: VecDeque::from([0, 255]),
+            set: BTreeSet::from([3, 2]),
+            boxed: vec![1].into(),
+        },
+        expect![[r#"
+            {
+              "deque": [
+                0,
+                255
+              ],
+              "set": [
+                2,
+                3
+              ],
+              "boxed": [
+                "1"
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn test_map_skip_error() {
+    use serde_with::MapSkipError;
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S {
+        tag: String,
+        #[serde_as(as = "MapSkipError<DisplayFromStr, _>")]
+        values: BTreeMap<u8, u8>,
+    }
+
+    check_deserialization(
+        S {
+            tag: "type".into(),
+            values: BTreeMap::from([(0, 1), (10, 20)]),
+        },
+        r#"{
+            "tag": "type",
+            "values": {"0": 1, "str": 2, "3": "str", "4": [10, 11], "5": null, "300": 3, "10": 20}
+        }"#,
+    );
+    is_equal(
+        S {
+            tag: "round-trip".into(),
+            values: BTreeMap::from([(0, 0), (255, 255)]),
+        },
+        expect![[r#"
+            {
+              "tag": "round-trip",
+              "values": {
+                "0": 0,
+                "255": 255
+              }
+            }"#]],
+    );
+
+    // HashMap keys which fail are skipped together with their value
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S2(#[serde_as(as = "MapSkipError<_, DisplayFromStr>")] HashMap<String, u32>);
+
+    check_deserialization(
+        S2(HashMap::from([("a".into(), 1)])),
+        r#"{"a": "1", "b": "x", "c": 2}"#,
+    );
+}
+
+#[test]
+fn test_vec_collect_errors() {
+    use serde_with::VecCollectErrors;
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S {
+        tag: String,
+        #[serde_as(as = "VecCollectErrors<_>")]
+        values: Vec<Result<u8, String>>,
+    }
+
+    check_deserialization(
+        S {
+            tag: "type".into(),
+            values: vec![
+                Ok(0),
+                Err("invalid type: string \"str\", expected u8".into()),
+                Ok(1),
+                Err("invalid type: sequence, expected u8".into()),
+                Err("invalid value: integer `-2`, expected u8".into()),
+                Err("invalid type: map, expected u8".into()),
+                Err("invalid value: integer `300`, expected u8".into()),
+            ],
+        },
+        r#"{"tag":"type","values":[0, "str", 1, [10, 11], -2, {}, 300]}"#,
+    );
+    is_equal(
+        S {
+            tag: "round-trip".into(),
+            values: vec![Ok(0), Ok(255)],
+        },
+        expect![[r#"
+            {
+              "tag": "round-trip",
+              "values": [
+                0,
+                255
+              ]
+            }"#]],
+    );
+    check_serialization(
+        S {
+            tag: "errors".into(),
+            values: vec![Ok(1), Err("error".into()), Ok(2)],
+        },
+        expect![[r#"
+            {
+              "tag": "errors",
+              "values": [
+                1,
+                2
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn test_validated() {
+    use serde_with::{
+        validate::{And, Finite, InRange, NonEmpty, Not, Or},
+        Validated,
+    };
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S {
+        #[serde_as(as = "Validated<_, NonEmpty>")]
+        name: String,
+        #[serde_as(as = "Validated<Vec<DisplayFromStr>, NonEmpty>")]
+        ports: Vec<u16>,
+        #[serde_as(as = "Validated<_, And<InRange<{-10}, 10>, Not<InRange<0, 0>>>>")]
+        offset: i64,
+        #[serde_as(as = "Validated<_, Or<InRange<1, 3>, InRange<10, 10>>>")]
+        level: u8,
+        #[serde_as(as = "Validated<_, Finite>")]
+        ratio: f32,
+    }
+
+    is_equal(
+        S {
+            name: "a".into(),
+            ports: vec![80],
+            offset: -5,
+            level: 10,
+            ratio: 0.5,
+        },
+        expect![[r#"
+            {
+              "name": "a",
+              "ports": [
+                "80"
+              ],
+              "offset": -5,
+              "level": 10,
+              "ratio": 0.5
+            }"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"name": "", "ports": ["80"], "offset": 1, "level": 1, "ratio": 1}"#,
+        expect![[r#"invalid value: string "", expected a non-empty value at line 1 column 11"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"name": "a", "ports": [], "offset": 1, "level": 1, "ratio": 1}"#,
+        expect![[
+            r#"invalid value: an empty sequence, expected a non-empty value at line 1 column 25"#
+        ]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"name": "a", "ports": ["80"], "offset": 0, "level": 1, "ratio": 1}"#,
+        expect![[
+            r#"invalid value: integer `0`, expected an integer between -10 and 10 and anything except an integer between 0 and 0 at line 1 column 42"#
+        ]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"name": "a", "ports": ["80"], "offset": 11, "level": 1, "ratio": 1}"#,
+        expect![[
+            r#"invalid value: integer `11`, expected an integer between -10 and 10 and anything except an integer between 0 and 0 at line 1 column 43"#
+        ]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"name": "a", "ports": ["80"], "offset": 1, "level": 5, "ratio": 1}"#,
+        expect![[
+            r#"invalid value: integer `5`, expected an integer between 1 and 3 or an integer between 10 and 10 at line 1 column 54"#
+        ]],
+    );
+}
+
+#[cfg(feature = "regex_1")]
+#[test]
+fn test_validated_regex() {
+    use serde_with::{
+        validate::{MatchesRegex, RegexPattern},
+        Validated,
+    };
+
+    struct Slug;
+    impl RegexPattern for Slug {
+        const PATTERN: &'static str = "^[a-z0-9]+(-[a-z0-9]+)*$";
+    }
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S(#[serde_as(as = "Vec<Validated<_, MatchesRegex<Slug>>>")] Vec<String>);
+
+    is_equal(
+        S(vec!["serde-with".into(), "v3".into()]),
+        expect![[r#"
+            [
+              "serde-with",
+              "v3"
+            ]"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"["serde-with", "Serde With"]"#,
+        expect![[
+            r#"invalid value: string "Serde With", expected a string matching the pattern `^[a-z0-9]+(-[a-z0-9]+)*$` at line 1 column 28"#
+        ]],
+    );
+}
+
+#[test]
+fn test_prevent_duplicates_error_message() {
+    use serde_with::{MapPreventDuplicates, SetPreventDuplicates};
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+    struct Name(String);
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct S {
+        #[serde_as(as = "MapPreventDuplicates<_, _>")]
+        map: BTreeMap<Name, u8>,
+        #[serde_as(as = "SetPreventDuplicates<DisplayFromStr>")]
+        set: BTreeSet<u32>,
+        #[serde_as(as = "SetPreventDuplicates<_>")]
+        tuples: BTreeSet<(u8, u8)>,
+    }
+
+    // The key is described as seen in the input, even if it passes through a newtype
+    check_error_deserialization::<S>(
+        r#"{"map": {"a\"b": 1, "c": 2, "a\"b": 3}, "set": [], "tuples": []}"#,
+        expect![[r#"invalid entry: found duplicate key "a\"b" at index 2 at line 1 column 38"#]],
+    );
+    // Long strings, which are not borrowed from the input, are truncated
+    check_error_deserialization::<S>(
+        &format!(
+            r#"{{"map": {{"{key}": 1, "{key}": 2}}, "set": [], "tuples": []}}"#,
+            key = r#"\"xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx"#
+        ),
+        expect![[
+            r#"invalid entry: found duplicate key "\"xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx"... at index 1 at line 1 column 166"#
+        ]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"map": {}, "set": ["1", "2", "3", "2"], "tuples": []}"#,
+        expect![[r#"invalid entry: found duplicate value "2" at index 3 at line 1 column 39"#]],
+    );
+    // Only the index is available for non-scalar values
+    check_error_deserialization::<S>(
+        r#"{"map": {}, "set": [], "tuples": [[1, 2], [1, 2]]}"#,
+        expect![[r#"invalid entry: found duplicate value at index 1 at line 1 column 49"#]],
+    );
+}
+
+#[test]
+fn test_map_merge_duplicates() {
+    use serde_with::{
+        formats::{Concat, DeepMerge, ExtendValues, Replace},
+        MapMergeDuplicates,
+    };
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S {
+        #[serde_as(as = "MapMergeDuplicates<_, _, Concat>")]
+        concat: BTreeMap<String, Vec<u8>>,
+        #[serde_as(as = "MapMergeDuplicates<DisplayFromStr, _, Concat>")]
+        strings: HashMap<u8, String>,
+        #[serde_as(as = "MapMergeDuplicates<_, _, ExtendValues>")]
+        extend: BTreeMap<String, BTreeSet<u8>>,
+        #[serde_as(as = "MapMergeDuplicates<_, _, DeepMerge>")]
+        deep: BTreeMap<String, BTreeMap<String, u8>>,
+        #[serde_as(as = "MapMergeDuplicates<_, _, DeepMerge<DeepMerge<Concat>>>")]
+        deeper: BTreeMap<String, BTreeMap<String, BTreeMap<String, Vec<u8>>>>,
+        #[serde_as(as = "MapMergeDuplicates<_, _, Replace>")]
+        replace: BTreeMap<String, u8>,
+    }
+
+    check_deserialization(
+        S {
+            concat: BTreeMap::from([("a".into(), vec![1, 2, 3]), ("b".into(), vec![4])]),
+            strings: HashMap::from([(1, "ab".into())]),
+            extend: BTreeMap::from([("a".into(), BTreeSet::from([1, 2, 3]))]),
+            deep: BTreeMap::from([(
+                "a".into(),
+                BTreeMap::from([("x".into(), 3), ("y".into(), 2)]),
+            )]),
+            deeper: BTreeMap::from([(
+                "a".into(),
+                BTreeMap::from([(
+                    "x".into(),
+                    BTreeMap::from([("i".into(), vec![1, 2]), ("j".into(), vec![3])]),
+                )]),
+            )]),
+            replace: BTreeMap::from([("a".into(), 2)]),
+        },
+        r#"{
+            "concat": {"a": [1], "b": [4], "a": [2, 3]},
+            "strings": {"1": "a", "1": "b"},
+            "extend": {"a": [1, 2], "a": [2, 3]},
+            "deep": {"a": {"x": 1, "y": 2}, "a": {"x": 3}},
+            "deeper": {"a": {"x": {"i": [1]}}, "a": {"x": {"i": [2], "j": [3]}}},
+            "replace": {"a": 1, "a": 2}
+        }"#,
+    );
+
+    // Serialization is unchanged
+    is_equal(
+        S {
+            concat: BTreeMap::from([("a".into(), vec![1, 2])]),
+            strings: HashMap::new(),
+            extend: BTreeMap::new(),
+            deep: BTreeMap::new(),
+            deeper: BTreeMap::new(),
+            replace: BTreeMap::from([("a".into(), 1)]),
+        },
+        expect![[r#"
+            {
+              "concat": {
+                "a": [
+                  1,
+                  2
+                ]
+              },
+              "strings": {},
+              "extend": {},
+              "deep": {},
+              "deeper": {},
+              "replace": {
+                "a": 1
+              }
+            }"#]],
+    );
+}
+
+#[test]
+fn test_map_normalize_keys() {
+    use serde_with::{
+        de::DeserializeAsWrap,
+        formats::{
+            AsciiLowercase, DuplicateKeyPolicy, FirstValueWins, LastValueWins, Lowercase,
+            PreventDuplicates, Trim,
+        },
+        MapNormalizeKeys, Same,
+    };
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Header {
+        Accept,
+        Host,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S {
+        #[serde_as(as = "MapNormalizeKeys<AsciiLowercase, _, _>")]
+        last: BTreeMap<String, u8>,
+        #[serde_as(as = "MapNormalizeKeys<(Trim, Lowercase), _, _, FirstValueWins>")]
+        first: HashMap<String, u8>,
+        #[serde_as(as = "MapNormalizeKeys<Trim, _, _, LastValueWins>")]
+        trimmed: BTreeMap<Header, u8>,
+        #[serde_as(as = "MapNormalizeKeys<AsciiLowercase, _, _, PreventDuplicates>")]
+        prevent: BTreeMap<String, u8>,
+    }
+
+    check_deserialization(
+        S {
+            last: BTreeMap::from([("content-type".into(), 2), ("host".into(), 3)]),
+            first: HashMap::from([("strasse".into(), 0), ("straße".into(), 1)]),
+            trimmed: BTreeMap::from([(Header::Accept, 1), (Header::Host, 2)]),
+            prevent: BTreeMap::from([("a".into(), 1), ("b".into(), 2)]),
+        },
+        r#"{
+            "last": {"Content-Type": 1, "content-type": 2, "HOST": 3},
+            "first": {" STRASSE": 0, " STRAẞE ": 1, "straße": 2},
+            "trimmed": {" accept ": 1, "host\t": 2},
+            "prevent": {"A": 1, "b": 2}
+        }"#,
+    );
+    check_error_deserialization::<S>(
+        r#"{"last": {}, "first": {}, "trimmed": {}, "prevent": {"A": 1, "B": 2, "a": 3}}"#,
+        expect![[r#"invalid entry: found duplicate key "a" at index 2 at line 1 column 76"#]],
+    );
+
+    // The policy can be named in generic code
+    fn normalized<POLICY: DuplicateKeyPolicy>(
+        json: &str,
+    ) -> Result<BTreeMap<String, u8>, serde_json::Error> {
+        serde_json::from_str::<
+            DeserializeAsWrap<_, MapNormalizeKeys<AsciiLowercase, Same, Same, POLICY>>,
+        >(json)
+        .map(DeserializeAsWrap::into_inner)
+    }
+    assert_eq!(
+        BTreeMap::from([("a".into(), 1)]),
+        normalized::<FirstValueWins>(r#"{"A": 1, "a": 2}"#).unwrap()
+    );
+    normalized::<PreventDuplicates>(r#"{"A": 1, "a": 2}"#).unwrap_err();
+
+    // The keys are serialized without normalization
+    check_serialization(
+        S {
+            last: BTreeMap::from([("Content-Type".into(), 1)]),
+            first: HashMap::new(),
+            trimmed: BTreeMap::new(),
+            prevent: BTreeMap::from([(" A ".into(), 1)]),
+        },
+        expect![[r#"
+            {
+              "last": {
+                "Content-Type": 1
+              },
+              "first": {},
+              "trimmed": {},
+              "prevent": {
+                " A ": 1
+              }
+            }"#]],
+    );
+}
+
 #[test]
 fn test_serialize_reference() {
     #[serde_as]
@@ -1032,6 +1508,128 @@ fn test_one_or_many_prefer_many() {
     );
 }
 
+#[test]
+fn test_one_or_many_collections() {
+    use serde_with::formats::PreferMany;
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S {
+        #[serde_as(as = "OneOrMany<_>")]
+        set: BTreeSet<u32>,
+        #[serde_as(as = "OneOrMany<DisplayFromStr>")]
+        deque: VecDeque<u32>,
+        #[serde_as(as = "OneOrMany<_, PreferMany>")]
+        slice: Box<[u32]>,
+    }
+
+    is_equal(
+        S {
+            set: BTreeSet::from([1]),
+            deque: VecDeque::from([2]),
+            slice: vec![3].into(),
+        },
+        expect![[r#"
+            {
+              "set": 1,
+              "deque": "2",
+              "slice": [
+                3
+              ]
+            }"#]],
+    );
+    is_equal(
+        S {
+            set: BTreeSet::from([1, 2]),
+            deque: VecDeque::from([3, 4]),
+            slice: vec![].into(),
+        },
+        expect![[r#"
+            {
+              "set": [
+                1,
+                2
+              ],
+              "deque": [
+                "3",
+                "4"
+              ],
+              "slice": []
+            }"#]],
+    );
+    check_deserialization(
+        S {
+            set: BTreeSet::from([1]),
+            deque: VecDeque::from([2]),
+            slice: vec![3].into(),
+        },
+        r#"{"set": [1], "deque": ["2"], "slice": 3}"#,
+    );
+    check_error_deserialization::<S>(
+        r#"{"set": [1, 1], "deque": "x", "slice": 3}"#,
+        expect![[r#"
+            OneOrMany could not deserialize any variant:
+              One: invalid digit found in string
+              Many: invalid type: string "x", expected a sequence at line 1 column 28"#]],
+    );
+}
+
+#[test]
+fn test_one_or_many_map() {
+    use serde_with::{formats::PreferMany, OneOrManyMap};
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S1(#[serde_as(as = "OneOrManyMap<_, DisplayFromStr>")] BTreeMap<String, u32>);
+
+    is_equal(S1(BTreeMap::new()), expect![[r#"{}"#]]);
+    is_equal(
+        S1(BTreeMap::from([("a".into(), 1), ("b".into(), 2)])),
+        expect![[r#"
+            {
+              "a": "1",
+              "b": "2"
+            }"#]],
+    );
+    check_deserialization(
+        S1(BTreeMap::from([("a".into(), 1), ("b".into(), 3)])),
+        r#"[{"a": "1"}, {}, {"b": "2", "b": "3"}]"#,
+    );
+    check_deserialization(S1(BTreeMap::new()), r#"[]"#);
+    check_error_deserialization::<S1>(
+        r#"1"#,
+        expect![[
+            r#"invalid type: integer `1`, expected a map or a sequence of maps at line 1 column 1"#
+        ]],
+    );
+    check_error_deserialization::<S1>(
+        r#"[{"a": "1"}, 2]"#,
+        expect![[r#"invalid type: integer `2`, expected a map at line 1 column 14"#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S2(#[serde_as(as = "OneOrManyMap<DisplayFromStr, _, PreferMany>")] HashMap<u8, bool>);
+
+    is_equal(
+        S2(HashMap::from([(1, true)])),
+        expect![[r#"
+            [
+              {
+                "1": true
+              }
+            ]"#]],
+    );
+    check_deserialization(
+        S2(HashMap::from([(1, true), (2, false)])),
+        r#"{"1": true, "2": false}"#,
+    );
+    check_deserialization(
+        S2(HashMap::from([(1, true), (2, false)])),
+        r#"[{"1": true, "2": false}]"#,
+    );
+}
+
 /// Test that Cow borrows from the input
 #[test]
 fn test_borrow_cow_str() {
@@ -685,3 +685,104 @@ fn test_map_as_tuple_list_works_with_serializer_that_needs_length_to_serialize_s
 
     Data::default().serialize(&mut TestSerializer).unwrap();
 }
+
+#[test]
+fn test_vec_as_index_map() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Dense(#[serde_as(as = "VecAsIndexMap<DisplayFromStr>")] Vec<u32>);
+
+    is_equal(Dense(vec![]), expect![[r#"{}"#]]);
+    is_equal(
+        Dense(vec![1, 2, 3]),
+        expect![[r#"
+            {
+              "0": "1",
+              "1": "2",
+              "2": "3"
+            }"#]],
+    );
+    // The order of the keys does not matter and integer keys are accepted too
+    check_deserialization(Dense(vec![1, 2, 3]), r#"{"2": "3", "0": "1", "1": "2"}"#);
+    let dense: Dense = serde_yaml::from_str("{1: '2', 0: '1'}").unwrap();
+    assert_eq!(dense, Dense(vec![1, 2]));
+    check_error_deserialization::<Dense>(
+        r#"{"0": "1", "2": "3"}"#,
+        expect![[r#"invalid entry: missing index 1"#]],
+    );
+    check_error_deserialization::<Dense>(
+        r#"{"0": "1", "0": "2"}"#,
+        expect![[r#"invalid entry: found duplicate index 0 at line 1 column 20"#]],
+    );
+    check_error_deserialization::<Dense>(
+        r#"{"01": "1"}"#,
+        expect![[
+            r#"invalid value: string "01", expected an index between 0 and 65535 at line 1 column 5"#
+        ]],
+    );
+    check_error_deserialization::<Dense>(
+        r#"{"-1": "1"}"#,
+        expect![[
+            r#"invalid value: string "-1", expected an index between 0 and 65535 at line 1 column 5"#
+        ]],
+    );
+    check_error_deserialization::<Dense>(
+        r#"{"65536": "1"}"#,
+        expect![[
+            r#"invalid value: integer `65536`, expected an index between 0 and 65535 at line 1 column 8"#
+        ]],
+    );
+    check_error_deserialization::<Dense>(
+        r#"[]"#,
+        expect![[
+            r#"invalid type: sequence, expected a map with indices as keys at line 1 column 0"#
+        ]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Sparse(#[serde_as(as = "VecAsIndexMap<_, Flexible, 10>")] Vec<Option<String>>);
+
+    is_equal(
+        Sparse(vec![Some("a".into()), None, None, Some("b".into())]),
+        expect![[r#"
+            {
+              "0": "a",
+              "3": "b"
+            }"#]],
+    );
+    // Trailing holes cannot be represented
+    check_serialization(
+        Sparse(vec![None, Some("a".into()), None]),
+        expect![[r#"
+            {
+              "1": "a"
+            }"#]],
+    );
+    check_deserialization(Sparse(vec![None, None, Some("c".into())]), r#"{"2": "c"}"#);
+    check_deserialization(Sparse(vec![]), r#"{}"#);
+    check_error_deserialization::<Sparse>(
+        r#"{"11": "a"}"#,
+        expect![[
+            r#"invalid value: integer `11`, expected an index between 0 and 10 at line 1 column 5"#
+        ]],
+    );
+
+    // Elements which are `None` are serialized as `null` in strict mode
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Nullable(#[serde_as(as = "VecAsIndexMap<_>")] Vec<Option<u32>>);
+
+    is_equal(
+        Nullable(vec![None, Some(1)]),
+        expect![[r#"
+            {
+              "0": null,
+              "1": 1
+            }"#]],
+    );
+    check_error_deserialization::<Nullable>(
+        r#"{"1": 1}"#,
+        expect![[r#"invalid entry: missing index 0"#]],
+    );
+}
@@ -0,0 +1,221 @@
+use super::*;
+use serde_with::{StructAsFieldIndex, StructAsSeq};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Point {
+    x: i32,
+    y: i32,
+    #[serde(default)]
+    label: Option<String>,
+}
+
+#[test]
+fn test_struct_as_seq() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "StructAsSeq")] Point);
+
+    is_equal(
+        S(Point {
+            x: 1,
+            y: 2,
+            label: Some("a".into()),
+        }),
+        expect![[r#"
+        [
+          1,
+          2,
+          "a"
+        ]"#]],
+    );
+    is_equal(
+        S(Point {
+            x: 1,
+            y: 2,
+            label: None,
+        }),
+        expect![[r#"
+        [
+          1,
+          2,
+          null
+        ]"#]],
+    );
+
+    check_error_deserialization::<S>(
+        r#"[1]"#,
+        expect![[r#"invalid length 1, expected struct Point with 3 elements at line 1 column 3"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"[1, 2, null, 4]"#,
+        expect![[r#"trailing characters at line 1 column 14"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"x": 1, "y": 2}"#,
+        expect![[r#"invalid type: map, expected a sequence of struct fields at line 1 column 0"#]],
+    );
+}
+
+#[test]
+fn test_struct_as_seq_not_a_struct() {
+    #[serde_as]
+    #[derive(Debug, Serialize)]
+    struct S(#[serde_as(as = "StructAsSeq")] Vec<u32>);
+
+    check_error_serialization(
+        S(vec![1, 2]),
+        expect![[r#"the value must serialize as a struct"#]],
+    );
+}
+
+#[test]
+fn test_struct_as_seq_skipped_field() {
+    #[derive(Debug, Serialize)]
+    struct Skipped {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        a: Option<u32>,
+        b: u32,
+    }
+
+    #[serde_as]
+    #[derive(Debug, Serialize)]
+    struct S(#[serde_as(as = "StructAsSeq")] Skipped);
+
+    check_error_serialization(
+        S(Skipped { a: None, b: 7 }),
+        expect![[
+            r#"the field `a` cannot be skipped, since the fields are identified by their position"#
+        ]],
+    );
+}
+
+#[test]
+fn test_struct_as_field_index() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "StructAsFieldIndex")]
+        point: Point,
+        #[serde_as(as = "Vec<StructAsFieldIndex>")]
+        points: Vec<Point>,
+    }
+
+    let value = S {
+        point: Point {
+            x: 1,
+            y: 2,
+            label: Some("a".into()),
+        },
+        points: vec![Point {
+            x: 3,
+            y: 4,
+            label: None,
+        }],
+    };
+
+    // Field names are used for the outer struct, but not for `Point`
+    let rmp = rmp_serde::to_vec_named(&value).unwrap();
+    expect![[r#"[82, a5, 70, 6f, 69, 6e, 74, 83, 00, 01, 01, 02, 02, a1, 61, a6, 70, 6f, 69, 6e, 74, 73, 91, 83, 00, 03, 01, 04, 02, c0]"#]].assert_eq(&format!("{rmp:02x?}"));
+    assert_eq!(value, rmp_serde::from_slice(&rmp).unwrap());
+
+    #[derive(Serialize)]
+    struct Raw {
+        point: BTreeMap<u64, i32>,
+        points: Vec<()>,
+    }
+
+    // Unknown indices are ignored like unknown fields
+    let rmp = rmp_serde::to_vec_named(&Raw {
+        point: BTreeMap::from([(0, 1), (1, 2), (7, 3)]),
+        points: vec![],
+    })
+    .unwrap();
+    let value: S = rmp_serde::from_slice(&rmp).unwrap();
+    assert_eq!(
+        Point {
+            x: 1,
+            y: 2,
+            label: None
+        },
+        value.point
+    );
+
+    let rmp = rmp_serde::to_vec_named(&Raw {
+        point: BTreeMap::from([(0, 1)]),
+        points: vec![],
+    })
+    .unwrap();
+    let err = rmp_serde::from_slice::<S>(&rmp).unwrap_err();
+    expect![[r#"missing field `y`"#]].assert_eq(&err.to_string());
+}
+
+#[test]
+fn test_struct_as_if_is_human_readable() {
+    #[serde_as]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "IfIsHumanReadable<Same, StructAsSeq>")]
+        seq: Point,
+        #[serde_as(as = "IfIsHumanReadable<Same, StructAsFieldIndex>")]
+        index: Point,
+    }
+
+    let value = S {
+        seq: Point {
+            x: 1,
+            y: 2,
+            label: None,
+        },
+        index: Point {
+            x: 3,
+            y: 4,
+            label: Some("b".into()),
+        },
+    };
+
+    is_equal(
+        value.clone(),
+        expect![[r#"
+        {
+          "seq": {
+            "x": 1,
+            "y": 2,
+            "label": null
+          },
+          "index": {
+            "x": 3,
+            "y": 4,
+            "label": "b"
+          }
+        }"#]],
+    );
+
+    let rmp = rmp_serde::to_vec_named(&value).unwrap();
+    expect![[r#"[82, a3, 73, 65, 71, 93, 01, 02, c0, a5, 69, 6e, 64, 65, 78, 83, 00, 03, 01, 04, 02, a1, 62]"#]].assert_eq(&format!("{rmp:02x?}"));
+    assert_eq!(value, rmp_serde::from_slice(&rmp).unwrap());
+}
+
+#[test]
+fn test_struct_as_field_index_skipped_field() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Skipped {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        a: Option<u32>,
+        b: u32,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "StructAsFieldIndex")] Skipped);
+
+    // The skipped field keeps its index, such that `b` still uses index 1
+    let value = S(Skipped { a: None, b: 7 });
+    let rmp = rmp_serde::to_vec(&value).unwrap();
+    expect![[r#"[81, 01, 07]"#]].assert_eq(&format!("{rmp:02x?}"));
+    assert_eq!(value, rmp_serde::from_slice(&rmp).unwrap());
+
+    let value = S(Skipped { a: Some(1), b: 7 });
+    let rmp = rmp_serde::to_vec(&value).unwrap();
+    expect![[r#"[82, 00, 01, 01, 07]"#]].assert_eq(&format!("{rmp:02x?}"));
+    assert_eq!(value, rmp_serde::from_slice(&rmp).unwrap());
+}
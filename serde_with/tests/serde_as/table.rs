@@ -0,0 +1,283 @@
+use super::*;
+use serde_with::{formats::HeaderRow, Table};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct User {
+    id: u32,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+}
+
+#[test]
+fn test_table_columns_and_rows() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Table")] Vec<User>);
+
+    is_equal(
+        S(vec![
+            User {
+                id: 1,
+                name: "alice".into(),
+                email: Some("alice@example.com".into()),
+            },
+            User {
+                id: 2,
+                name: "bob".into(),
+                email: None,
+            },
+        ]),
+        expect![[r#"
+            {
+              "columns": [
+                "id",
+                "name",
+                "email"
+              ],
+              "rows": [
+                [
+                  1,
+                  "alice",
+                  "alice@example.com"
+                ],
+                [
+                  2,
+                  "bob",
+                  null
+                ]
+              ]
+            }"#]],
+    );
+    is_equal(
+        S(vec![]),
+        expect![[r#"
+        {
+          "columns": [],
+          "rows": []
+        }"#]],
+    );
+
+    // Columns are matched by name, extra columns are ignored, and missing optional fields are `None`
+    check_deserialization(
+        S(vec![
+            User {
+                id: 1,
+                name: "alice".into(),
+                email: None,
+            },
+            User {
+                id: 2,
+                name: "bob".into(),
+                email: None,
+            },
+        ]),
+        r#"{"rows": [["alice", 30, 1], ["bob", 40, 2]], "columns": ["name", "age", "id"]}"#,
+    );
+
+    check_error_deserialization::<S>(
+        r#"{"columns": ["id", "name"], "rows": [[1, "alice"], [2]]}"#,
+        expect![[
+            r#"invalid length 1 of row 1, expected 2 values like the columns at line 1 column 56"#
+        ]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"columns": ["id"], "rows": [[1]]}"#,
+        expect![[r#"missing field `name` at line 1 column 34"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"columns": ["id"]}"#,
+        expect![[r#"missing field `rows` at line 1 column 19"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"columns": [], "columns": [], "rows": []}"#,
+        expect![[r#"duplicate field `columns` at line 1 column 25"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"[["id"], [1]]"#,
+        expect![[r#"invalid type: integer `1`, expected a sequence at line 1 column 11"#]],
+    );
+}
+
+#[test]
+fn test_table_header_row() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Table<_, HeaderRow>")] Vec<User>);
+
+    is_equal(
+        S(vec![
+            User {
+                id: 1,
+                name: "alice".into(),
+                email: None,
+            },
+            User {
+                id: 2,
+                name: "bob".into(),
+                email: Some("bob@example.com".into()),
+            },
+        ]),
+        expect![[r#"
+            [
+              [
+                "id",
+                "name",
+                "email"
+              ],
+              [
+                1,
+                "alice",
+                null
+              ],
+              [
+                2,
+                "bob",
+                "bob@example.com"
+              ]
+            ]"#]],
+    );
+    is_equal(
+        S(vec![]),
+        expect![[r#"
+        [
+          []
+        ]"#]],
+    );
+    check_deserialization(S(vec![]), r#"[]"#);
+
+    check_deserialization(
+        S(vec![User {
+            id: 1,
+            name: "alice".into(),
+            email: None,
+        }]),
+        r#"[["name", "id", "extra"], ["alice", 1, true]]"#,
+    );
+
+    check_error_deserialization::<S>(
+        r#"[["id", "name"], [1, "alice", "extra"]]"#,
+        expect![[
+            r#"invalid length 3 of row 0, expected 2 values like the columns at line 1 column 39"#
+        ]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"columns": ["id"], "rows": [[1]]}"#,
+        expect![[
+            r#"invalid type: map, expected a list of rows, starting with the column names at line 1 column 0"#
+        ]],
+    );
+}
+
+#[test]
+fn test_table_skipped_default_field() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        id: u32,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Table")] Vec<Item>);
+
+    // The `null` of the skipped field is read as a missing field
+    is_equal(
+        S(vec![
+            Item {
+                id: 1,
+                tags: vec!["a".into()],
+            },
+            Item {
+                id: 2,
+                tags: vec![],
+            },
+        ]),
+        expect![[r#"
+            {
+              "columns": [
+                "id",
+                "tags"
+              ],
+              "rows": [
+                [
+                  1,
+                  [
+                    "a"
+                  ]
+                ],
+                [
+                  2,
+                  null
+                ]
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn test_table_inner_adapter() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Table<Map<_, DisplayFromStr>>")] Vec<Vec<(String, u32)>>);
+
+    is_equal(
+        S(vec![
+            vec![("a".into(), 1), ("b".into(), 2)],
+            vec![("a".into(), 3), ("b".into(), 4)],
+        ]),
+        expect![[r#"
+            {
+              "columns": [
+                "a",
+                "b"
+              ],
+              "rows": [
+                [
+                  "1",
+                  "2"
+                ],
+                [
+                  "3",
+                  "4"
+                ]
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn test_table_rmp() {
+    // rmp serializes structs as arrays, so the `columns` and `rows` are a sequence
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "Table")]
+        users: Vec<User>,
+        #[serde_as(as = "Table<_, HeaderRow>")]
+        admins: Vec<User>,
+    }
+
+    let value = S {
+        users: vec![
+            User {
+                id: 1,
+                name: "alice".into(),
+                email: None,
+            },
+            User {
+                id: 2,
+                name: "bob".into(),
+                email: None,
+            },
+        ],
+        admins: vec![User {
+            id: 3,
+            name: "carol".into(),
+            email: None,
+        }],
+    };
+    let rmp = rmp_serde::to_vec(&value).unwrap();
+    assert_eq!(value, rmp_serde::from_slice(&rmp).unwrap());
+}
@@ -1,11 +1,11 @@
 use super::*;
 use core::time::Duration;
 use serde_with::{
-    DurationMicroSeconds, DurationMicroSecondsWithFrac, DurationMilliSeconds,
+    formats::Saturating, DurationMicroSeconds, DurationMicroSecondsWithFrac, DurationMilliSeconds,
     DurationMilliSecondsWithFrac, DurationNanoSeconds, DurationNanoSecondsWithFrac,
-    DurationSeconds, DurationSecondsWithFrac, TimestampMicroSeconds, TimestampMicroSecondsWithFrac,
-    TimestampMilliSeconds, TimestampMilliSecondsWithFrac, TimestampNanoSeconds,
-    TimestampNanoSecondsWithFrac, TimestampSeconds, TimestampSecondsWithFrac,
+    DurationSeconds, DurationSecondsWithFrac, TimestampAny, TimestampMicroSeconds,
+    TimestampMicroSecondsWithFrac, TimestampMilliSeconds, TimestampMilliSecondsWithFrac,
+    TimestampNanoSeconds, TimestampNanoSecondsWithFrac, TimestampSeconds, TimestampSecondsWithFrac,
 };
 use std::time::SystemTime;
 
@@ -326,7 +326,7 @@ fn test_timestamp_seconds_systemtime() {
     check_error_deserialization::<StructStringStrict>(
         r#"0.0"#,
         expect![[
-            r#"invalid type: floating point `0`, expected a string containing a number at line 1 column 3"#
+            r#"invalid type: floating point `0.0`, expected a string containing a number at line 1 column 3"#
         ]],
     );
 
@@ -424,7 +424,7 @@ fn test_timestamp_seconds_with_frac_systemtime() {
     );
     check_error_deserialization::<StructStringStrict>(
         r#"0.0"#,
-        expect![[r#"invalid type: floating point `0`, expected a string at line 1 column 3"#]],
+        expect![[r#"invalid type: floating point `0.0`, expected a string at line 1 column 3"#]],
     );
 
     #[serde_as]
@@ -464,6 +464,134 @@ macro_rules! smoketest {
     };
 }
 
+#[test]
+fn test_timestamp_any_systemtime() {
+    let zero = SystemTime::UNIX_EPOCH;
+    let one_second = SystemTime::UNIX_EPOCH
+        .checked_add(Duration::new(1, 0))
+        .unwrap();
+    let with_millis = SystemTime::UNIX_EPOCH
+        .checked_add(Duration::new(1_478_563_200, 123_000_000))
+        .unwrap();
+    let minus_half_second = SystemTime::UNIX_EPOCH
+        .checked_sub(Duration::new(0, 500_000_000))
+        .unwrap();
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Int(#[serde_as(as = "TimestampAny")] SystemTime);
+
+    is_equal(Int(zero), expect![[r#"0"#]]);
+    is_equal(Int(one_second), expect![[r#"1"#]]);
+    check_serialization(Int(minus_half_second), expect![[r#"-1"#]]);
+    check_deserialization(Int(one_second), r#"1.0"#);
+    check_deserialization(Int(one_second), r#""1""#);
+    check_deserialization(Int(minus_half_second), r#"-0.5"#);
+    check_deserialization(Int(minus_half_second), r#""-0.5""#);
+    check_deserialization(Int(with_millis), r#""1478563200.123""#);
+    check_deserialization(Int(with_millis), r#""2016-11-08T00:00:00.123Z""#);
+    check_deserialization(Int(with_millis), r#""2016-11-08T05:30:00.123+05:30""#);
+    check_deserialization(Int(with_millis), r#""2016-11-07 19:00:00.123-0500""#);
+    check_deserialization(Int(minus_half_second), r#""1969-12-31T23:59:59.5Z""#);
+    check_error_deserialization::<Int>(
+        r#""2016-11-08""#,
+        expect![[
+            r#"invalid value: string "2016-11-08", expected an integer, a float, a string containing a number, or an RFC 3339 timestamp at line 1 column 12"#
+        ]],
+    );
+    check_error_deserialization::<Int>(
+        r#"null"#,
+        expect![[
+            r#"invalid type: null, expected an integer, a float, a string containing a number, or an RFC 3339 timestamp at line 1 column 4"#
+        ]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Float(#[serde_as(as = "TimestampAny<f64>")] SystemTime);
+
+    is_equal(Float(one_second), expect![[r#"1.0"#]]);
+    is_equal(Float(minus_half_second), expect![[r#"-0.5"#]]);
+    check_deserialization(Float(one_second), r#""1970-01-01T00:00:01Z""#);
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Str(#[serde_as(as = "TimestampAny<String>")] SystemTime);
+
+    is_equal(Str(one_second), expect![[r#""1""#]]);
+    is_equal(Str(minus_half_second), expect![[r#""-0.5""#]]);
+    check_deserialization(Str(one_second), r#"1"#);
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Rfc3339(#[serde_as(as = "TimestampAny<serde_with::formats::Rfc3339>")] SystemTime);
+
+    is_equal(Rfc3339(zero), expect![[r#""1970-01-01T00:00:00Z""#]]);
+    is_equal(
+        Rfc3339(with_millis),
+        expect![[r#""2016-11-08T00:00:00.123Z""#]],
+    );
+    is_equal(
+        Rfc3339(minus_half_second),
+        expect![[r#""1969-12-31T23:59:59.500Z""#]],
+    );
+    check_deserialization(Rfc3339(minus_half_second), r#"-0.5"#);
+}
+
+#[test]
+fn test_saturating_duration() {
+    let zero = Duration::new(0, 0);
+    let one_second = Duration::new(1, 0);
+    let max = Duration::new(u64::MAX, 999_999_999);
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Int(#[serde_as(as = "DurationSeconds<u64, Saturating>")] Duration);
+
+    is_equal(Int(zero), expect![[r#"0"#]]);
+    is_equal(Int(one_second), expect![[r#"1"#]]);
+    check_deserialization(Int(one_second), r#""1""#);
+    check_deserialization(Int(zero), r#"-1"#);
+    check_deserialization(Int(zero), r#""-1.5""#);
+    check_deserialization(Int(max), r#"1e30"#);
+    check_deserialization(Int(max), r#""100000000000000000000""#);
+    check_deserialization(Int(zero), r#""-100000000000000000000.5""#);
+    check_error_deserialization::<Int>(
+        r#""a""#,
+        expect![[
+            r#"invalid value: string "a", expected an integer, a float, or a string containing a number at line 1 column 3"#
+        ]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Millis(#[serde_as(as = "DurationMilliSecondsWithFrac<f64, Saturating>")] Duration);
+
+    is_equal(Millis(one_second), expect![[r#"1000.0"#]]);
+    check_deserialization(Millis(zero), r#"-1000.0"#);
+    check_deserialization(Millis(max), r#"1e30"#);
+}
+
+#[test]
+fn test_saturating_timestamp_systemtime() {
+    let one_second = SystemTime::UNIX_EPOCH
+        .checked_add(Duration::new(1, 0))
+        .unwrap();
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Int(#[serde_as(as = "TimestampSeconds<i64, Saturating>")] SystemTime);
+
+    is_equal(Int(one_second), expect![[r#"1"#]]);
+    check_deserialization(Int(one_second), r#""1""#);
+
+    // The range of `SystemTime` depends on the platform, so only check that both ends are clamped
+    let max: Int = serde_json::from_str("1e30").unwrap();
+    assert!(max.0.checked_add(Duration::new(1, 0)).is_none());
+    let min: Int = serde_json::from_str("-1e30").unwrap();
+    assert!(min.0.checked_sub(Duration::new(1, 0)).is_none());
+}
+
 #[test]
 fn test_duration_smoketest() {
     let one_second = Duration::new(1, 0);
@@ -12,13 +12,15 @@ use crate::utils::{check_deserialization, check_error_deserialization, is_equal}
 use expect_test::expect;
 use serde::{Deserialize, Serialize};
 use serde_with::{
-    serde_as, DurationMicroSeconds, DurationMicroSecondsWithFrac, DurationMilliSeconds,
+    formats::{Flexible, Saturating},
+    serde_as, AsName, DurationMicroSeconds, DurationMicroSecondsWithFrac, DurationMilliSeconds,
     DurationMilliSecondsWithFrac, DurationNanoSeconds, DurationNanoSecondsWithFrac,
-    DurationSeconds, DurationSecondsWithFrac, TimestampMicroSeconds, TimestampMicroSecondsWithFrac,
-    TimestampMilliSeconds, TimestampMilliSecondsWithFrac, TimestampNanoSeconds,
-    TimestampNanoSecondsWithFrac, TimestampSeconds, TimestampSecondsWithFrac,
+    DurationSeconds, DurationSecondsWithFrac, MonthAsNumber, OffsetAsString, OffsetSeconds,
+    TimestampAny, TimestampMicroSeconds, TimestampMicroSecondsWithFrac, TimestampMilliSeconds,
+    TimestampMilliSecondsWithFrac, TimestampNanoSeconds, TimestampNanoSecondsWithFrac,
+    TimestampSeconds, TimestampSecondsWithFrac, WeekdayAsNumber,
 };
-use time_0_3::{Duration, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+use time_0_3::{Duration, Month, OffsetDateTime, PrimitiveDateTime, UtcOffset, Weekday};
 
 /// Create a [`PrimitiveDateTime`] for the Unix Epoch
 fn unix_epoch_primitive() -> PrimitiveDateTime {
@@ -112,6 +114,65 @@ fn test_datetime_utc_smoketest() {
     };
 }
 
+#[test]
+fn test_timestamp_any() {
+    let zero = OffsetDateTime::UNIX_EPOCH;
+    let with_millis = zero + Duration::new(1_478_563_200, 123_000_000);
+
+    smoketest! {
+        OffsetDateTime, "TimestampAny", zero + Duration::seconds(1_478_563_200), {expect![[r#"1478563200"#]]};
+        OffsetDateTime, "TimestampAny<f64>", zero + Duration::milliseconds(500), {expect![[r#"0.5"#]]};
+        OffsetDateTime, "TimestampAny<String>", zero - Duration::milliseconds(500), {expect![[r#""-0.5""#]]};
+        OffsetDateTime, "TimestampAny<serde_with::formats::Rfc3339>", with_millis, {expect![[r#""2016-11-08T00:00:00.123Z""#]]};
+        PrimitiveDateTime, "TimestampAny<serde_with::formats::Rfc3339>", unix_epoch_primitive(), {expect![[r#""1970-01-01T00:00:00Z""#]]};
+    };
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S(#[serde_as(as = "TimestampAny")] OffsetDateTime);
+
+    check_deserialization(S(with_millis), r#""1478563200.123""#);
+    check_deserialization(S(with_millis), r#""2016-11-08T00:00:00.123Z""#);
+    check_deserialization(S(with_millis), r#""2016-11-08T05:30:00.123+05:30""#);
+    check_deserialization(S(zero + Duration::seconds(1_478_563_200)), r#"1478563200"#);
+}
+
+#[test]
+fn test_saturating() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Dur(#[serde_as(as = "DurationSeconds<i64, Saturating>")] Duration);
+
+    is_equal(Dur(Duration::seconds(-1)), expect![[r#"-1"#]]);
+    check_deserialization(Dur(Duration::seconds(1)), r#""1""#);
+    check_deserialization(Dur(Duration::new(i64::MAX, 999_999_999)), r#"1e30"#);
+    check_deserialization(
+        Dur(Duration::new(-i64::MAX, -999_999_999)),
+        r#""-100000000000000000000""#,
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Offset(#[serde_as(as = "TimestampSeconds<i64, Saturating>")] OffsetDateTime);
+
+    is_equal(Offset(OffsetDateTime::UNIX_EPOCH), expect![[r#"0"#]]);
+    check_deserialization(Offset(PrimitiveDateTime::MAX.assume_utc()), r#"1e30"#);
+    check_deserialization(
+        Offset(PrimitiveDateTime::MIN.assume_utc()),
+        r#"-9223372036854775807"#,
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Primitive(
+        #[serde_as(as = "TimestampMilliSecondsWithFrac<f64, Saturating>")] PrimitiveDateTime,
+    );
+
+    is_equal(Primitive(unix_epoch_primitive()), expect![[r#"0.0"#]]);
+    check_deserialization(Primitive(PrimitiveDateTime::MAX), r#"1e30"#);
+    check_deserialization(Primitive(PrimitiveDateTime::MIN), r#"-1e30"#);
+}
+
 #[test]
 fn test_naive_datetime_smoketest() {
     let zero = unix_epoch_primitive();
@@ -161,7 +222,7 @@ fn test_offset_datetime_rfc2822() {
 
     check_error_deserialization::<S>(
         r#""Foobar""#,
-        expect![[r#"the 'weekday' component could not be parsed at line 1 column 8"#]],
+        expect![[r#"the 'day' component could not be parsed at line 1 column 8"#]],
     );
     check_error_deserialization::<S>(
         r#""Fri, 2000""#,
@@ -274,6 +335,77 @@ fn test_offset_datetime_iso8601() {
     );
     check_error_deserialization::<S>(
         r#""2000-AA""#,
-        expect!["unexpected trailing characters at line 1 column 9"],
+        expect!["unexpected trailing characters; the end of input was expected at line 1 column 9"],
+    );
+}
+
+#[test]
+fn test_utc_offset_smoketest() {
+    let india = UtcOffset::from_hms(5, 30, 0).unwrap();
+    let newfoundland = UtcOffset::from_hms(-3, -30, 0).unwrap();
+
+    smoketest! {
+        UtcOffset, "OffsetAsString", UtcOffset::UTC, {expect![[r#""Z""#]]};
+        UtcOffset, "OffsetAsString", india, {expect![[r#""+05:30""#]]};
+        UtcOffset, "OffsetAsString", newfoundland, {expect![[r#""-03:30""#]]};
+        UtcOffset, "OffsetAsString", UtcOffset::from_hms(0, 9, 21).unwrap(), {expect![[r#""+00:09:21""#]]};
+        UtcOffset, "OffsetSeconds", UtcOffset::UTC, {expect![[r#"0"#]]};
+        UtcOffset, "OffsetSeconds", india, {expect![[r#"19800"#]]};
+        UtcOffset, "OffsetSeconds<String>", newfoundland, {expect![[r#""-12600""#]]};
+    };
+}
+
+#[test]
+fn test_utc_offset_flexible() {
+    let india = UtcOffset::from_hms(5, 30, 0).unwrap();
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct AsString(#[serde_as(as = "OffsetAsString<Flexible>")] UtcOffset);
+
+    check_deserialization(AsString(india), r#""+05:30""#);
+    check_deserialization(AsString(india), r#""+0530""#);
+    check_deserialization(AsString(UtcOffset::from_hms(5, 0, 0).unwrap()), r#""+05""#);
+    check_deserialization(AsString(UtcOffset::UTC), r#""z""#);
+    check_error_deserialization::<AsString>(
+        r#""+26:00""#,
+        expect![[
+            r#"UTC offset is outside of the representable range: seconds must be in the range -93599..=93599"#
+        ]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Seconds(#[serde_as(as = "OffsetSeconds<i64, Flexible>")] UtcOffset);
+
+    check_deserialization(Seconds(india), r#"19800"#);
+    check_deserialization(Seconds(india), r#""19800""#);
+}
+
+#[test]
+fn test_month_and_weekday_smoketest() {
+    smoketest! {
+        Month, "MonthAsNumber", Month::January, {expect![[r#"1"#]]};
+        Month, "MonthAsNumber", Month::December, {expect![[r#"12"#]]};
+        Weekday, "WeekdayAsNumber", Weekday::Monday, {expect![[r#"0"#]]};
+        Weekday, "WeekdayAsNumber<serde_with::formats::SundayZero>", Weekday::Sunday, {expect![[r#"0"#]]};
+        Weekday, "WeekdayAsNumber<serde_with::formats::MondayOne>", Weekday::Sunday, {expect![[r#"7"#]]};
+        Month, "AsName", Month::March, {expect![[r#""March""#]]};
+        Month, "AsName<serde_with::formats::Lowercase, serde_with::formats::Abbreviated>", Month::March, {expect![[r#""mar""#]]};
+        Weekday, "AsName<serde_with::formats::Uppercase>", Weekday::Friday, {expect![[r#""FRIDAY""#]]};
+        Weekday, "AsName<serde_with::formats::Titlecase, serde_with::formats::Abbreviated>", Weekday::Tuesday, {expect![[r#""Tue""#]]};
+    };
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Name(#[serde_as(as = "AsName")] Weekday);
+
+    check_deserialization(Name(Weekday::Wednesday), r#""wed""#);
+    check_deserialization(Name(Weekday::Wednesday), r#""WEDNESDAY""#);
+    check_error_deserialization::<Name>(
+        r#""Wedn""#,
+        expect![[
+            r#"invalid value: string "Wedn", expected a weekday name like "Monday" or "Mon" at line 1 column 6"#
+        ]],
     );
 }
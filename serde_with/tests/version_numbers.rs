@@ -36,7 +36,7 @@ fn test_docs_rs_url_point_to_current_version() -> Result<(), Box<dyn std::error:
     let pkg_name = env!("CARGO_PKG_NAME");
     let pkg_version = env!("CARGO_PKG_VERSION");
 
-    let re = regex::Regex::new(&format!(
+    let re = regex_1::Regex::new(&format!(
         "https?://docs.rs/{pkg_name}/((\\d[^/]+|\\*|latest))/"
     ))?;
     let mut error = false;